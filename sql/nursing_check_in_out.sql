@@ -0,0 +1,120 @@
+-- ----------------------------
+-- 中州养老 入住/退住流程增量脚本
+-- 新增：入住申请、老人状态变更历史、退住结算及明细、护理执行记录
+-- 变更：入住记录增加护理等级，合同增加押金
+-- ----------------------------
+
+-- ----------------------------
+-- 1、入住申请表
+-- ----------------------------
+CREATE TABLE IF NOT EXISTS check_in_apply (
+    id               VARCHAR(36)   NOT NULL COMMENT '主键ID',
+    elder_id         VARCHAR(36)   NOT NULL COMMENT '老人ID',
+    stage            INT           NOT NULL DEFAULT 0 COMMENT '办理阶段（0已申请 1已评估 2已选床 3已签约 4已入住 5已取消）',
+    assessment       VARCHAR(1000) DEFAULT NULL COMMENT '健康评估结果',
+    nursing_level_id VARCHAR(36)   DEFAULT NULL COMMENT '护理等级ID',
+    bed_id           VARCHAR(36)   DEFAULT NULL COMMENT '床位ID',
+    contract_no      VARCHAR(64)   DEFAULT NULL COMMENT '合同编号',
+    start_time       DATETIME      DEFAULT NULL COMMENT '合同开始时间',
+    end_time         DATETIME      DEFAULT NULL COMMENT '合同结束时间',
+    amount           DECIMAL(10,2) DEFAULT NULL COMMENT '合同金额',
+    deposit          DECIMAL(10,2) DEFAULT NULL COMMENT '押金',
+    check_in_id      VARCHAR(36)   DEFAULT NULL COMMENT '入住记录ID',
+    create_by        VARCHAR(64)   DEFAULT NULL COMMENT '创建者',
+    create_time      DATETIME      DEFAULT NULL COMMENT '创建时间',
+    update_by        VARCHAR(64)   DEFAULT NULL COMMENT '更新者',
+    update_time      DATETIME      DEFAULT NULL COMMENT '更新时间',
+    remark           VARCHAR(500)  DEFAULT NULL COMMENT '备注',
+    pending_elder_id VARCHAR(36)   GENERATED ALWAYS AS (IF(stage IN (4, 5), NULL, elder_id)) STORED
+                                   COMMENT '办理中的老人ID（已入住、已取消时为空）',
+    PRIMARY KEY (id),
+    UNIQUE KEY uk_check_in_apply_pending (pending_elder_id),
+    KEY idx_check_in_apply_elder (elder_id),
+    KEY idx_check_in_apply_bed (bed_id)
+) ENGINE = InnoDB DEFAULT CHARSET = utf8mb4 COMMENT = '入住申请表';
+
+-- ----------------------------
+-- 2、老人状态变更历史表
+-- ----------------------------
+CREATE TABLE IF NOT EXISTS elder_status_history (
+    id          VARCHAR(36)  NOT NULL COMMENT '主键ID',
+    elder_id    VARCHAR(36)  NOT NULL COMMENT '老人ID',
+    from_status INT          NOT NULL COMMENT '变更前状态',
+    to_status   INT          NOT NULL COMMENT '变更后状态',
+    reason      VARCHAR(500) DEFAULT NULL COMMENT '变更原因',
+    create_by   VARCHAR(64)  DEFAULT NULL COMMENT '创建者',
+    create_time DATETIME     DEFAULT NULL COMMENT '创建时间',
+    update_by   VARCHAR(64)  DEFAULT NULL COMMENT '更新者',
+    update_time DATETIME     DEFAULT NULL COMMENT '更新时间',
+    remark      VARCHAR(500) DEFAULT NULL COMMENT '备注',
+    PRIMARY KEY (id),
+    KEY idx_elder_status_history_elder (elder_id, create_time)
+) ENGINE = InnoDB DEFAULT CHARSET = utf8mb4 COMMENT = '老人状态变更历史表';
+
+-- ----------------------------
+-- 3、退住结算表
+-- ----------------------------
+CREATE TABLE IF NOT EXISTS check_out_settlement (
+    id             VARCHAR(36)   NOT NULL COMMENT '主键ID',
+    elder_id       VARCHAR(36)   NOT NULL COMMENT '老人ID',
+    check_in_id    VARCHAR(36)   DEFAULT NULL COMMENT '入住记录ID',
+    contract_id    VARCHAR(36)   DEFAULT NULL COMMENT '合同ID',
+    check_in_time  DATETIME      DEFAULT NULL COMMENT '入住时间',
+    check_out_time DATETIME      DEFAULT NULL COMMENT '退住时间',
+    days           INT           NOT NULL DEFAULT 0 COMMENT '计费天数',
+    room_fee       DECIMAL(10,2) NOT NULL DEFAULT 0 COMMENT '床位费',
+    nursing_fee    DECIMAL(10,2) NOT NULL DEFAULT 0 COMMENT '护理费',
+    project_fee    DECIMAL(10,2) NOT NULL DEFAULT 0 COMMENT '护理项目费',
+    total_fee      DECIMAL(10,2) NOT NULL DEFAULT 0 COMMENT '费用合计',
+    deposit        DECIMAL(10,2) NOT NULL DEFAULT 0 COMMENT '押金',
+    balance        DECIMAL(10,2) NOT NULL DEFAULT 0 COMMENT '结算余额（正数退还，负数补缴）',
+    create_by      VARCHAR(64)   DEFAULT NULL COMMENT '创建者',
+    create_time    DATETIME      DEFAULT NULL COMMENT '创建时间',
+    update_by      VARCHAR(64)   DEFAULT NULL COMMENT '更新者',
+    update_time    DATETIME      DEFAULT NULL COMMENT '更新时间',
+    remark         VARCHAR(500)  DEFAULT NULL COMMENT '备注',
+    PRIMARY KEY (id),
+    KEY idx_check_out_settlement_elder (elder_id)
+) ENGINE = InnoDB DEFAULT CHARSET = utf8mb4 COMMENT = '退住结算表';
+
+-- ----------------------------
+-- 4、退住结算明细表
+-- ----------------------------
+CREATE TABLE IF NOT EXISTS check_out_settlement_item (
+    id            VARCHAR(36)   NOT NULL COMMENT '主键ID',
+    settlement_id VARCHAR(36)   NOT NULL COMMENT '结算单ID',
+    item_name     VARCHAR(100)  NOT NULL COMMENT '费用项目',
+    unit          VARCHAR(20)   DEFAULT NULL COMMENT '单位',
+    price         DECIMAL(10,2) NOT NULL DEFAULT 0 COMMENT '单价',
+    quantity      DECIMAL(10,2) NOT NULL DEFAULT 0 COMMENT '数量',
+    amount        DECIMAL(10,2) NOT NULL DEFAULT 0 COMMENT '金额',
+    PRIMARY KEY (id),
+    KEY idx_check_out_settlement_item_settlement (settlement_id)
+) ENGINE = InnoDB DEFAULT CHARSET = utf8mb4 COMMENT = '退住结算明细表';
+
+-- ----------------------------
+-- 5、护理执行记录表
+-- ----------------------------
+CREATE TABLE IF NOT EXISTS nursing_record (
+    id           VARCHAR(36)   NOT NULL COMMENT '主键ID',
+    elder_id     VARCHAR(36)   NOT NULL COMMENT '老人ID',
+    project_id   VARCHAR(36)   NOT NULL COMMENT '护理项目ID',
+    quantity     DECIMAL(10,2) NOT NULL DEFAULT 1 COMMENT '执行数量',
+    execute_time DATETIME      NOT NULL COMMENT '执行时间',
+    create_by    VARCHAR(64)   DEFAULT NULL COMMENT '创建者（执行护理员）',
+    create_time  DATETIME      DEFAULT NULL COMMENT '创建时间',
+    update_by    VARCHAR(64)   DEFAULT NULL COMMENT '更新者',
+    update_time  DATETIME      DEFAULT NULL COMMENT '更新时间',
+    remark       VARCHAR(500)  DEFAULT NULL COMMENT '备注',
+    PRIMARY KEY (id),
+    KEY idx_nursing_record_elder_time (elder_id, execute_time)
+) ENGINE = InnoDB DEFAULT CHARSET = utf8mb4 COMMENT = '护理执行记录表';
+
+-- ----------------------------
+-- 6、入住记录增加护理等级，合同增加押金
+-- ----------------------------
+ALTER TABLE check_in
+    ADD COLUMN nursing_level_id VARCHAR(36) DEFAULT NULL COMMENT '护理等级ID' AFTER bed_id;
+
+ALTER TABLE contract
+    ADD COLUMN deposit DECIMAL(10,2) DEFAULT NULL COMMENT '押金' AFTER amount;
@@ -1,5 +1,6 @@
 use axum::{
     routing::{get, post},
+    middleware,
     Router,
     response::Html,
     extract::State,
@@ -7,11 +8,17 @@ use axum::{
     Json,
 };
 use tower_http::cors::CorsLayer;
+use tower_http::services::ServeDir;
 use tower_http::trace::TraceLayer;
-use tracing::{info, error};
+use tracing::{info, error, warn};
 use std::net::SocketAddr;
 use zzyl_common::error::{Result, AjaxResult};
 use zzyl_framework::{init_config, init_database, init_redis, get_config};
+use zzyl_framework::config::OssStorageType;
+use zzyl_framework::security::{auth_middleware, PermitAllUrl};
+use zzyl_framework::web::{init_oper_log_recorder, rate_limit_layer, RateLimiter};
+use zzyl_oss::{init_oss, LOCAL_RESOURCE_PREFIX};
+use zzyl_system::{SysConfigService, SysDictTypeService, SysOperLogService};
 
 /// 应用状态
 #[derive(Clone)]
@@ -121,12 +128,36 @@ struct SystemInfo {
     rust_version: String,
 }
 
+/// 匿名访问地址
+fn permit_all_url() -> PermitAllUrl {
+    PermitAllUrl::new()
+        .permit("/")
+        .permit("/health")
+        .permit("/api/info")
+        .permit("/api/login")
+        .permit("/api/logout")
+        .permit("/captchaImage")
+        .permit(&format!("{}/**", LOCAL_RESOURCE_PREFIX))
+}
+
 /// 创建应用路由
 fn create_app(state: AppState) -> Router {
-    Router::new()
+    let mut router = Router::new()
         .route("/", get(index))
         .route("/health", get(health))
         .route("/api/info", get(system_info))
+        .merge(zzyl_system::controller::routes())
+        .merge(zzyl_nursing_platform::controller::routes());
+    if state.config.oss.storage_type == OssStorageType::Local {
+        router = router.nest_service(LOCAL_RESOURCE_PREFIX, ServeDir::new(state.config.get_upload_path()));
+    }
+
+    router
+        .route_layer(middleware::from_fn_with_state(
+            RateLimiter::from_config(&state.config.rate_limit),
+            rate_limit_layer,
+        ))
+        .route_layer(middleware::from_fn_with_state(permit_all_url(), auth_middleware))
         .layer(CorsLayer::permissive())
         .layer(TraceLayer::new_for_http())
         .with_state(state)
@@ -162,6 +193,23 @@ async fn init_app() -> Result<AppState> {
             e
         })?;
     
+    // 初始化对象存储
+    init_oss(&config).map_err(|e| {
+        error!("对象存储初始化失败: {}", e);
+        e
+    })?;
+    
+    // 初始化操作日志记录任务
+    init_oper_log_recorder(|oper_log| SysOperLogService::insert_oper_log(oper_log.into()));
+    
+    // 预热参数与字典缓存
+    if let Err(e) = SysConfigService::load_config_cache().await {
+        warn!("加载参数缓存失败: {}", e);
+    }
+    if let Err(e) = SysDictTypeService::load_dict_cache().await {
+        warn!("加载字典缓存失败: {}", e);
+    }
+    
     info!("应用初始化完成");
     Ok(AppState { config })
 }
@@ -198,7 +246,7 @@ async fn main() -> Result<()> {
     // 打印启动横幅
     print_banner();
     
-    axum::serve(listener, app).await
+    axum::serve(listener, app.into_make_service_with_connect_info::<SocketAddr>()).await
         .map_err(|e| {
             error!("服务器启动失败: {}", e);
             zzyl_common::error::ZzylError::System(format!("服务器启动失败: {}", e))
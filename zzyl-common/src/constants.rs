@@ -66,6 +66,12 @@ pub mod constants {
     /// 参数管理 cache key
     pub const SYS_CONFIG_KEY: &str = "sys_config:";
     
+    /// 参数键名：账号自助-验证码开关
+    pub const CONFIG_KEY_CAPTCHA_ENABLED: &str = "sys.account.captchaEnabled";
+    
+    /// 参数键名：用户管理-账号初始密码
+    pub const CONFIG_KEY_INIT_PASSWORD: &str = "sys.user.initPassword";
+    
     /// 字典管理 cache key
     pub const SYS_DICT_KEY: &str = "sys_dict:";
     
@@ -53,6 +53,23 @@ impl Default for PageDomain {
     }
 }
 
+impl PageDomain {
+    /// 获取当前页码，未指定或非法时为第一页
+    pub fn page_num(&self) -> i32 {
+        self.page_num.filter(|page_num| *page_num > 0).unwrap_or(1)
+    }
+
+    /// 获取每页数量，未指定或非法时为10条，最多500条
+    pub fn page_size(&self) -> i32 {
+        self.page_size.filter(|page_size| *page_size > 0).unwrap_or(10).min(500)
+    }
+
+    /// 获取分页偏移量
+    pub fn offset(&self) -> i64 {
+        (self.page_num() as i64 - 1) * self.page_size() as i64
+    }
+}
+
 /// 分页结果
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PageResult<T> {
@@ -319,6 +336,8 @@ pub struct SysOperLog {
     pub error_msg: Option<String>,
     /// 操作时间
     pub oper_time: Option<DateTime<Utc>>,
+    /// 消耗时间（毫秒）
+    pub cost_time: Option<i64>,
 }
 
 /// 登录日志
@@ -189,7 +189,7 @@ pub enum DataScope {
     /// 本部门及以下数据权限
     DeptAndChild = 4,
     /// 仅本人数据权限
-    Self = 5,
+    SelfOnly = 5,
 }
 
 impl From<i32> for DataScope {
@@ -199,8 +199,8 @@ impl From<i32> for DataScope {
             2 => DataScope::Custom,
             3 => DataScope::Dept,
             4 => DataScope::DeptAndChild,
-            5 => DataScope::Self,
-            _ => DataScope::Self,
+            5 => DataScope::SelfOnly,
+            _ => DataScope::SelfOnly,
         }
     }
 }
@@ -248,6 +248,41 @@ impl From<ElderStatus> for i32 {
     }
 }
 
+impl ElderStatus {
+    /// 判断是否允许流转到目标状态
+    ///
+    /// 启用/禁用互转；启用或已退住的老人可办理入住；入住中可请假、申请退住；
+    /// 请假归来恢复入住中；退住中可撤销回入住中或完成退住；已退住可禁用。
+    pub fn can_transition_to(self, target: ElderStatus) -> bool {
+        use ElderStatus::*;
+        matches!(
+            (self, target),
+            (Disabled, Enabled)
+                | (Enabled, Disabled)
+                | (Enabled, CheckedIn)
+                | (CheckedIn, Leave)
+                | (Leave, CheckedIn)
+                | (CheckedIn, Leaving)
+                | (Leaving, CheckedIn)
+                | (Leaving, CheckedOut)
+                | (CheckedOut, CheckedIn)
+                | (CheckedOut, Disabled)
+        )
+    }
+
+    /// 状态名称
+    pub fn label(self) -> &'static str {
+        match self {
+            ElderStatus::Disabled => "禁用",
+            ElderStatus::Enabled => "启用",
+            ElderStatus::Leave => "请假",
+            ElderStatus::Leaving => "退住中",
+            ElderStatus::CheckedIn => "入住中",
+            ElderStatus::CheckedOut => "已退住",
+        }
+    }
+}
+
 /// 性别枚举
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Gender {
@@ -273,3 +308,80 @@ impl From<Gender> for i32 {
     }
 }
 
+
+/// 入住申请阶段枚举
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CheckInStage {
+    /// 已申请
+    Applied = 0,
+    /// 已评估
+    Assessed = 1,
+    /// 已选床位
+    BedSelected = 2,
+    /// 已签约
+    ContractSigned = 3,
+    /// 已入住
+    Admitted = 4,
+    /// 已取消
+    Cancelled = 5,
+}
+
+impl From<i32> for CheckInStage {
+    fn from(value: i32) -> Self {
+        match value {
+            0 => CheckInStage::Applied,
+            1 => CheckInStage::Assessed,
+            2 => CheckInStage::BedSelected,
+            3 => CheckInStage::ContractSigned,
+            4 => CheckInStage::Admitted,
+            5 => CheckInStage::Cancelled,
+            _ => CheckInStage::Applied,
+        }
+    }
+}
+
+impl From<CheckInStage> for i32 {
+    fn from(stage: CheckInStage) -> Self {
+        stage as i32
+    }
+}
+
+impl CheckInStage {
+    /// 阶段名称
+    pub fn label(self) -> &'static str {
+        match self {
+            CheckInStage::Applied => "已申请",
+            CheckInStage::Assessed => "已评估",
+            CheckInStage::BedSelected => "已选床位",
+            CheckInStage::ContractSigned => "已签约",
+            CheckInStage::Admitted => "已入住",
+            CheckInStage::Cancelled => "已取消",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn elder_status_transition_table() {
+        use ElderStatus::*;
+        let statuses = [Disabled, Enabled, Leave, Leaving, CheckedIn, CheckedOut];
+        // 行为当前状态，列为目标状态，顺序同 statuses
+        let allowed = [
+            /* Disabled   */ [false, true, false, false, false, false],
+            /* Enabled    */ [true, false, false, false, true, false],
+            /* Leave      */ [false, false, false, false, true, false],
+            /* Leaving    */ [false, false, false, false, true, true],
+            /* CheckedIn  */ [false, false, true, true, false, false],
+            /* CheckedOut */ [true, false, false, false, true, false],
+        ];
+
+        for (from, row) in statuses.iter().zip(allowed) {
+            for (to, expected) in statuses.iter().zip(row) {
+                assert_eq!(from.can_transition_to(*to), expected, "{:?} -> {:?}", from, to);
+            }
+        }
+    }
+}
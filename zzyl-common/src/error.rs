@@ -1,5 +1,13 @@
 use std::fmt;
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::Serialize;
 use thiserror::Error;
+use tracing::{debug, error};
+use uuid::Uuid;
 
 /// 通用错误类型
 #[derive(Error, Debug)]
@@ -87,13 +95,15 @@ impl ZzylError {
     /// 获取HTTP状态码
     pub fn status_code(&self) -> u16 {
         match self {
-            ZzylError::InvalidParameter(_) => 400,
-            ZzylError::Authentication(_) => 401,
-            ZzylError::Authorization(_) | ZzylError::Permission(_) => 403,
+            ZzylError::InvalidParameter(_) | ZzylError::Business(_) | ZzylError::Captcha(_) | ZzylError::User(_) => 400,
+            ZzylError::Authentication(_) | ZzylError::Token(_) => 401,
+            ZzylError::Authorization(_) | ZzylError::Permission(_) | ZzylError::Forbidden(_) => 403,
             ZzylError::NotFound(_) => 404,
             ZzylError::AlreadyExists(_) => 409,
             ZzylError::TooManyRequests(_) => 429,
             ZzylError::ServiceUnavailable(_) => 503,
+            ZzylError::Sql(sqlx::Error::RowNotFound) => 404,
+            ZzylError::Sql(sqlx::Error::Database(e)) if e.is_unique_violation() => 409,
             ZzylError::HttpStatus(code) => *code,
             _ => 500,
         }
@@ -101,22 +111,67 @@ impl ZzylError {
     
     /// 获取错误代码
     pub fn error_code(&self) -> i32 {
-        match self {
-            ZzylError::InvalidParameter(_) => 400,
-            ZzylError::Authentication(_) => 401,
-            ZzylError::Authorization(_) | ZzylError::Permission(_) => 403,
-            ZzylError::NotFound(_) => 404,
-            ZzylError::AlreadyExists(_) => 409,
-            ZzylError::TooManyRequests(_) => 429,
-            ZzylError::ServiceUnavailable(_) => 503,
-            ZzylError::HttpStatus(code) => *code as i32,
-            _ => 500,
-        }
+        self.status_code() as i32
     }
     
     /// 获取错误消息
     pub fn error_message(&self) -> String {
-        self.to_string()
+        match self {
+            ZzylError::Database(msg)
+            | ZzylError::Authentication(msg)
+            | ZzylError::Authorization(msg)
+            | ZzylError::InvalidParameter(msg)
+            | ZzylError::Business(msg)
+            | ZzylError::System(msg)
+            | ZzylError::FileOperation(msg)
+            | ZzylError::Network(msg)
+            | ZzylError::Configuration(msg)
+            | ZzylError::Serialization(msg)
+            | ZzylError::Captcha(msg)
+            | ZzylError::Token(msg)
+            | ZzylError::User(msg)
+            | ZzylError::Permission(msg)
+            | ZzylError::NotFound(msg)
+            | ZzylError::AlreadyExists(msg)
+            | ZzylError::Forbidden(msg)
+            | ZzylError::TooManyRequests(msg)
+            | ZzylError::ServiceUnavailable(msg) => msg.clone(),
+            ZzylError::Sql(sqlx::Error::RowNotFound) => "数据不存在".to_string(),
+            ZzylError::Sql(sqlx::Error::Database(e)) if e.is_unique_violation() => "数据已存在".to_string(),
+            _ => self.to_string(),
+        }
+    }
+    
+    /// 获取完整的错误链
+    pub fn error_chain(&self) -> String {
+        let mut chain = self.to_string();
+        let mut source = std::error::Error::source(self);
+        while let Some(cause) = source {
+            chain.push_str(&format!(" <- {}", cause));
+            source = cause.source();
+        }
+        chain
+    }
+    
+    /// 获取返回给客户端的错误消息
+    ///
+    /// 内部错误不向客户端暴露细节，仅返回错误编号，完整错误链按编号记录到日志中。
+    pub fn response_message(&self) -> String {
+        if self.status_code() == 500 {
+            let error_id = Uuid::new_v4().simple().to_string();
+            error!(error_id = %error_id, "系统内部错误: {}", self.error_chain());
+            format!("系统内部错误，请联系管理员（错误编号：{}）", error_id)
+        } else {
+            debug!("请求处理失败: {}", self.error_chain());
+            self.error_message()
+        }
+    }
+}
+
+impl IntoResponse for ZzylError {
+    fn into_response(self) -> Response {
+        let status = StatusCode::from_u16(self.status_code()).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+        (status, Json(AjaxResult::<()>::from(self))).into_response()
     }
 }
 
@@ -170,12 +225,18 @@ impl<T> From<ZzylError> for AjaxResult<T> {
     fn from(error: ZzylError) -> Self {
         Self {
             code: error.error_code(),
-            msg: error.error_message(),
+            msg: error.response_message(),
             data: None,
         }
     }
 }
 
+impl<T: Serialize> IntoResponse for AjaxResult<T> {
+    fn into_response(self) -> Response {
+        Json(self).into_response()
+    }
+}
+
 /// 分页响应结果
 #[derive(Debug, Clone, serde::Serialize)]
 pub struct TableDataInfo<T> {
@@ -207,3 +268,20 @@ impl<T> TableDataInfo<T> {
     }
 }
 
+
+impl<T> From<ZzylError> for TableDataInfo<T> {
+    fn from(error: ZzylError) -> Self {
+        Self {
+            code: error.error_code(),
+            msg: error.response_message(),
+            rows: Vec::new(),
+            total: 0,
+        }
+    }
+}
+
+impl<T: Serialize> IntoResponse for TableDataInfo<T> {
+    fn into_response(self) -> Response {
+        Json(self).into_response()
+    }
+}
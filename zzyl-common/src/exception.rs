@@ -1,3 +1,4 @@
+use axum::response::{IntoResponse, Response};
 use crate::error::{ZzylError, Result};
 
 /// 业务异常
@@ -504,3 +505,34 @@ impl From<ServiceUnavailableException> for ZzylError {
     }
 }
 
+
+/// 为异常类型实现响应转换，统一按 ZzylError 输出
+macro_rules! impl_into_response {
+    ($($exception:ty),* $(,)?) => {
+        $(
+            impl IntoResponse for $exception {
+                fn into_response(self) -> Response {
+                    ZzylError::from(self).into_response()
+                }
+            }
+        )*
+    };
+}
+
+impl_into_response!(
+    BusinessException,
+    ParameterException,
+    AuthenticationException,
+    AuthorizationException,
+    DataNotFoundException,
+    DataAlreadyExistsException,
+    SystemException,
+    FileOperationException,
+    CaptchaException,
+    TokenException,
+    UserException,
+    PermissionException,
+    ForbiddenException,
+    TooManyRequestsException,
+    ServiceUnavailableException,
+);
@@ -3,16 +3,20 @@ pub mod string;
 pub mod file;
 pub mod security;
 pub mod validation;
+pub mod id_card;
 pub mod excel;
 pub mod json;
 pub mod http;
+pub mod user_agent;
 
 pub use date::*;
 pub use string::*;
 pub use file::*;
 pub use security::*;
 pub use validation::*;
+pub use id_card::*;
 pub use excel::*;
 pub use json::*;
 pub use http::*;
+pub use user_agent::*;
 
@@ -15,6 +15,11 @@ impl DateUtils {
         Local::now()
     }
     
+    /// 获取时间对应的本地日期
+    pub fn local_date(dt: &DateTime<Utc>) -> NaiveDate {
+        dt.with_timezone(&Local).date_naive()
+    }
+    
     /// 格式化日期时间
     pub fn format_datetime(dt: &DateTime<Utc>, format: &str) -> String {
         dt.format(format).to_string()
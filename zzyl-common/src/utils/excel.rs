@@ -63,6 +63,25 @@ impl ExcelUtils {
         Ok(result)
     }
     
+    /// 从上传的文件内容读取Excel到结构体，读取前校验表头
+    pub fn read_excel_bytes_to_struct<T>(bytes: &[u8], headers: &[String]) -> Result<Vec<T>, Box<dyn std::error::Error>>
+    where
+        T: for<'de> Deserialize<'de>,
+    {
+        let path = std::env::temp_dir().join(format!("zzyl-import-{}.xlsx", uuid::Uuid::new_v4()));
+        let path_str = path.to_str().ok_or("临时文件路径无效")?;
+        let result = std::fs::write(&path, bytes)
+            .map_err(Into::into)
+            .and_then(|_| {
+                if !Self::validate_excel_format(path_str, headers)? {
+                    return Err(format!("表头与模板不一致，应为: {}", headers.join(", ")).into());
+                }
+                Self::read_excel_to_struct(path_str, headers)
+            });
+        let _ = std::fs::remove_file(&path);
+        result
+    }
+    
     /// 写入Excel文件
     pub fn write_excel(path: &str, data: &[Vec<String>]) -> Result<(), Box<dyn std::error::Error>> {
         use calamine::{Writer, Xlsx};
@@ -81,6 +100,15 @@ impl ExcelUtils {
         Ok(())
     }
     
+    /// 写入Excel并返回文件内容，用于HTTP下载
+    pub fn write_excel_bytes(data: &[Vec<String>]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+        let path = std::env::temp_dir().join(format!("zzyl-export-{}.xlsx", uuid::Uuid::new_v4()));
+        let path_str = path.to_str().ok_or("临时文件路径无效")?;
+        let result = Self::write_excel(path_str, data).and_then(|_| Ok(std::fs::read(&path)?));
+        let _ = std::fs::remove_file(&path);
+        result
+    }
+    
     /// 写入结构体到Excel文件
     pub fn write_struct_to_excel<T>(path: &str, headers: &[String], data: &[T]) -> Result<(), Box<dyn std::error::Error>>
     where
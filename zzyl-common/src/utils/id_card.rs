@@ -0,0 +1,141 @@
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use validator::ValidationError;
+use crate::enums::Gender;
+
+/// 18位身份证前17位加权因子
+const WEIGHTS: [u32; 17] = [7, 9, 10, 5, 8, 4, 2, 1, 6, 3, 7, 9, 10, 5, 8, 4, 2];
+
+/// 校验位对照表（按加权和模11取值）
+const CHECK_CODES: [char; 11] = ['1', '0', 'X', '9', '8', '7', '6', '5', '4', '3', '2'];
+
+/// 身份证解析结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IdCardInfo {
+    /// 出生日期
+    pub birthday: NaiveDate,
+    /// 性别
+    pub sex: Gender,
+}
+
+impl IdCardInfo {
+    /// 出生日期（UTC零点）
+    pub fn birthday_time(&self) -> DateTime<Utc> {
+        self.birthday.and_time(NaiveTime::MIN).and_utc()
+    }
+}
+
+/// 身份证工具类
+pub struct IdCardUtils;
+
+impl IdCardUtils {
+    /// 解析18位身份证号，校验格式、出生日期与校验位，返回出生日期与性别
+    pub fn parse(id_card: &str) -> Result<IdCardInfo, ValidationError> {
+        let chars: Vec<char> = id_card.chars().collect();
+        if chars.len() != 18
+            || !chars[..17].iter().all(char::is_ascii_digit)
+            || !(chars[17].is_ascii_digit() || chars[17] == 'X' || chars[17] == 'x')
+        {
+            return Err(Self::error("id_card_format", "身份证号必须为18位"));
+        }
+
+        let birthday = NaiveDate::parse_from_str(&id_card[6..14], "%Y%m%d")
+            .ok()
+            .filter(|birthday| *birthday <= Utc::now().date_naive())
+            .ok_or_else(|| Self::error("id_card_birthday", "身份证号中的出生日期无效"))?;
+
+        if Self::check_code(id_card) != Some(chars[17].to_ascii_uppercase()) {
+            return Err(Self::error("id_card_checksum", "身份证号校验位错误"));
+        }
+
+        // 第17位为顺序码末位，奇数为男，偶数为女
+        let sex = match chars[16].to_digit(10) {
+            Some(digit) if digit % 2 == 1 => Gender::Male,
+            _ => Gender::Female,
+        };
+        Ok(IdCardInfo { birthday, sex })
+    }
+
+    /// 计算身份证校验位
+    pub fn check_code(id_card: &str) -> Option<char> {
+        let sum = id_card
+            .chars()
+            .take(17)
+            .zip(WEIGHTS)
+            .map(|(c, weight)| c.to_digit(10).map(|digit| digit * weight))
+            .sum::<Option<u32>>()?;
+        Some(CHECK_CODES[(sum % 11) as usize])
+    }
+
+    /// 构建带提示信息的校验错误
+    fn error(code: &'static str, message: &'static str) -> ValidationError {
+        ValidationError::new(code).with_message(message.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 按前17位补齐校验位
+    fn with_check_code(prefix: &str) -> String {
+        format!("{}{}", prefix, IdCardUtils::check_code(prefix).unwrap())
+    }
+
+    fn error_code(id_card: &str) -> String {
+        IdCardUtils::parse(id_card).unwrap_err().code.to_string()
+    }
+
+    #[test]
+    fn parse_valid_id_card() {
+        let info = IdCardUtils::parse("110101199003070011").unwrap();
+        assert_eq!(info.birthday, NaiveDate::from_ymd_opt(1990, 3, 7).unwrap());
+        assert_eq!(info.sex, Gender::Male);
+
+        let info = IdCardUtils::parse("440300200002290019").unwrap();
+        assert_eq!(info.birthday, NaiveDate::from_ymd_opt(2000, 2, 29).unwrap());
+        assert_eq!(info.sex, Gender::Male);
+    }
+
+    #[test]
+    fn parse_check_code_x_in_any_case() {
+        for id_card in ["11010519491231002X", "11010519491231002x"] {
+            let info = IdCardUtils::parse(id_card).unwrap();
+            assert_eq!(info.birthday, NaiveDate::from_ymd_opt(1949, 12, 31).unwrap());
+            assert_eq!(info.sex, Gender::Female);
+        }
+    }
+
+    #[test]
+    fn parse_rejects_bad_checksum() {
+        assert_eq!(error_code("110101199003070012"), "id_card_checksum");
+        assert_eq!(error_code("110105194912310021"), "id_card_checksum");
+    }
+
+    #[test]
+    fn parse_rejects_invalid_or_future_birthday() {
+        assert_eq!(error_code(&with_check_code("11010119900230001")), "id_card_birthday");
+        assert_eq!(error_code(&with_check_code("11010119901301001")), "id_card_birthday");
+        assert_eq!(error_code(&with_check_code("11010129990101001")), "id_card_birthday");
+    }
+
+    #[test]
+    fn parse_rejects_malformed_input() {
+        for id_card in [
+            "",
+            "11010519491231002",
+            "1101051949123100200",
+            "11010519491231002Y",
+            "11010519491231002Ｘ",
+            "１１０１０５１９４９１２３１００２X",
+            "北京市东城区194912310021",
+        ] {
+            assert_eq!(error_code(id_card), "id_card_format", "{}", id_card);
+        }
+    }
+
+    #[test]
+    fn birthday_time_is_midnight_utc() {
+        let info = IdCardUtils::parse("110101199003070011").unwrap();
+        assert_eq!(info.birthday_time().to_rfc3339(), "1990-03-07T00:00:00+00:00");
+    }
+}
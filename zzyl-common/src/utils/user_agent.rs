@@ -0,0 +1,50 @@
+/// User-Agent解析工具类
+pub struct UserAgentUtils;
+
+impl UserAgentUtils {
+    /// 获取浏览器类型
+    pub fn get_browser(user_agent: &str) -> String {
+        let browser = if user_agent.contains("MicroMessenger") {
+            "WeChat"
+        } else if user_agent.contains("Edg/") || user_agent.contains("Edge/") {
+            "Edge"
+        } else if user_agent.contains("OPR/") || user_agent.contains("Opera") {
+            "Opera"
+        } else if user_agent.contains("Firefox/") {
+            "Firefox"
+        } else if user_agent.contains("Chrome/") {
+            "Chrome"
+        } else if user_agent.contains("Safari/") {
+            "Safari"
+        } else if user_agent.contains("MSIE") || user_agent.contains("Trident/") {
+            "Internet Explorer"
+        } else {
+            "Unknown"
+        };
+        browser.to_string()
+    }
+    
+    /// 获取操作系统
+    pub fn get_os(user_agent: &str) -> String {
+        let os = if user_agent.contains("Windows NT 10.0") {
+            "Windows 10"
+        } else if user_agent.contains("Windows NT 6.3") {
+            "Windows 8.1"
+        } else if user_agent.contains("Windows NT 6.1") {
+            "Windows 7"
+        } else if user_agent.contains("Windows") {
+            "Windows"
+        } else if user_agent.contains("iPhone") || user_agent.contains("iPad") {
+            "iOS"
+        } else if user_agent.contains("Mac OS X") {
+            "Mac OS X"
+        } else if user_agent.contains("Android") {
+            "Android"
+        } else if user_agent.contains("Linux") {
+            "Linux"
+        } else {
+            "Unknown"
+        };
+        os.to_string()
+    }
+}
@@ -0,0 +1,147 @@
+use std::time::Duration;
+use sqlx::Row;
+use tracing::warn;
+use zzyl_common::constants::constants::SYS_CONFIG_KEY;
+use zzyl_common::error::Result;
+use crate::database::require_database_pool;
+use crate::redis::get_redis_manager;
+
+/// 系统参数缓存
+///
+/// 参数值以 `sys_config:{config_key}` 缓存在Redis中，未命中时回源数据库；
+/// 类型化读取在参数缺失或格式错误时返回调用方给出的默认值。
+pub struct ConfigCache;
+
+impl ConfigCache {
+    /// 读取参数值
+    pub async fn get(config_key: &str) -> Result<Option<String>> {
+        let cache_key = Self::cache_key(config_key);
+        if let Some(redis) = get_redis_manager() {
+            match redis.get(&cache_key).await {
+                Ok(Some(value)) => return Ok(Some(value)),
+                Ok(None) => {}
+                Err(e) => warn!("读取参数缓存失败: {}", e),
+            }
+        }
+
+        let row = sqlx::query("SELECT config_value FROM sys_config WHERE config_key = ? LIMIT 1")
+            .bind(config_key)
+            .fetch_optional(require_database_pool()?)
+            .await?;
+        let value: Option<String> = match row {
+            Some(row) => row.try_get("config_value")?,
+            None => None,
+        };
+        if let Some(value) = &value {
+            Self::set(config_key, value).await;
+        }
+        Ok(value)
+    }
+
+    /// 读取字符串参数
+    pub async fn get_string(config_key: &str, default: &str) -> String {
+        match Self::get(config_key).await {
+            Ok(Some(value)) if !value.trim().is_empty() => value,
+            Ok(_) => default.to_string(),
+            Err(e) => {
+                warn!("读取参数'{}'失败: {}", config_key, e);
+                default.to_string()
+            }
+        }
+    }
+
+    /// 读取布尔参数，支持 true/false、1/0、Y/N、yes/no、on/off
+    pub async fn get_bool(config_key: &str, default: bool) -> bool {
+        Self::get_parsed(config_key, default, Self::parse_bool).await
+    }
+
+    /// 读取整数参数
+    pub async fn get_i64(config_key: &str, default: i64) -> i64 {
+        Self::get_parsed(config_key, default, |value| value.parse().ok()).await
+    }
+
+    /// 读取时长参数，支持 ms/s/m/h/d 后缀，无后缀时按秒处理
+    pub async fn get_duration(config_key: &str, default: Duration) -> Duration {
+        Self::get_parsed(config_key, default, Self::parse_duration).await
+    }
+
+    /// 写入参数缓存，失败时仅记录日志
+    pub async fn set(config_key: &str, config_value: &str) {
+        if let Some(redis) = get_redis_manager() {
+            if let Err(e) = redis.set(&Self::cache_key(config_key), config_value).await {
+                warn!("写入参数缓存失败: {}", e);
+            }
+        }
+    }
+
+    /// 删除参数缓存，失败时仅记录日志
+    pub async fn remove(config_key: &str) {
+        if let Some(redis) = get_redis_manager() {
+            if let Err(e) = redis.del(&Self::cache_key(config_key)).await {
+                warn!("删除参数缓存失败: {}", e);
+            }
+        }
+    }
+
+    /// 清空全部参数缓存
+    pub async fn clear() -> Result<()> {
+        if let Some(redis) = get_redis_manager() {
+            let keys = redis.keys(&format!("{}*", SYS_CONFIG_KEY)).await?;
+            if !keys.is_empty() {
+                redis.del_batch(&keys).await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// 参数缓存键
+    pub fn cache_key(config_key: &str) -> String {
+        format!("{}{}", SYS_CONFIG_KEY, config_key)
+    }
+
+    /// 读取并解析参数
+    async fn get_parsed<T, F>(config_key: &str, default: T, parse: F) -> T
+    where
+        F: Fn(&str) -> Option<T>,
+    {
+        match Self::get(config_key).await {
+            Ok(Some(value)) => match parse(value.trim()) {
+                Some(parsed) => parsed,
+                None => {
+                    warn!("参数'{}'的值'{}'格式错误，使用默认值", config_key, value);
+                    default
+                }
+            },
+            Ok(None) => default,
+            Err(e) => {
+                warn!("读取参数'{}'失败: {}", config_key, e);
+                default
+            }
+        }
+    }
+
+    /// 解析布尔值
+    fn parse_bool(value: &str) -> Option<bool> {
+        match value.to_ascii_lowercase().as_str() {
+            "true" | "1" | "y" | "yes" | "on" => Some(true),
+            "false" | "0" | "n" | "no" | "off" => Some(false),
+            _ => None,
+        }
+    }
+
+    /// 解析时长
+    fn parse_duration(value: &str) -> Option<Duration> {
+        let value = value.to_ascii_lowercase();
+        let split = value.find(|c: char| !c.is_ascii_digit()).unwrap_or(value.len());
+        let (number, unit) = value.split_at(split);
+        let number: u64 = number.parse().ok()?;
+        match unit.trim() {
+            "ms" => Some(Duration::from_millis(number)),
+            "" | "s" => Some(Duration::from_secs(number)),
+            "m" => Some(Duration::from_secs(number * 60)),
+            "h" => Some(Duration::from_secs(number * 60 * 60)),
+            "d" => Some(Duration::from_secs(number * 24 * 60 * 60)),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,266 @@
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use zzyl_common::constants::constants::{CAPTCHA_CODE_KEY, CAPTCHA_EXPIRATION, CONFIG_KEY_CAPTCHA_ENABLED};
+use zzyl_common::error::{Result, ZzylError};
+use crate::cache::ConfigCache;
+use crate::config::get_config;
+use crate::redis::{get_redis_manager, RedisManager};
+
+/// 验证码类型：数字计算
+pub const CAPTCHA_TYPE_MATH: &str = "math";
+
+/// 验证码类型：字符验证
+pub const CAPTCHA_TYPE_CHAR: &str = "char";
+
+/// 图片宽度
+const IMAGE_WIDTH: u32 = 160;
+
+/// 图片高度
+const IMAGE_HEIGHT: u32 = 60;
+
+/// 字形放大倍数
+const GLYPH_SCALE: u32 = 4;
+
+/// 字符验证码长度
+const CHAR_CAPTCHA_LENGTH: usize = 4;
+
+/// 字符验证码字符集（去掉易混淆的 0/O、1/I/L）
+const CHAR_CAPTCHA_CHARSET: &[u8] = b"23456789ABCDEFGHJKMNPQRSTUVWXYZ";
+
+/// 5x7 点阵字形，每行低 5 位有效
+const GLYPHS: &[(char, [u8; 7])] = &[
+    ('0', [0b01110, 0b10001, 0b10011, 0b10101, 0b11001, 0b10001, 0b01110]),
+    ('1', [0b00100, 0b01100, 0b00100, 0b00100, 0b00100, 0b00100, 0b01110]),
+    ('2', [0b01110, 0b10001, 0b00001, 0b00010, 0b00100, 0b01000, 0b11111]),
+    ('3', [0b11111, 0b00010, 0b00100, 0b00010, 0b00001, 0b10001, 0b01110]),
+    ('4', [0b00010, 0b00110, 0b01010, 0b10010, 0b11111, 0b00010, 0b00010]),
+    ('5', [0b11111, 0b10000, 0b11110, 0b00001, 0b00001, 0b10001, 0b01110]),
+    ('6', [0b00110, 0b01000, 0b10000, 0b11110, 0b10001, 0b10001, 0b01110]),
+    ('7', [0b11111, 0b00001, 0b00010, 0b00100, 0b01000, 0b01000, 0b01000]),
+    ('8', [0b01110, 0b10001, 0b10001, 0b01110, 0b10001, 0b10001, 0b01110]),
+    ('9', [0b01110, 0b10001, 0b10001, 0b01111, 0b00001, 0b00010, 0b01100]),
+    ('A', [0b01110, 0b10001, 0b10001, 0b11111, 0b10001, 0b10001, 0b10001]),
+    ('B', [0b11110, 0b10001, 0b10001, 0b11110, 0b10001, 0b10001, 0b11110]),
+    ('C', [0b01110, 0b10001, 0b10000, 0b10000, 0b10000, 0b10001, 0b01110]),
+    ('D', [0b11100, 0b10010, 0b10001, 0b10001, 0b10001, 0b10010, 0b11100]),
+    ('E', [0b11111, 0b10000, 0b10000, 0b11110, 0b10000, 0b10000, 0b11111]),
+    ('F', [0b11111, 0b10000, 0b10000, 0b11110, 0b10000, 0b10000, 0b10000]),
+    ('G', [0b01110, 0b10001, 0b10000, 0b10111, 0b10001, 0b10001, 0b01111]),
+    ('H', [0b10001, 0b10001, 0b10001, 0b11111, 0b10001, 0b10001, 0b10001]),
+    ('J', [0b00111, 0b00010, 0b00010, 0b00010, 0b00010, 0b10010, 0b01100]),
+    ('K', [0b10001, 0b10010, 0b10100, 0b11000, 0b10100, 0b10010, 0b10001]),
+    ('M', [0b10001, 0b11011, 0b10101, 0b10101, 0b10001, 0b10001, 0b10001]),
+    ('N', [0b10001, 0b10001, 0b11001, 0b10101, 0b10011, 0b10001, 0b10001]),
+    ('P', [0b11110, 0b10001, 0b10001, 0b11110, 0b10000, 0b10000, 0b10000]),
+    ('Q', [0b01110, 0b10001, 0b10001, 0b10001, 0b10101, 0b10010, 0b01101]),
+    ('R', [0b11110, 0b10001, 0b10001, 0b11110, 0b10100, 0b10010, 0b10001]),
+    ('S', [0b01111, 0b10000, 0b10000, 0b01110, 0b00001, 0b00001, 0b11110]),
+    ('T', [0b11111, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100]),
+    ('U', [0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b01110]),
+    ('V', [0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b01010, 0b00100]),
+    ('W', [0b10001, 0b10001, 0b10001, 0b10101, 0b10101, 0b10101, 0b01010]),
+    ('X', [0b10001, 0b10001, 0b01010, 0b00100, 0b01010, 0b10001, 0b10001]),
+    ('Y', [0b10001, 0b10001, 0b10001, 0b01010, 0b00100, 0b00100, 0b00100]),
+    ('Z', [0b11111, 0b00001, 0b00010, 0b00100, 0b01000, 0b10000, 0b11111]),
+    ('+', [0b00000, 0b00100, 0b00100, 0b11111, 0b00100, 0b00100, 0b00000]),
+    ('-', [0b00000, 0b00000, 0b00000, 0b11111, 0b00000, 0b00000, 0b00000]),
+    ('*', [0b00000, 0b10001, 0b01010, 0b00100, 0b01010, 0b10001, 0b00000]),
+    ('=', [0b00000, 0b00000, 0b11111, 0b00000, 0b11111, 0b00000, 0b00000]),
+    ('?', [0b01110, 0b10001, 0b00001, 0b00010, 0b00100, 0b00000, 0b00100]),
+];
+
+/// 验证码图片
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CaptchaImage {
+    /// 验证码唯一标识
+    pub uuid: String,
+    /// Base64编码的PNG图片
+    pub img: String,
+    /// 是否开启验证码
+    pub captcha_enabled: bool,
+}
+
+/// 验证码服务
+pub struct CaptchaService;
+
+impl CaptchaService {
+    /// 是否开启验证码，由系统参数 `sys.account.captchaEnabled` 控制
+    pub async fn is_enabled() -> bool {
+        ConfigCache::get_bool(CONFIG_KEY_CAPTCHA_ENABLED, true).await
+    }
+
+    /// 生成验证码图片并缓存答案，未开启验证码时不生成图片
+    pub async fn create_captcha() -> Result<CaptchaImage> {
+        if !Self::is_enabled().await {
+            return Ok(CaptchaImage {
+                uuid: String::new(),
+                img: String::new(),
+                captcha_enabled: false,
+            });
+        }
+
+        let captcha_type = get_config()
+            .map(|config| config.get_captcha_type().to_string())
+            .unwrap_or_else(|| CAPTCHA_TYPE_MATH.to_string());
+
+        let (text, answer) = Self::generate(&captcha_type)?;
+        let png = Self::render_png(&text)?;
+
+        let uuid = Uuid::new_v4().to_string().replace('-', "");
+        let key = Self::cache_key(&uuid);
+        Self::redis()?
+            .set_ex(&key, &answer, (CAPTCHA_EXPIRATION * 60) as u64)
+            .await?;
+
+        Ok(CaptchaImage {
+            uuid,
+            img: STANDARD.encode(png),
+            captcha_enabled: true,
+        })
+    }
+
+    /// 校验验证码，无论成功与否验证码都会失效
+    pub async fn verify_captcha(uuid: &str, code: &str) -> Result<()> {
+        if uuid.trim().is_empty() || code.trim().is_empty() {
+            return Err(ZzylError::Captcha("验证码不能为空".to_string()));
+        }
+
+        let redis = Self::redis()?;
+        let key = Self::cache_key(uuid.trim());
+        let answer = redis.get(&key).await?;
+        redis.del(&key).await?;
+
+        match answer {
+            None => Err(ZzylError::Captcha("验证码已失效".to_string())),
+            Some(answer) if !answer.eq_ignore_ascii_case(code.trim()) => {
+                Err(ZzylError::Captcha("验证码错误".to_string()))
+            }
+            Some(_) => Ok(()),
+        }
+    }
+
+    /// 根据验证码类型生成展示文本与答案
+    pub fn generate(captcha_type: &str) -> Result<(String, String)> {
+        let mut rng = rand::thread_rng();
+        match captcha_type {
+            CAPTCHA_TYPE_MATH => {
+                let a: i32 = rng.gen_range(0..10);
+                let b: i32 = rng.gen_range(0..10);
+                let (text, answer) = match rng.gen_range(0..3) {
+                    0 => (format!("{}+{}=?", a, b), a + b),
+                    1 => (format!("{}-{}=?", a.max(b), a.min(b)), a.max(b) - a.min(b)),
+                    _ => (format!("{}*{}=?", a, b), a * b),
+                };
+                Ok((text, answer.to_string()))
+            }
+            CAPTCHA_TYPE_CHAR => {
+                let code: String = (0..CHAR_CAPTCHA_LENGTH)
+                    .map(|_| CHAR_CAPTCHA_CHARSET[rng.gen_range(0..CHAR_CAPTCHA_CHARSET.len())] as char)
+                    .collect();
+                Ok((code.clone(), code))
+            }
+            other => Err(ZzylError::Configuration(format!("不支持的验证码类型: {}", other))),
+        }
+    }
+
+    /// 将文本绘制为带干扰线和噪点的PNG图片
+    pub fn render_png(text: &str) -> Result<Vec<u8>> {
+        let mut rng = rand::thread_rng();
+        let (width, height) = (IMAGE_WIDTH as i32, IMAGE_HEIGHT as i32);
+        let mut pixels = vec![0u8; (IMAGE_WIDTH * IMAGE_HEIGHT * 3) as usize];
+
+        let background = [rng.gen_range(230..=255), rng.gen_range(230..=255), rng.gen_range(230..=255)];
+        for pixel in pixels.chunks_mut(3) {
+            pixel.copy_from_slice(&background);
+        }
+
+        let put_pixel = |pixels: &mut [u8], x: i32, y: i32, color: [u8; 3]| {
+            if x >= 0 && x < width && y >= 0 && y < height {
+                let offset = ((y * width + x) * 3) as usize;
+                pixels[offset..offset + 3].copy_from_slice(&color);
+            }
+        };
+
+        // 干扰线
+        for _ in 0..6 {
+            let color = Self::random_color(&mut rng, 120, 200);
+            let (x0, y0) = (rng.gen_range(0..width), rng.gen_range(0..height));
+            let (x1, y1) = (rng.gen_range(0..width), rng.gen_range(0..height));
+            let steps = (x1 - x0).abs().max((y1 - y0).abs()).max(1);
+            for step in 0..=steps {
+                let x = x0 + (x1 - x0) * step / steps;
+                let y = y0 + (y1 - y0) * step / steps;
+                put_pixel(&mut pixels, x, y, color);
+            }
+        }
+
+        // 字符
+        let chars: Vec<char> = text.chars().collect();
+        let glyph_width = (5 * GLYPH_SCALE) as i32;
+        let glyph_height = (7 * GLYPH_SCALE) as i32;
+        let slot = width / (chars.len() as i32 + 1);
+        for (index, ch) in chars.iter().enumerate() {
+            let glyph = match GLYPHS.iter().find(|(c, _)| *c == ch.to_ascii_uppercase()) {
+                Some((_, glyph)) => glyph,
+                None => continue,
+            };
+            let color = Self::random_color(&mut rng, 20, 120);
+            let origin_x = slot * (index as i32 + 1) - glyph_width / 2 + rng.gen_range(-3..=3);
+            let origin_y = (height - glyph_height) / 2 + rng.gen_range(-6..=6);
+            // 横向错切，使字符略微倾斜
+            let shear: i32 = rng.gen_range(-2..=2);
+
+            for (row, bits) in glyph.iter().enumerate() {
+                for col in 0..5 {
+                    if bits & (1 << (4 - col)) == 0 {
+                        continue;
+                    }
+                    for dy in 0..GLYPH_SCALE as i32 {
+                        let y = origin_y + row as i32 * GLYPH_SCALE as i32 + dy;
+                        let offset = shear * (y - origin_y - glyph_height / 2) * 4 / glyph_height;
+                        for dx in 0..GLYPH_SCALE as i32 {
+                            let x = origin_x + col * GLYPH_SCALE as i32 + dx + offset;
+                            put_pixel(&mut pixels, x, y, color);
+                        }
+                    }
+                }
+            }
+        }
+
+        // 噪点
+        for _ in 0..(IMAGE_WIDTH * IMAGE_HEIGHT / 20) {
+            let color = Self::random_color(&mut rng, 0, 255);
+            put_pixel(&mut pixels, rng.gen_range(0..width), rng.gen_range(0..height), color);
+        }
+
+        let mut buffer = Vec::new();
+        {
+            let mut encoder = png::Encoder::new(&mut buffer, IMAGE_WIDTH, IMAGE_HEIGHT);
+            encoder.set_color(png::ColorType::Rgb);
+            encoder.set_depth(png::BitDepth::Eight);
+            let mut writer = encoder.write_header()
+                .map_err(|e| ZzylError::System(format!("验证码图片生成失败: {}", e)))?;
+            writer.write_image_data(&pixels)
+                .map_err(|e| ZzylError::System(format!("验证码图片生成失败: {}", e)))?;
+        }
+
+        Ok(buffer)
+    }
+
+    /// 生成随机颜色
+    fn random_color(rng: &mut impl Rng, min: u8, max: u8) -> [u8; 3] {
+        [rng.gen_range(min..=max), rng.gen_range(min..=max), rng.gen_range(min..=max)]
+    }
+
+    /// 构建验证码缓存键
+    fn cache_key(uuid: &str) -> String {
+        format!("{}{}", CAPTCHA_CODE_KEY, uuid)
+    }
+
+    /// 获取Redis管理器
+    fn redis() -> Result<&'static RedisManager> {
+        get_redis_manager().ok_or_else(|| ZzylError::ServiceUnavailable("Redis未初始化".to_string()))
+    }
+}
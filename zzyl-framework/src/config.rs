@@ -34,6 +34,9 @@ pub struct ServerConfig {
     pub max_threads: i32,
     /// 最小空闲线程数
     pub min_spare_threads: i32,
+    /// 可信代理地址，仅来自这些地址的请求才采信转发头中的客户端IP
+    #[serde(default)]
+    pub trusted_proxies: Vec<String>,
 }
 
 /// 数据库配置
@@ -162,6 +165,95 @@ pub struct ThreadPoolConfig {
     pub keep_alive_seconds: i32,
 }
 
+/// 限流配置
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RateLimitConfig {
+    /// 是否启用限流
+    #[serde(default)]
+    pub enabled: bool,
+    /// 限流规则，按顺序匹配第一条
+    #[serde(default)]
+    pub rules: Vec<RateLimitRule>,
+}
+
+/// 限流规则
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RateLimitRule {
+    /// 请求路径，支持以 /** 结尾的前缀匹配
+    pub path: String,
+    /// 限流类型
+    #[serde(default)]
+    pub limit_type: LimitType,
+    /// 限流算法
+    #[serde(default)]
+    pub algorithm: LimitAlgorithm,
+    /// 时间窗口内允许的请求次数
+    pub count: u64,
+    /// 时间窗口（秒）
+    pub time: u64,
+}
+
+/// 限流类型
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LimitType {
+    /// 按路由全局限流
+    #[default]
+    Route,
+    /// 按客户端IP限流
+    Ip,
+    /// 按登录用户限流，未登录时按IP
+    User,
+}
+
+/// 限流算法
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LimitAlgorithm {
+    /// 固定窗口
+    #[default]
+    FixedWindow,
+    /// 滑动窗口
+    SlidingWindow,
+}
+
+/// 对象存储配置
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OssConfig {
+    /// 存储类型
+    #[serde(default)]
+    pub storage_type: OssStorageType,
+    /// 访问域名，为空时返回相对路径
+    #[serde(default)]
+    pub domain: String,
+    /// S3服务地址
+    #[serde(default)]
+    pub endpoint: String,
+    /// S3区域
+    #[serde(default)]
+    pub region: String,
+    /// S3存储桶
+    #[serde(default)]
+    pub bucket: String,
+    /// S3访问密钥ID
+    #[serde(default)]
+    pub access_key: String,
+    /// S3访问密钥
+    #[serde(default)]
+    pub secret_key: String,
+}
+
+/// 对象存储类型
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OssStorageType {
+    /// 本地磁盘，存放在 profile 目录下
+    #[default]
+    Local,
+    /// S3兼容的对象存储
+    S3,
+}
+
 /// 完整配置
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ZzylConfig {
@@ -175,6 +267,12 @@ pub struct ZzylConfig {
     pub user: UserConfig,
     /// Spring配置
     pub spring: SpringConfig,
+    /// 限流配置
+    #[serde(default)]
+    pub rate_limit: RateLimitConfig,
+    /// 对象存储配置
+    #[serde(default)]
+    pub oss: OssConfig,
 }
 
 impl ZzylConfig {
@@ -269,6 +367,7 @@ impl Default for ZzylConfig {
                 accept_count: 1000,
                 max_threads: 800,
                 min_spare_threads: 100,
+                trusted_proxies: Vec::new(),
             },
             logging: LoggingConfig {
                 level: "info".to_string(),
@@ -327,6 +426,8 @@ impl Default for ZzylConfig {
                     },
                 },
             },
+            rate_limit: RateLimitConfig::default(),
+            oss: OssConfig::default(),
         }
     }
 }
@@ -1,8 +1,11 @@
 use sqlx::{MySqlPool, Pool, MySql, Row};
 use sea_orm::{Database, DatabaseConnection, ConnectOptions, ConnectionTrait};
 use std::time::Duration;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+use zzyl_common::BaseEntity;
+use zzyl_common::error::{Result, ZzylError};
 use crate::config::{DatabaseConfig, PoolConfig};
-use crate::error::{Result, ZzylError};
 
 /// 数据库连接池
 pub struct DatabaseManager {
@@ -91,10 +94,13 @@ impl DatabaseManager {
         Ok(result)
     }
     
-    /// 执行事务
+    /// 执行事务，闭包返回错误时回滚
     pub async fn transaction<F, R>(&self, f: F) -> Result<R>
     where
-        F: FnOnce(&mut sqlx::Transaction<'_, MySql>) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<R>> + Send + '_>>,
+        F: for<'c> FnOnce(
+            &'c mut sqlx::Transaction<'static, MySql>,
+        ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<R>> + Send + 'c>>,
+        R: Send,
     {
         let mut tx = self.pool.begin().await
             .map_err(|e| ZzylError::Database(format!("Begin transaction failed: {}", e)))?;
@@ -233,6 +239,38 @@ impl DatabaseUtils {
             .replace("\x1a", "\\Z")
     }
     
+    /// 读取UUID列（以字符串存储）
+    pub fn get_uuid(row: &sqlx::mysql::MySqlRow, column: &str) -> Result<Option<Uuid>> {
+        let value: Option<String> = row.try_get(column)?;
+        value
+            .filter(|v| !v.is_empty())
+            .map(|v| Uuid::parse_str(&v)
+                .map_err(|e| ZzylError::Database(format!("列 {} 不是合法的UUID: {}", column, e))))
+            .transpose()
+    }
+    
+    /// 读取多行中的UUID列，忽略空值
+    pub fn get_uuids(rows: &[sqlx::mysql::MySqlRow], column: &str) -> Result<Vec<Uuid>> {
+        let mut ids = Vec::with_capacity(rows.len());
+        for row in rows {
+            if let Some(id) = Self::get_uuid(row, column)? {
+                ids.push(id);
+            }
+        }
+        Ok(ids)
+    }
+    
+    /// 读取基础实体列
+    pub fn get_base_entity(row: &sqlx::mysql::MySqlRow) -> Result<BaseEntity> {
+        Ok(BaseEntity {
+            create_by: row.try_get("create_by")?,
+            create_time: row.try_get::<Option<DateTime<Utc>>, _>("create_time")?,
+            update_by: row.try_get("update_by")?,
+            update_time: row.try_get::<Option<DateTime<Utc>>, _>("update_time")?,
+            remark: row.try_get("remark")?,
+        })
+    }
+    
     /// 验证SQL安全性（简单的SQL注入检查）
     pub fn is_sql_safe(sql: &str) -> bool {
         let dangerous_patterns = [
@@ -276,11 +314,21 @@ pub fn get_database_manager() -> Option<&'static DatabaseManager> {
     }
 }
 
+/// 获取数据库管理器（未初始化时返回错误）
+pub fn require_database_manager() -> Result<&'static DatabaseManager> {
+    get_database_manager().ok_or_else(|| ZzylError::ServiceUnavailable("数据库未初始化".to_string()))
+}
+
 /// 获取数据库连接池
 pub fn get_database_pool() -> Option<&'static MySqlPool> {
     get_database_manager().map(|manager| manager.get_pool())
 }
 
+/// 获取数据库连接池（未初始化时返回错误）
+pub fn require_database_pool() -> Result<&'static MySqlPool> {
+    get_database_pool().ok_or_else(|| ZzylError::ServiceUnavailable("数据库未初始化".to_string()))
+}
+
 /// 获取SeaORM连接
 pub fn get_sea_orm_connection() -> Option<&'static DatabaseConnection> {
     get_database_manager().map(|manager| manager.get_sea_orm_conn())
@@ -57,6 +57,23 @@ impl RedisManager {
         Ok(())
     }
     
+    /// 键不存在时设置值及过期时间（毫秒），返回是否设置成功
+    pub async fn set_nx_px(&self, key: &str, value: &str, millis: u64) -> Result<bool> {
+        let mut conn = self.connection_manager.clone();
+        let result: RedisResult<Option<String>> = redis::cmd("SET")
+            .arg(key)
+            .arg(value)
+            .arg("NX")
+            .arg("PX")
+            .arg(millis)
+            .query_async(&mut conn)
+            .await;
+        match result {
+            Ok(value) => Ok(value.is_some()),
+            Err(e) => Err(ZzylError::Redis(e)),
+        }
+    }
+    
     /// 获取字符串值
     pub async fn get(&self, key: &str) -> Result<Option<String>> {
         let mut conn = self.connection_manager.clone();
@@ -0,0 +1,9 @@
+pub mod token;
+pub mod auth;
+pub mod permission;
+pub mod data_scope;
+
+pub use token::*;
+pub use auth::*;
+pub use permission::*;
+pub use data_scope::*;
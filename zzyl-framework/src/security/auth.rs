@@ -0,0 +1,100 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+use axum::{
+    async_trait,
+    extract::{FromRequestParts, MatchedPath, Request, State},
+    http::request::Parts,
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use tracing::debug;
+use zzyl_common::error::ZzylError;
+use zzyl_common::LoginUser;
+use crate::security::token::TokenService;
+
+/// 匿名访问地址（标注了 Anonymous 注解的路由）
+#[derive(Debug, Clone, Default)]
+pub struct PermitAllUrl {
+    urls: Arc<HashSet<String>>,
+}
+
+impl PermitAllUrl {
+    /// 创建匿名访问地址集合
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 添加匿名访问地址，支持以 `/**` 结尾的前缀匹配
+    pub fn permit(mut self, path: &str) -> Self {
+        Arc::make_mut(&mut self.urls).insert(path.to_string());
+        self
+    }
+
+    /// 判断地址是否允许匿名访问
+    pub fn is_anonymous(&self, path: &str) -> bool {
+        self.urls.iter().any(|url| match url.strip_suffix("/**") {
+            Some(prefix) => path == prefix || path.starts_with(&format!("{}/", prefix)),
+            None => url == path,
+        })
+    }
+}
+
+/// 令牌认证中间件
+///
+/// 需要通过 `route_layer` 挂载，以便取得匹配到的路由模板。认证成功后
+/// 将 `LoginUser` 放入请求扩展，处理器可通过 `CurrentUser` 提取。
+pub async fn auth_middleware(
+    State(permit_all): State<PermitAllUrl>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    let path = request.extensions()
+        .get::<MatchedPath>()
+        .map(|matched| matched.as_str().to_string())
+        .unwrap_or_else(|| request.uri().path().to_string());
+    let anonymous = permit_all.is_anonymous(&path);
+
+    match TokenService::get_token(request.headers()) {
+        Some(token) => match TokenService::get_login_user(&token).await {
+            Ok(login_user) => {
+                request.extensions_mut().insert(login_user);
+            }
+            Err(e) if !anonymous => {
+                debug!("请求 {} 认证失败: {}", path, e);
+                return error_response(e);
+            }
+            Err(_) => {}
+        },
+        None if !anonymous => {
+            return error_response(ZzylError::Authentication(format!("请求访问：{}，认证失败，无法访问系统资源", path)));
+        }
+        None => {}
+    }
+
+    next.run(request).await
+}
+
+/// 当前登录用户提取器
+#[derive(Debug, Clone)]
+pub struct CurrentUser(pub LoginUser);
+
+#[async_trait]
+impl<S> FromRequestParts<S> for CurrentUser
+where
+    S: Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> std::result::Result<Self, Self::Rejection> {
+        parts.extensions
+            .get::<LoginUser>()
+            .cloned()
+            .map(CurrentUser)
+            .ok_or_else(|| error_response(ZzylError::Authentication("获取用户信息异常".to_string())))
+    }
+}
+
+/// 将错误转换为带状态码的响应
+pub(crate) fn error_response(error: ZzylError) -> Response {
+    error.into_response()
+}
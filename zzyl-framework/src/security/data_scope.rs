@@ -0,0 +1,131 @@
+use zzyl_common::annotation::DataScope as DataScopeAnnotation;
+use zzyl_common::constants::constants::ROLE_NORMAL;
+use zzyl_common::enums::DataScope;
+use zzyl_common::LoginUser;
+use crate::security::permission::PermissionService;
+
+/// 数据权限过滤条件
+///
+/// `sql` 为可直接拼接到 WHERE 之后的条件片段，其中的 `?` 占位符按顺序绑定 `params`。
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DataScopeSql {
+    /// 条件片段
+    pub sql: String,
+    /// 绑定参数
+    pub params: Vec<String>,
+}
+
+impl DataScopeSql {
+    /// 以 ` AND (...)` 形式返回条件片段，无过滤条件时返回空字符串
+    pub fn and_clause(&self) -> String {
+        if self.sql.is_empty() {
+            String::new()
+        } else {
+            format!(" AND ({})", self.sql)
+        }
+    }
+
+    /// 是否无需过滤
+    pub fn is_empty(&self) -> bool {
+        self.sql.is_empty()
+    }
+}
+
+/// 数据权限过滤器
+pub struct DataScopeFilter;
+
+impl DataScopeFilter {
+    /// 根据登录用户的角色生成数据权限过滤条件
+    ///
+    /// 多个角色之间取并集，任一角色拥有全部数据权限时不做过滤；
+    /// 同时拥有本部门及以下权限时，本部门权限被其覆盖。
+    pub fn build(login_user: &LoginUser, annotation: &DataScopeAnnotation) -> DataScopeSql {
+        if PermissionService::is_admin(login_user) {
+            return DataScopeSql::default();
+        }
+
+        let dept_alias = Self::alias(annotation.dept_alias.as_deref());
+        let user_alias = annotation.user_alias.as_deref().filter(|alias| !alias.is_empty());
+
+        let active_roles: Vec<_> = login_user.roles.iter()
+            .filter(|role| role.status == ROLE_NORMAL)
+            .collect();
+        let scopes: Vec<DataScope> = active_roles.iter()
+            .map(|role| Self::parse_scope(&role.data_scope))
+            .collect();
+
+        if scopes.contains(&DataScope::All) {
+            return DataScopeSql::default();
+        }
+
+        let mut conditions = Vec::new();
+        let mut params = Vec::new();
+
+        let custom_role_ids: Vec<String> = active_roles.iter()
+            .filter(|role| Self::parse_scope(&role.data_scope) == DataScope::Custom)
+            .map(|role| role.role_id.to_string())
+            .collect();
+        if !custom_role_ids.is_empty() {
+            let placeholders = vec!["?"; custom_role_ids.len()].join(", ");
+            conditions.push(format!(
+                "{}dept_id IN (SELECT dept_id FROM sys_role_dept WHERE role_id IN ({}))",
+                dept_alias, placeholders
+            ));
+            params.extend(custom_role_ids);
+        }
+
+        let dept_id = login_user.dept.as_ref().map(|dept| dept.dept_id.to_string());
+        if scopes.contains(&DataScope::DeptAndChild) {
+            if let Some(dept_id) = &dept_id {
+                conditions.push(format!(
+                    "{}dept_id IN (SELECT dept_id FROM sys_dept WHERE dept_id = ? OR FIND_IN_SET(?, ancestors))",
+                    dept_alias
+                ));
+                params.push(dept_id.clone());
+                params.push(dept_id.clone());
+            }
+        } else if scopes.contains(&DataScope::Dept) {
+            if let Some(dept_id) = &dept_id {
+                conditions.push(format!("{}dept_id = ?", dept_alias));
+                params.push(dept_id.clone());
+            }
+        }
+
+        if scopes.contains(&DataScope::SelfOnly) {
+            match user_alias {
+                Some(alias) => {
+                    conditions.push(format!("{}.user_id = ?", alias));
+                    params.push(login_user.user_id.to_string());
+                }
+                // 未指定用户表别名时仅本人权限无法生效，不返回任何数据
+                None => conditions.push("1 = 0".to_string()),
+            }
+        }
+
+        if conditions.is_empty() {
+            // 没有任何有效的数据权限，不返回任何数据
+            return DataScopeSql {
+                sql: "1 = 0".to_string(),
+                params: Vec::new(),
+            };
+        }
+
+        DataScopeSql {
+            sql: conditions.join(" OR "),
+            params,
+        }
+    }
+
+    /// 解析角色的数据范围
+    fn parse_scope(data_scope: &str) -> DataScope {
+        data_scope.trim().parse::<i32>().map(DataScope::from).unwrap_or(DataScope::SelfOnly)
+    }
+
+    /// 构建列前缀
+    fn alias(alias: Option<&str>) -> String {
+        match alias.filter(|alias| !alias.is_empty()) {
+            Some(alias) => format!("{}.", alias),
+            None => String::new(),
+        }
+    }
+}
@@ -0,0 +1,176 @@
+use axum::{
+    extract::{Request, State},
+    middleware::Next,
+    response::Response,
+};
+use tracing::warn;
+use zzyl_common::annotation::PreAuthorize;
+use zzyl_common::error::{Result, ZzylError};
+use zzyl_common::LoginUser;
+use crate::security::auth::error_response;
+
+/// 所有权限标识
+pub const ALL_PERMISSION: &str = "*:*:*";
+
+/// 超级管理员角色权限标识
+pub const SUPER_ADMIN: &str = "admin";
+
+/// 权限字符串分隔符
+const PERMISSION_DELIMITER: char = ',';
+
+/// 权限服务
+pub struct PermissionService;
+
+impl PermissionService {
+    /// 验证用户是否具备某权限
+    pub fn has_permi(login_user: &LoginUser, permission: &str) -> bool {
+        if permission.trim().is_empty() {
+            return false;
+        }
+        if Self::is_admin(login_user) {
+            return true;
+        }
+        login_user.permissions.iter().any(|granted| Self::matches(granted, permission.trim()))
+    }
+
+    /// 验证用户是否具有以下任意一个权限
+    pub fn has_any_permi(login_user: &LoginUser, permissions: &str) -> bool {
+        Self::split(permissions).any(|permission| Self::has_permi(login_user, permission))
+    }
+
+    /// 验证用户是否具有以下全部权限
+    pub fn has_all_permi(login_user: &LoginUser, permissions: &str) -> bool {
+        let mut list = Self::split(permissions).peekable();
+        list.peek().is_some() && list.all(|permission| Self::has_permi(login_user, permission))
+    }
+
+    /// 判断用户是否拥有某个角色
+    pub fn has_role(login_user: &LoginUser, role: &str) -> bool {
+        let role = role.trim();
+        if role.is_empty() {
+            return false;
+        }
+        login_user.role_keys.iter().any(|key| key == SUPER_ADMIN || key == role)
+    }
+
+    /// 验证用户是否具有以下任意一个角色
+    pub fn has_any_roles(login_user: &LoginUser, roles: &str) -> bool {
+        Self::split(roles).any(|role| Self::has_role(login_user, role))
+    }
+
+    /// 验证用户是否具有以下全部角色
+    pub fn has_all_roles(login_user: &LoginUser, roles: &str) -> bool {
+        let mut list = Self::split(roles).peekable();
+        list.peek().is_some() && list.all(|role| Self::has_role(login_user, role))
+    }
+
+    /// 是否为超级管理员
+    pub fn is_admin(login_user: &LoginUser) -> bool {
+        login_user.role_keys.iter().any(|key| key == SUPER_ADMIN)
+            || login_user.permissions.iter().any(|permission| permission == ALL_PERMISSION)
+    }
+
+    /// 校验预授权注解
+    ///
+    /// `has_perm` 与 `has_role` 均支持逗号分隔的多个值，`logical` 为 AND 时要求全部满足，
+    /// 为 OR 时满足任意一项即可；同时配置权限与角色时按相同逻辑组合。
+    pub fn check(login_user: &LoginUser, pre_authorize: &PreAuthorize) -> Result<()> {
+        let is_or = pre_authorize.logical
+            .as_deref()
+            .map(|logical| logical.eq_ignore_ascii_case("OR"))
+            .unwrap_or(false);
+
+        let mut results = Vec::new();
+        if let Some(perms) = pre_authorize.has_perm.as_deref().filter(|perms| !perms.trim().is_empty()) {
+            results.push(if is_or {
+                Self::has_any_permi(login_user, perms)
+            } else {
+                Self::has_all_permi(login_user, perms)
+            });
+        }
+        if let Some(roles) = pre_authorize.has_role.as_deref().filter(|roles| !roles.trim().is_empty()) {
+            results.push(if is_or {
+                Self::has_any_roles(login_user, roles)
+            } else {
+                Self::has_all_roles(login_user, roles)
+            });
+        }
+
+        let granted = if results.is_empty() {
+            true
+        } else if is_or {
+            results.iter().any(|granted| *granted)
+        } else {
+            results.iter().all(|granted| *granted)
+        };
+
+        if granted {
+            Ok(())
+        } else {
+            Err(ZzylError::Permission("没有权限，请联系管理员授权".to_string()))
+        }
+    }
+
+    /// 判断已授予的权限是否匹配所需权限
+    ///
+    /// 按 `:` 分段比较，`*` 匹配任意一段，位于末尾时匹配剩余所有段，
+    /// 如 `system:user:*` 匹配 `system:user:list`，`*:*:*` 匹配全部权限。
+    pub fn matches(granted: &str, required: &str) -> bool {
+        if granted == required || granted == ALL_PERMISSION {
+            return true;
+        }
+
+        let granted_parts: Vec<&str> = granted.split(':').collect();
+        let required_parts: Vec<&str> = required.split(':').collect();
+
+        for (index, granted_part) in granted_parts.iter().enumerate() {
+            let is_last = index == granted_parts.len() - 1;
+            match required_parts.get(index) {
+                Some(_) if *granted_part == "*" && is_last => return true,
+                Some(required_part) if *granted_part == "*" || granted_part == required_part => {}
+                _ => return false,
+            }
+        }
+
+        granted_parts.len() == required_parts.len()
+    }
+
+    /// 拆分逗号分隔的字符串
+    fn split(value: &str) -> impl Iterator<Item = &str> {
+        value.split(PERMISSION_DELIMITER)
+            .map(|item| item.trim())
+            .filter(|item| !item.is_empty())
+    }
+}
+
+/// 预授权守卫
+///
+/// 以路由层挂载在需要鉴权的路由上，须位于认证中间件之后：
+///
+/// ```ignore
+/// .route("/system/user/list", get(list).route_layer(
+///     middleware::from_fn_with_state(pre_authorize!("system:user:list"), permission_guard)))
+/// ```
+pub async fn permission_guard(
+    State(pre_authorize): State<PreAuthorize>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let login_user = match request.extensions().get::<LoginUser>() {
+        Some(login_user) => login_user,
+        None => return error_response(ZzylError::Authentication("获取用户信息异常".to_string())),
+    };
+
+    if let Err(e) = PermissionService::check(login_user, &pre_authorize) {
+        warn!(
+            "用户 {} 访问 {} 权限不足，需要权限: {:?}，角色: {:?}",
+            login_user.username,
+            request.uri().path(),
+            pre_authorize.has_perm,
+            pre_authorize.has_role
+        );
+        return error_response(e);
+    }
+
+    next.run(request).await
+}
@@ -0,0 +1,134 @@
+use axum::http::HeaderMap;
+use chrono::Utc;
+use uuid::Uuid;
+use zzyl_common::constants::constants::{AUTHORIZATION_HEADER, CACHE_USER_SESSION_KEY, TOKEN_PREFIX};
+use zzyl_common::error::{Result, ZzylError};
+use zzyl_common::utils::{SecurityUtils, UserAgentUtils};
+use zzyl_common::{LoginUser, SysUserOnline};
+use crate::config::{get_config, ZzylConfig};
+use crate::redis::{get_redis_manager, RedisManager, RedisUtils};
+use crate::web::request::ClientInfo;
+
+/// 令牌有效期（秒），与 SecurityUtils::generate_token 保持一致
+pub const TOKEN_EXPIRE_SECONDS: u64 = 7 * 24 * 60 * 60;
+
+/// 令牌服务
+pub struct TokenService;
+
+impl TokenService {
+    /// 从请求头中获取令牌
+    pub fn get_token(headers: &HeaderMap) -> Option<String> {
+        let value = headers.get(AUTHORIZATION_HEADER)?.to_str().ok()?;
+        let token = value.strip_prefix(TOKEN_PREFIX).unwrap_or(value).trim();
+        if token.is_empty() {
+            None
+        } else {
+            Some(token.to_string())
+        }
+    }
+
+    /// 创建令牌，缓存登录用户并登记在线会话
+    pub async fn create_token(login_user: &LoginUser, client: &ClientInfo) -> Result<String> {
+        let token = SecurityUtils::generate_token(&login_user.user_id, &login_user.username, &Self::jwt_secret())?;
+        Self::refresh_login_user(login_user).await?;
+
+        let session = SysUserOnline {
+            token_id: Self::token_id(&token),
+            dept_name: login_user.dept.as_ref().map(|dept| dept.dept_name.clone()),
+            user_name: login_user.username.clone(),
+            ipaddr: client.ip.clone(),
+            login_location: None,
+            browser: Some(UserAgentUtils::get_browser(&client.user_agent)),
+            os: Some(UserAgentUtils::get_os(&client.user_agent)),
+            login_time: Utc::now(),
+        };
+        let redis = Self::redis()?;
+        redis.set_json_ex(&Self::session_key(&session.token_id), &session, TOKEN_EXPIRE_SECONDS).await?;
+        redis.sadd(CACHE_USER_SESSION_KEY, &session.token_id).await?;
+
+        Ok(token)
+    }
+
+    /// 校验令牌并获取缓存的登录用户，会话已被注销的令牌视为失效
+    pub async fn get_login_user(token: &str) -> Result<LoginUser> {
+        let claims = SecurityUtils::verify_token(token, &Self::jwt_secret())
+            .map_err(|_| ZzylError::Authentication("令牌无效或已过期".to_string()))?;
+        let user_id = claims.get_user_id()
+            .map_err(|_| ZzylError::Authentication("令牌格式错误".to_string()))?;
+
+        let redis = Self::redis()?;
+        if !redis.exists(&Self::session_key(&Self::token_id(token))).await? {
+            return Err(ZzylError::Authentication("登录状态已过期，请重新登录".to_string()));
+        }
+
+        let key = RedisUtils::build_token_cache_key(&user_id.to_string());
+        redis.get_json::<LoginUser>(&key)
+            .await?
+            .ok_or_else(|| ZzylError::Authentication("登录状态已过期，请重新登录".to_string()))
+    }
+
+    /// 计算令牌对应的会话编号
+    pub fn token_id(token: &str) -> String {
+        SecurityUtils::sha256_hash(token)
+    }
+
+    /// 查询全部在线会话，同时清理已过期的会话编号
+    pub async fn list_sessions() -> Result<Vec<SysUserOnline>> {
+        let redis = Self::redis()?;
+        let mut sessions = Vec::new();
+        for token_id in redis.smembers(CACHE_USER_SESSION_KEY).await? {
+            match redis.get_json::<SysUserOnline>(&Self::session_key(&token_id)).await? {
+                Some(session) => sessions.push(session),
+                None => {
+                    redis.srem(CACHE_USER_SESSION_KEY, &token_id).await?;
+                }
+            }
+        }
+        Ok(sessions)
+    }
+
+    /// 注销在线会话，对应令牌立即失效
+    pub async fn delete_session(token_id: &str) -> Result<bool> {
+        let redis = Self::redis()?;
+        let deleted = redis.del(&Self::session_key(token_id)).await?;
+        redis.srem(CACHE_USER_SESSION_KEY, token_id).await?;
+        Ok(deleted > 0)
+    }
+
+    /// 获取用户当前缓存的登录信息，未登录时返回 None
+    pub async fn get_cached_login_user(user_id: &Uuid) -> Result<Option<LoginUser>> {
+        let key = RedisUtils::build_token_cache_key(&user_id.to_string());
+        Self::redis()?.get_json::<LoginUser>(&key).await
+    }
+
+    /// 刷新缓存的登录用户
+    pub async fn refresh_login_user(login_user: &LoginUser) -> Result<()> {
+        let key = RedisUtils::build_token_cache_key(&login_user.user_id.to_string());
+        Self::redis()?.set_json_ex(&key, login_user, TOKEN_EXPIRE_SECONDS).await
+    }
+
+    /// 删除缓存的登录用户
+    pub async fn delete_login_user(user_id: &Uuid) -> Result<()> {
+        let key = RedisUtils::build_token_cache_key(&user_id.to_string());
+        Self::redis()?.del(&key).await?;
+        Ok(())
+    }
+
+    /// 构建在线会话缓存键
+    fn session_key(token_id: &str) -> String {
+        RedisUtils::build_cache_key(CACHE_USER_SESSION_KEY, token_id)
+    }
+
+    /// 获取JWT密钥
+    fn jwt_secret() -> String {
+        match get_config() {
+            Some(config) => config.get_jwt_secret(),
+            None => ZzylConfig::default().get_jwt_secret(),
+        }
+    }
+
+    /// 获取Redis管理器
+    fn redis() -> Result<&'static RedisManager> {
+        get_redis_manager().ok_or_else(|| ZzylError::ServiceUnavailable("Redis未初始化".to_string()))
+    }
+}
@@ -0,0 +1,11 @@
+pub mod request;
+pub mod log;
+pub mod repeat_submit;
+pub mod rate_limit;
+pub mod route;
+
+pub use request::*;
+pub use log::*;
+pub use repeat_submit::*;
+pub use rate_limit::*;
+pub use route::*;
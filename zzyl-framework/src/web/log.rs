@@ -0,0 +1,218 @@
+use std::future::Future;
+use std::sync::OnceLock;
+use std::time::Instant;
+use axum::{
+    body::{to_bytes, Body},
+    extract::{MatchedPath, Request, State},
+    http::{header, HeaderMap},
+    middleware::Next,
+    response::Response,
+};
+use chrono::Utc;
+use tokio::sync::mpsc::{self, UnboundedSender};
+use tracing::{error, warn};
+use uuid::Uuid;
+use zzyl_common::annotation::Log;
+use zzyl_common::enums::{BusinessType, OperStatus};
+use zzyl_common::error::{Result, ZzylError};
+use zzyl_common::{LoginUser, SysOperLog};
+use crate::security::auth::error_response;
+use crate::web::request::RequestUtils;
+
+/// 请求参数与返回结果的最大保存长度
+pub const OPER_LOG_MAX_LENGTH: usize = 2000;
+
+/// 读取请求体的最大字节数
+pub(crate) const MAX_BODY_BYTES: usize = 10 * 1024 * 1024;
+
+/// 需要脱敏的请求参数字段
+const EXCLUDE_PROPERTIES: [&str; 6] = [
+    "password", "oldPassword", "newPassword", "confirmPassword", "old_password", "new_password",
+];
+
+/// 操作日志发送通道
+static OPER_LOG_SENDER: OnceLock<UnboundedSender<SysOperLog>> = OnceLock::new();
+
+/// 初始化操作日志记录任务
+///
+/// 日志经通道交由后台任务逐条持久化，不阻塞请求处理；重复初始化时忽略。
+pub fn init_oper_log_recorder<F, Fut>(recorder: F)
+where
+    F: Fn(SysOperLog) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<()>> + Send + 'static,
+{
+    let (sender, mut receiver) = mpsc::unbounded_channel::<SysOperLog>();
+    if OPER_LOG_SENDER.set(sender).is_err() {
+        warn!("操作日志记录任务已初始化");
+        return;
+    }
+
+    tokio::spawn(async move {
+        while let Some(oper_log) = receiver.recv().await {
+            if let Err(e) = recorder(oper_log).await {
+                error!("记录操作日志失败: {}", e);
+            }
+        }
+    });
+}
+
+/// 提交操作日志
+fn record_oper_log(oper_log: SysOperLog) {
+    match OPER_LOG_SENDER.get() {
+        Some(sender) => {
+            if sender.send(oper_log).is_err() {
+                warn!("操作日志记录任务已停止");
+            }
+        }
+        None => warn!("操作日志记录任务未初始化"),
+    }
+}
+
+/// 操作日志中间件
+///
+/// 以路由层挂载在需要审计的路由上，须位于认证中间件之后：
+///
+/// ```ignore
+/// .route("/system/user", post(add).route_layer(
+///     middleware::from_fn_with_state(log!("用户管理", i32::from(BusinessType::Insert)), oper_log_layer)))
+/// ```
+pub async fn oper_log_layer(State(log): State<Log>, request: Request, next: Next) -> Response {
+    let start = Instant::now();
+    let (parts, body) = request.into_parts();
+
+    let login_user = parts.extensions.get::<LoginUser>().cloned();
+    let request_method = parts.method.to_string();
+    let oper_url = parts.uri.path().to_string();
+    let method = parts.extensions.get::<MatchedPath>()
+        .map(|path| format!("{} {}", request_method, path.as_str()))
+        .unwrap_or_else(|| format!("{} {}", request_method, oper_url));
+    let oper_ip = RequestUtils::get_client_ip(&parts.headers, &parts.extensions);
+
+    let save_request = log.is_save_request_data.unwrap_or(true);
+    let (body, oper_param) = if save_request && !is_multipart(&parts.headers) {
+        let bytes = match to_bytes(body, MAX_BODY_BYTES).await {
+            Ok(bytes) => bytes,
+            Err(e) => return error_response(ZzylError::InvalidParameter(format!("读取请求参数失败: {}", e))),
+        };
+        let param = build_oper_param(parts.uri.query(), &bytes);
+        (Body::from(bytes), param)
+    } else {
+        (body, None)
+    };
+
+    let response = next.run(Request::from_parts(parts, body)).await;
+
+    let mut status = if response.status().is_success() { OperStatus::Success } else { OperStatus::Fail };
+    let mut error_msg = None;
+    let save_response = log.is_save_response_data.unwrap_or(true);
+    let (response, json_result) = if is_json(response.headers()) {
+        let (parts, body) = response.into_parts();
+        let bytes = match to_bytes(body, MAX_BODY_BYTES).await {
+            Ok(bytes) => bytes,
+            Err(e) => return error_response(ZzylError::System(format!("读取返回结果失败: {}", e))),
+        };
+        if let Ok(value) = serde_json::from_slice::<serde_json::Value>(&bytes) {
+            let code = value.get("code").and_then(|code| code.as_i64());
+            if code.is_some_and(|code| code != 200) {
+                status = OperStatus::Fail;
+            }
+            if status == OperStatus::Fail {
+                error_msg = value.get("msg").and_then(|msg| msg.as_str()).map(truncate);
+            }
+        }
+        let json_result = save_response.then(|| truncate(&String::from_utf8_lossy(&bytes)));
+        (Response::from_parts(parts, Body::from(bytes)), json_result)
+    } else {
+        (response, None)
+    };
+
+    if status == OperStatus::Fail && error_msg.is_none() {
+        error_msg = Some(response.status().to_string());
+    }
+
+    record_oper_log(SysOperLog {
+        oper_id: Uuid::new_v4(),
+        title: log.title.clone(),
+        method: Some(method),
+        request_method: Some(request_method),
+        business_type: log.business_type.unwrap_or_else(|| i32::from(BusinessType::Other)),
+        oper_name: login_user.as_ref().map(|user| user.username.clone()),
+        dept_name: login_user.as_ref()
+            .and_then(|user| user.dept.as_ref())
+            .map(|dept| dept.dept_name.clone()),
+        oper_url: Some(oper_url),
+        oper_ip: Some(oper_ip),
+        oper_location: None,
+        oper_param,
+        json_result,
+        status: i32::from(status),
+        error_msg,
+        oper_time: Some(Utc::now()),
+        cost_time: Some(start.elapsed().as_millis() as i64),
+    });
+
+    response
+}
+
+/// 构建请求参数，查询参数与请求体均存在时以换行分隔
+fn build_oper_param(query: Option<&str>, body: &[u8]) -> Option<String> {
+    let mut params = Vec::new();
+    if let Some(query) = query.filter(|query| !query.is_empty()) {
+        params.push(query.to_string());
+    }
+    if !body.is_empty() {
+        let body = match serde_json::from_slice::<serde_json::Value>(body) {
+            Ok(mut value) => {
+                mask_sensitive(&mut value);
+                value.to_string()
+            }
+            Err(_) => String::from_utf8_lossy(body).into_owned(),
+        };
+        params.push(body);
+    }
+
+    if params.is_empty() {
+        None
+    } else {
+        Some(truncate(&params.join("\n")))
+    }
+}
+
+/// 脱敏密码类字段
+fn mask_sensitive(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, item) in map.iter_mut() {
+                if EXCLUDE_PROPERTIES.contains(&key.as_str()) {
+                    *item = serde_json::Value::String("******".to_string());
+                } else {
+                    mask_sensitive(item);
+                }
+            }
+        }
+        serde_json::Value::Array(items) => items.iter_mut().for_each(mask_sensitive),
+        _ => {}
+    }
+}
+
+/// 按字符截断到最大保存长度
+fn truncate(value: &str) -> String {
+    value.chars().take(OPER_LOG_MAX_LENGTH).collect()
+}
+
+/// 是否为文件上传请求
+fn is_multipart(headers: &HeaderMap) -> bool {
+    content_type(headers).starts_with("multipart/")
+}
+
+/// 是否为JSON响应
+fn is_json(headers: &HeaderMap) -> bool {
+    content_type(headers).starts_with("application/json")
+}
+
+/// 获取Content-Type
+fn content_type(headers: &HeaderMap) -> &str {
+    headers.get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or("")
+}
@@ -0,0 +1,238 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use axum::{
+    extract::{Request, State},
+    http::{header, HeaderValue},
+    middleware::Next,
+    response::Response,
+};
+use redis::Script;
+use tracing::warn;
+use zzyl_common::error::{Result, ZzylError};
+use zzyl_common::LoginUser;
+use crate::config::{LimitAlgorithm, LimitType, RateLimitConfig, RateLimitRule};
+use crate::redis::{get_redis_manager, RedisManager, RedisUtils};
+use crate::security::auth::error_response;
+use crate::web::request::RequestUtils;
+
+/// 本地窗口数量超过该值时清理过期窗口
+const LOCAL_WINDOW_CLEAN_THRESHOLD: usize = 10000;
+
+/// 固定窗口限流脚本，返回 {当前次数, 剩余毫秒}
+const FIXED_WINDOW_SCRIPT: &str = r#"
+local current = redis.call('INCR', KEYS[1])
+if current == 1 then
+    redis.call('PEXPIRE', KEYS[1], ARGV[1])
+end
+return {current, redis.call('PTTL', KEYS[1])}
+"#;
+
+/// 滑动窗口限流脚本，返回 {是否放行, 需等待毫秒}
+const SLIDING_WINDOW_SCRIPT: &str = r#"
+local now = tonumber(ARGV[1])
+local window = tonumber(ARGV[2])
+local limit = tonumber(ARGV[3])
+redis.call('ZREMRANGEBYSCORE', KEYS[1], 0, now - window)
+if redis.call('ZCARD', KEYS[1]) < limit then
+    redis.call('ZADD', KEYS[1], now, ARGV[4])
+    redis.call('PEXPIRE', KEYS[1], window)
+    return {1, 0}
+end
+local oldest = redis.call('ZRANGE', KEYS[1], 0, 0, 'WITHSCORES')
+return {0, tonumber(oldest[2]) + window - now}
+"#;
+
+/// 限流判定结果
+enum Decision {
+    /// 放行
+    Allow,
+    /// 拒绝，附带建议重试等待时间
+    Reject(Duration),
+}
+
+/// 本地限流窗口
+enum LocalWindow {
+    /// 固定窗口：窗口开始时间与已计数
+    Fixed(Instant, u64),
+    /// 滑动窗口：窗口内请求时间
+    Sliding(VecDeque<Instant>),
+}
+
+/// 限流器
+///
+/// 优先使用Redis在多实例间共享计数，Redis不可用时退化为本实例内存计数。
+#[derive(Clone)]
+pub struct RateLimiter {
+    rules: Arc<Vec<RateLimitRule>>,
+    local: Arc<Mutex<HashMap<String, LocalWindow>>>,
+}
+
+impl RateLimiter {
+    /// 根据配置创建限流器，未启用时不包含任何规则
+    pub fn from_config(config: &RateLimitConfig) -> Self {
+        let rules = if config.enabled {
+            config.rules.iter()
+                .filter(|rule| rule.count > 0 && rule.time > 0)
+                .cloned()
+                .collect()
+        } else {
+            Vec::new()
+        };
+        Self {
+            rules: Arc::new(rules),
+            local: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// 是否配置了限流规则
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// 查找匹配请求路径的第一条规则
+    fn match_rule(&self, path: &str) -> Option<&RateLimitRule> {
+        self.rules.iter().find(|rule| match rule.path.strip_suffix("/**") {
+            Some(prefix) => prefix.is_empty() || path == prefix || path.starts_with(&format!("{}/", prefix)),
+            None => path == rule.path,
+        })
+    }
+
+    /// 判定请求是否放行
+    async fn acquire(&self, key: &str, rule: &RateLimitRule) -> Decision {
+        if let Some(redis) = get_redis_manager() {
+            match Self::acquire_redis(redis, key, rule).await {
+                Ok(decision) => return decision,
+                Err(e) => warn!("Redis限流失败，使用本地限流: {}", e),
+            }
+        }
+        self.acquire_local(key, rule)
+    }
+
+    /// 基于Redis的限流
+    async fn acquire_redis(redis: &RedisManager, key: &str, rule: &RateLimitRule) -> Result<Decision> {
+        let mut conn = redis.get_connection_manager().clone();
+        let window_millis = rule.time * 1000;
+        match rule.algorithm {
+            LimitAlgorithm::FixedWindow => {
+                let (current, ttl): (u64, i64) = Script::new(FIXED_WINDOW_SCRIPT)
+                    .key(key)
+                    .arg(window_millis)
+                    .invoke_async(&mut conn)
+                    .await
+                    .map_err(ZzylError::Redis)?;
+                if current > rule.count {
+                    Ok(Decision::Reject(Duration::from_millis(ttl.max(0) as u64)))
+                } else {
+                    Ok(Decision::Allow)
+                }
+            }
+            LimitAlgorithm::SlidingWindow => {
+                let now = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .unwrap_or_default()
+                    .as_millis() as u64;
+                let member = format!("{}-{}", now, uuid::Uuid::new_v4());
+                let (allowed, wait): (i64, i64) = Script::new(SLIDING_WINDOW_SCRIPT)
+                    .key(key)
+                    .arg(now)
+                    .arg(window_millis)
+                    .arg(rule.count)
+                    .arg(member)
+                    .invoke_async(&mut conn)
+                    .await
+                    .map_err(ZzylError::Redis)?;
+                if allowed == 1 {
+                    Ok(Decision::Allow)
+                } else {
+                    Ok(Decision::Reject(Duration::from_millis(wait.max(0) as u64)))
+                }
+            }
+        }
+    }
+
+    /// 基于本地内存的限流
+    fn acquire_local(&self, key: &str, rule: &RateLimitRule) -> Decision {
+        let window = Duration::from_secs(rule.time);
+        let now = Instant::now();
+        let mut local = match self.local.lock() {
+            Ok(local) => local,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+
+        if local.len() > LOCAL_WINDOW_CLEAN_THRESHOLD {
+            let max_window = Duration::from_secs(self.rules.iter().map(|rule| rule.time).max().unwrap_or(rule.time));
+            local.retain(|_, entry| match entry {
+                LocalWindow::Fixed(start, _) => now.duration_since(*start) < max_window,
+                LocalWindow::Sliding(requests) => requests.back()
+                    .is_some_and(|last| now.duration_since(*last) < max_window),
+            });
+        }
+
+        let entry = local.entry(key.to_string()).or_insert_with(|| match rule.algorithm {
+            LimitAlgorithm::FixedWindow => LocalWindow::Fixed(now, 0),
+            LimitAlgorithm::SlidingWindow => LocalWindow::Sliding(VecDeque::new()),
+        });
+
+        match entry {
+            LocalWindow::Fixed(start, count) => {
+                if now.duration_since(*start) >= window {
+                    *start = now;
+                    *count = 0;
+                }
+                *count += 1;
+                if *count > rule.count {
+                    Decision::Reject(window.saturating_sub(now.duration_since(*start)))
+                } else {
+                    Decision::Allow
+                }
+            }
+            LocalWindow::Sliding(requests) => {
+                while requests.front().is_some_and(|first| now.duration_since(*first) >= window) {
+                    requests.pop_front();
+                }
+                if (requests.len() as u64) < rule.count {
+                    requests.push_back(now);
+                    Decision::Allow
+                } else {
+                    let oldest = requests.front().copied().unwrap_or(now);
+                    Decision::Reject(window.saturating_sub(now.duration_since(oldest)))
+                }
+            }
+        }
+    }
+}
+
+/// 限流中间件
+///
+/// 须位于认证中间件之内以便按用户限流，超出限制时返回 429 及 `Retry-After` 响应头。
+pub async fn rate_limit_layer(State(limiter): State<RateLimiter>, request: Request, next: Next) -> Response {
+    let path = request.uri().path().to_string();
+    let rule = match limiter.match_rule(&path) {
+        Some(rule) => rule.clone(),
+        None => return next.run(request).await,
+    };
+
+    let subject = match rule.limit_type {
+        LimitType::Route => "route".to_string(),
+        LimitType::Ip => RequestUtils::get_client_ip(request.headers(), request.extensions()),
+        LimitType::User => match request.extensions().get::<LoginUser>() {
+            Some(login_user) => login_user.user_id.to_string(),
+            None => RequestUtils::get_client_ip(request.headers(), request.extensions()),
+        },
+    };
+    let key = RedisUtils::build_rate_limit_cache_key(&subject, &rule.path);
+
+    match limiter.acquire(&key, &rule).await {
+        Decision::Allow => next.run(request).await,
+        Decision::Reject(wait) => {
+            warn!("请求 {} 触发限流，限制对象: {}", path, subject);
+            let retry_after = wait.as_millis().div_ceil(1000).max(1);
+            let mut response = error_response(ZzylError::TooManyRequests("访问过于频繁，请稍候再试".to_string()));
+            if let Ok(value) = HeaderValue::from_str(&retry_after.to_string()) {
+                response.headers_mut().insert(header::RETRY_AFTER, value);
+            }
+            response
+        }
+    }
+}
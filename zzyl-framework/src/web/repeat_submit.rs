@@ -0,0 +1,86 @@
+use axum::{
+    body::{to_bytes, Body},
+    extract::{Request, State},
+    middleware::Next,
+    response::Response,
+};
+use tracing::warn;
+use zzyl_common::annotation::RepeatSubmit;
+use zzyl_common::error::ZzylError;
+use zzyl_common::utils::SecurityUtils;
+use zzyl_common::LoginUser;
+use crate::redis::{get_redis_manager, RedisUtils};
+use crate::security::auth::error_response;
+use crate::web::log::MAX_BODY_BYTES;
+use crate::web::request::RequestUtils;
+
+/// 默认重复提交间隔（毫秒）
+const DEFAULT_INTERVAL: i64 = 5000;
+
+/// 默认重复提交提示
+const DEFAULT_MESSAGE: &str = "不允许重复提交，请稍候再试";
+
+/// 防重复提交守卫
+///
+/// 以用户、请求路径和请求参数摘要作为指纹写入Redis，间隔时间内相同指纹的请求直接拒绝，
+/// 多实例部署时共享同一份记录；业务处理失败时释放指纹以便重试。
+///
+/// ```ignore
+/// .route("/elder/checkIn", post(check_in).route_layer(no_repeat!(3000)))
+/// ```
+pub async fn repeat_submit_guard(
+    State(repeat_submit): State<RepeatSubmit>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let redis = match get_redis_manager() {
+        Some(redis) => redis,
+        None => {
+            warn!("Redis未初始化，跳过重复提交校验");
+            return next.run(request).await;
+        }
+    };
+
+    let (parts, body) = request.into_parts();
+    let bytes = match to_bytes(body, MAX_BODY_BYTES).await {
+        Ok(bytes) => bytes,
+        Err(e) => return error_response(ZzylError::InvalidParameter(format!("读取请求参数失败: {}", e))),
+    };
+
+    let submitter = parts.extensions.get::<LoginUser>()
+        .map(|user| user.user_id.to_string())
+        .unwrap_or_else(|| RequestUtils::get_client_ip(&parts.headers, &parts.extensions));
+    let digest = SecurityUtils::sha256_hash(&format!(
+        "{}\n{}",
+        parts.uri.query().unwrap_or(""),
+        String::from_utf8_lossy(&bytes)
+    ));
+    let key = format!(
+        "{}:{}",
+        RedisUtils::build_repeat_submit_cache_key(&submitter, parts.uri.path()),
+        digest
+    );
+
+    let interval = repeat_submit.interval
+        .filter(|interval| *interval > 0)
+        .unwrap_or(DEFAULT_INTERVAL) as u64;
+    match redis.set_nx_px(&key, "1", interval).await {
+        Ok(true) => {}
+        Ok(false) => {
+            let message = repeat_submit.message.as_deref().unwrap_or(DEFAULT_MESSAGE);
+            return error_response(ZzylError::AlreadyExists(message.to_string()));
+        }
+        Err(e) => {
+            warn!("重复提交校验失败，放行请求: {}", e);
+            return next.run(Request::from_parts(parts, Body::from(bytes))).await;
+        }
+    }
+
+    let response = next.run(Request::from_parts(parts, Body::from(bytes))).await;
+    if !response.status().is_success() {
+        if let Err(e) = redis.del(&key).await {
+            warn!("释放重复提交标记失败: {}", e);
+        }
+    }
+    response
+}
@@ -0,0 +1,78 @@
+use std::convert::Infallible;
+use std::net::{IpAddr, SocketAddr};
+use axum::{
+    async_trait,
+    extract::{ConnectInfo, FromRequestParts},
+    http::{header, request::Parts, Extensions, HeaderMap},
+};
+use crate::config::get_config;
+
+/// 未知IP地址
+pub const UNKNOWN_IP: &str = "unknown";
+
+/// 请求工具类
+pub struct RequestUtils;
+
+impl RequestUtils {
+    /// 获取客户端IP地址
+    ///
+    /// 仅当直连地址属于配置的可信代理时才采信转发请求头，否则使用套接字地址，防止客户端伪造IP。
+    pub fn get_client_ip(headers: &HeaderMap, extensions: &Extensions) -> String {
+        let peer = extensions.get::<ConnectInfo<SocketAddr>>().map(|ConnectInfo(addr)| addr.ip());
+        if peer.is_some_and(Self::is_trusted_proxy) {
+            for name in ["x-forwarded-for", "x-real-ip", "proxy-client-ip", "wl-proxy-client-ip"] {
+                if let Some(ip) = headers.get(name)
+                    .and_then(|value| value.to_str().ok())
+                    .and_then(|value| value.split(',').next())
+                    .map(|value| value.trim())
+                    .filter(|value| !value.is_empty() && !value.eq_ignore_ascii_case(UNKNOWN_IP))
+                {
+                    return ip.to_string();
+                }
+            }
+        }
+
+        peer.map(|ip| ip.to_string()).unwrap_or_else(|| UNKNOWN_IP.to_string())
+    }
+
+    /// 判断地址是否为配置的可信代理
+    fn is_trusted_proxy(ip: IpAddr) -> bool {
+        get_config().is_some_and(|config| {
+            config.server.trusted_proxies.iter()
+                .filter_map(|proxy| proxy.trim().parse::<IpAddr>().ok())
+                .any(|proxy| proxy == ip.to_canonical())
+        })
+    }
+
+    /// 获取User-Agent
+    pub fn get_user_agent(headers: &HeaderMap) -> String {
+        headers.get(header::USER_AGENT)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or("")
+            .to_string()
+    }
+}
+
+/// 客户端信息提取器
+#[derive(Debug, Clone)]
+pub struct ClientInfo {
+    /// 客户端IP地址
+    pub ip: String,
+    /// User-Agent
+    pub user_agent: String,
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for ClientInfo
+where
+    S: Send + Sync,
+{
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> std::result::Result<Self, Self::Rejection> {
+        Ok(Self {
+            ip: RequestUtils::get_client_ip(&parts.headers, &parts.extensions),
+            user_agent: RequestUtils::get_user_agent(&parts.headers),
+        })
+    }
+}
@@ -0,0 +1,81 @@
+use axum::{
+    http::header,
+    response::{IntoResponse, Response},
+};
+use chrono::Utc;
+use uuid::Uuid;
+use zzyl_common::error::{Result, ZzylError};
+use zzyl_common::utils::ExcelUtils;
+
+/// 权限校验路由层
+#[macro_export]
+macro_rules! perm {
+    ($perm:expr) => {
+        ::axum::middleware::from_fn_with_state(
+            ::zzyl_common::annotation::PreAuthorize {
+                has_perm: Some($perm.to_string()),
+                ..Default::default()
+            },
+            $crate::security::permission_guard,
+        )
+    };
+}
+
+/// 操作日志路由层
+#[macro_export]
+macro_rules! oper_log {
+    ($title:expr, $business_type:expr) => {
+        ::axum::middleware::from_fn_with_state(
+            ::zzyl_common::annotation::Log {
+                title: Some($title.to_string()),
+                business_type: Some(i32::from($business_type)),
+                ..Default::default()
+            },
+            $crate::web::oper_log_layer,
+        )
+    };
+}
+
+/// 防重复提交路由层
+#[macro_export]
+macro_rules! no_repeat {
+    () => {
+        ::axum::middleware::from_fn_with_state(
+            ::zzyl_common::annotation::RepeatSubmit::default(),
+            $crate::web::repeat_submit_guard,
+        )
+    };
+    ($interval:expr) => {
+        ::axum::middleware::from_fn_with_state(
+            ::zzyl_common::annotation::RepeatSubmit {
+                interval: Some($interval),
+                ..Default::default()
+            },
+            $crate::web::repeat_submit_guard,
+        )
+    };
+}
+
+/// 解析逗号分隔的ID列表
+pub fn parse_ids(ids: &str) -> Result<Vec<Uuid>> {
+    ids.split(',')
+        .map(str::trim)
+        .filter(|id| !id.is_empty())
+        .map(|id| Uuid::parse_str(id).map_err(|_| ZzylError::InvalidParameter(format!("ID格式错误: {}", id))))
+        .collect()
+}
+
+/// 生成Excel下载响应
+pub fn excel_response(file_name: &str, data: &[Vec<String>]) -> Result<Response> {
+    let bytes = ExcelUtils::write_excel_bytes(data)
+        .map_err(|e| ZzylError::System(format!("导出Excel失败: {}", e)))?;
+    let disposition = format!("attachment; filename=\"{}_{}.xlsx\"", file_name, Utc::now().format("%Y%m%d%H%M%S"));
+    Ok((
+        [
+            (header::CONTENT_TYPE, "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet".to_string()),
+            (header::CONTENT_DISPOSITION, disposition),
+        ],
+        bytes,
+    )
+        .into_response())
+}
@@ -0,0 +1,302 @@
+pub mod bed;
+pub mod building;
+pub mod check_in;
+pub mod check_out;
+pub mod elder;
+pub mod floor;
+pub mod nursing_record;
+pub mod room;
+pub mod room_type;
+
+use axum::{
+    routing::{delete, get, post, put},
+    Router,
+};
+use zzyl_common::enums::BusinessType;
+use zzyl_framework::{no_repeat, oper_log, perm};
+
+/// 养老业务路由
+pub fn routes<S>() -> Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    Router::new()
+        .merge(elder_routes())
+        .merge(check_in_routes())
+        .merge(check_out_routes())
+        .merge(nursing_record_routes())
+        .merge(floor_routes())
+        .merge(room_type_routes())
+        .merge(room_routes())
+        .merge(bed_routes())
+        .merge(building_routes())
+}
+
+/// 老人档案路由
+fn elder_routes<S>() -> Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    Router::new()
+        .route(
+            "/api/elders",
+            get(elder::list)
+                .route_layer(perm!("nursing:elder:list"))
+                .merge(post(elder::add)
+                    .route_layer(oper_log!("老人档案", BusinessType::Insert))
+                    .route_layer(perm!("nursing:elder:add")))
+                .merge(put(elder::edit)
+                    .route_layer(oper_log!("老人档案", BusinessType::Update))
+                    .route_layer(perm!("nursing:elder:edit"))),
+        )
+        .route(
+            "/api/elders/:elder_id",
+            get(elder::get_info)
+                .route_layer(perm!("nursing:elder:query"))
+                .merge(delete(elder::remove)
+                    .route_layer(oper_log!("老人档案", BusinessType::Delete))
+                    .route_layer(perm!("nursing:elder:remove"))),
+        )
+        .route(
+            "/api/elders/status",
+            put(elder::change_status)
+                .route_layer(oper_log!("老人状态", BusinessType::Update))
+                .route_layer(perm!("nursing:elder:edit")),
+        )
+        .route(
+            "/api/elders/:elder_id/history",
+            get(elder::status_history).route_layer(perm!("nursing:elder:query")),
+        )
+}
+
+/// 入住办理路由
+fn check_in_routes<S>() -> Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    Router::new()
+        .route(
+            "/api/check-ins",
+            get(check_in::list)
+                .route_layer(perm!("nursing:checkin:list"))
+                .merge(post(check_in::apply)
+                    .route_layer(oper_log!("入住申请", BusinessType::Insert))
+                    .route_layer(no_repeat!())
+                    .route_layer(perm!("nursing:checkin:apply"))),
+        )
+        .route("/api/check-ins/:apply_id", get(check_in::get_info).route_layer(perm!("nursing:checkin:query")))
+        .route(
+            "/api/check-ins/:apply_id/assessment",
+            put(check_in::assess)
+                .route_layer(oper_log!("入住评估", BusinessType::Update))
+                .route_layer(no_repeat!())
+                .route_layer(perm!("nursing:checkin:assess")),
+        )
+        .route(
+            "/api/check-ins/:apply_id/bed",
+            put(check_in::select_bed)
+                .route_layer(oper_log!("入住选床", BusinessType::Update))
+                .route_layer(no_repeat!())
+                .route_layer(perm!("nursing:checkin:bed")),
+        )
+        .route(
+            "/api/check-ins/:apply_id/contract",
+            put(check_in::sign_contract)
+                .route_layer(oper_log!("入住签约", BusinessType::Update))
+                .route_layer(no_repeat!())
+                .route_layer(perm!("nursing:checkin:contract")),
+        )
+        .route(
+            "/api/check-ins/:apply_id/admission",
+            put(check_in::admit)
+                .route_layer(oper_log!("办理入住", BusinessType::Update))
+                .route_layer(no_repeat!())
+                .route_layer(perm!("nursing:checkin:admit")),
+        )
+        .route(
+            "/api/check-ins/:apply_id/cancellation",
+            put(check_in::cancel)
+                .route_layer(oper_log!("取消入住申请", BusinessType::Update))
+                .route_layer(no_repeat!())
+                .route_layer(perm!("nursing:checkin:cancel")),
+        )
+}
+
+/// 退住办理路由
+fn check_out_routes<S>() -> Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    Router::new()
+        .route(
+            "/api/check-outs",
+            post(check_out::check_out)
+                .route_layer(oper_log!("退住结算", BusinessType::Insert))
+                .route_layer(no_repeat!())
+                .route_layer(perm!("nursing:checkout:settle")),
+        )
+        .route(
+            "/api/check-outs/apply",
+            post(check_out::apply)
+                .route_layer(oper_log!("退住申请", BusinessType::Update))
+                .route_layer(no_repeat!())
+                .route_layer(perm!("nursing:checkout:apply")),
+        )
+        .route(
+            "/api/check-outs/cancellation",
+            put(check_out::cancel)
+                .route_layer(oper_log!("撤销退住申请", BusinessType::Update))
+                .route_layer(no_repeat!())
+                .route_layer(perm!("nursing:checkout:cancel")),
+        )
+        .route("/api/check-outs/preview", get(check_out::preview).route_layer(perm!("nursing:checkout:query")))
+        .route(
+            "/api/check-outs/elders/:elder_id",
+            get(check_out::list_by_elder).route_layer(perm!("nursing:checkout:query")),
+        )
+        .route(
+            "/api/check-outs/:settlement_id",
+            get(check_out::get_info).route_layer(perm!("nursing:checkout:query")),
+        )
+        .route(
+            "/api/check-outs/:settlement_id/print",
+            post(check_out::print)
+                .route_layer(oper_log!("退住结算单", BusinessType::Export))
+                .route_layer(perm!("nursing:checkout:export")),
+        )
+}
+
+/// 护理记录路由
+fn nursing_record_routes<S>() -> Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    Router::new().route(
+        "/api/nursing-records",
+        get(nursing_record::list)
+            .route_layer(perm!("nursing:record:list"))
+            .merge(post(nursing_record::add)
+                .route_layer(oper_log!("护理记录", BusinessType::Insert))
+                .route_layer(no_repeat!())
+                .route_layer(perm!("nursing:record:add"))),
+    )
+}
+
+/// 楼层管理路由
+fn floor_routes<S>() -> Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    Router::new()
+        .route(
+            "/api/floors",
+            get(floor::list)
+                .route_layer(perm!("nursing:floor:list"))
+                .merge(post(floor::add)
+                    .route_layer(oper_log!("楼层管理", BusinessType::Insert))
+                    .route_layer(perm!("nursing:floor:add")))
+                .merge(put(floor::edit)
+                    .route_layer(oper_log!("楼层管理", BusinessType::Update))
+                    .route_layer(perm!("nursing:floor:edit"))),
+        )
+        .route(
+            "/api/floors/:floor_id",
+            get(floor::get_info)
+                .route_layer(perm!("nursing:floor:query"))
+                .merge(delete(floor::remove)
+                    .route_layer(oper_log!("楼层管理", BusinessType::Delete))
+                    .route_layer(perm!("nursing:floor:remove"))),
+        )
+}
+
+/// 房间类型管理路由
+fn room_type_routes<S>() -> Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    Router::new()
+        .route(
+            "/api/room-types",
+            get(room_type::list)
+                .route_layer(perm!("nursing:roomType:list"))
+                .merge(post(room_type::add)
+                    .route_layer(oper_log!("房间类型管理", BusinessType::Insert))
+                    .route_layer(perm!("nursing:roomType:add")))
+                .merge(put(room_type::edit)
+                    .route_layer(oper_log!("房间类型管理", BusinessType::Update))
+                    .route_layer(perm!("nursing:roomType:edit"))),
+        )
+        .route(
+            "/api/room-types/:room_type_id",
+            get(room_type::get_info)
+                .route_layer(perm!("nursing:roomType:query"))
+                .merge(delete(room_type::remove)
+                    .route_layer(oper_log!("房间类型管理", BusinessType::Delete))
+                    .route_layer(perm!("nursing:roomType:remove"))),
+        )
+}
+
+/// 房间管理路由
+fn room_routes<S>() -> Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    Router::new()
+        .route(
+            "/api/rooms",
+            get(room::list)
+                .route_layer(perm!("nursing:room:list"))
+                .merge(post(room::add)
+                    .route_layer(oper_log!("房间管理", BusinessType::Insert))
+                    .route_layer(perm!("nursing:room:add")))
+                .merge(put(room::edit)
+                    .route_layer(oper_log!("房间管理", BusinessType::Update))
+                    .route_layer(perm!("nursing:room:edit"))),
+        )
+        .route(
+            "/api/rooms/:room_id",
+            get(room::get_info)
+                .route_layer(perm!("nursing:room:query"))
+                .merge(delete(room::remove)
+                    .route_layer(oper_log!("房间管理", BusinessType::Delete))
+                    .route_layer(perm!("nursing:room:remove"))),
+        )
+}
+
+/// 床位管理路由
+fn bed_routes<S>() -> Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    Router::new()
+        .route(
+            "/api/beds",
+            get(bed::list)
+                .route_layer(perm!("nursing:bed:list"))
+                .merge(post(bed::add)
+                    .route_layer(oper_log!("床位管理", BusinessType::Insert))
+                    .route_layer(perm!("nursing:bed:add")))
+                .merge(put(bed::edit)
+                    .route_layer(oper_log!("床位管理", BusinessType::Update))
+                    .route_layer(perm!("nursing:bed:edit"))),
+        )
+        .route(
+            "/api/beds/:bed_id",
+            get(bed::get_info)
+                .route_layer(perm!("nursing:bed:query"))
+                .merge(delete(bed::remove)
+                    .route_layer(oper_log!("床位管理", BusinessType::Delete))
+                    .route_layer(perm!("nursing:bed:remove"))),
+        )
+}
+
+/// 楼宇总览路由
+fn building_routes<S>() -> Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    Router::new().route(
+        "/api/building/overview",
+        get(building::overview).route_layer(perm!("nursing:building:overview")),
+    )
+}
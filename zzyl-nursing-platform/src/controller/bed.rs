@@ -0,0 +1,35 @@
+use axum::{extract::{Path, Query}, Json};
+use uuid::Uuid;
+use zzyl_common::error::{AjaxResult, Result};
+use zzyl_framework::security::CurrentUser;
+use zzyl_framework::web::parse_ids;
+use crate::domain::{Bed, BedRequest, BedSearchCondition};
+use crate::service::BedService;
+
+/// 床位列表
+pub async fn list(Query(condition): Query<BedSearchCondition>) -> Result<AjaxResult<Vec<Bed>>> {
+    Ok(AjaxResult::success(BedService::select_bed_list(&condition).await?))
+}
+
+/// 床位详情
+pub async fn get_info(Path(bed_id): Path<Uuid>) -> Result<AjaxResult<Bed>> {
+    Ok(AjaxResult::success(BedService::select_bed_by_id(&bed_id).await?))
+}
+
+/// 新增床位
+pub async fn add(CurrentUser(login_user): CurrentUser, Json(request): Json<BedRequest>) -> Result<AjaxResult<Uuid>> {
+    Ok(AjaxResult::success(BedService::insert_bed(&login_user, &request).await?))
+}
+
+/// 修改床位
+pub async fn edit(CurrentUser(login_user): CurrentUser, Json(request): Json<BedRequest>) -> Result<AjaxResult<()>> {
+    BedService::update_bed(&login_user, &request).await?;
+    Ok(AjaxResult::success_msg("操作成功"))
+}
+
+/// 删除床位，多个ID以逗号分隔
+pub async fn remove(Path(bed_ids): Path<String>) -> Result<AjaxResult<()>> {
+    let bed_ids = parse_ids(&bed_ids)?;
+    BedService::delete_bed_by_ids(&bed_ids).await?;
+    Ok(AjaxResult::success_msg("操作成功"))
+}
@@ -0,0 +1,8 @@
+use zzyl_common::error::{AjaxResult, Result};
+use crate::domain::BuildingOverviewDto;
+use crate::service::BuildingService;
+
+/// 楼宇总览
+pub async fn overview() -> Result<AjaxResult<BuildingOverviewDto>> {
+    Ok(AjaxResult::success(BuildingService::select_overview().await?))
+}
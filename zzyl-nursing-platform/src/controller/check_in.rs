@@ -0,0 +1,73 @@
+use axum::{extract::{Path, Query}, Json};
+use uuid::Uuid;
+use zzyl_common::error::{AjaxResult, Result, TableDataInfo};
+use zzyl_common::PageDomain;
+use zzyl_framework::security::CurrentUser;
+use crate::domain::{
+    CheckInApply, CheckInApplyRequest, CheckInApplySearchCondition, CheckInAssessRequest, CheckInBedRequest,
+    CheckInContractRequest,
+};
+use crate::service::CheckInService;
+
+/// 入住申请列表
+pub async fn list(
+    Query(condition): Query<CheckInApplySearchCondition>,
+    Query(page): Query<PageDomain>,
+) -> Result<TableDataInfo<CheckInApply>> {
+    let page = CheckInService::select_apply_list(&condition, &page).await?;
+    Ok(TableDataInfo::success(page.list, page.total))
+}
+
+/// 入住申请详情
+pub async fn get_info(Path(apply_id): Path<Uuid>) -> Result<AjaxResult<CheckInApply>> {
+    Ok(AjaxResult::success(CheckInService::select_apply_by_id(&apply_id).await?))
+}
+
+/// 提交入住申请
+pub async fn apply(
+    CurrentUser(login_user): CurrentUser,
+    Json(request): Json<CheckInApplyRequest>,
+) -> Result<AjaxResult<Uuid>> {
+    Ok(AjaxResult::success(CheckInService::apply(&login_user, &request).await?))
+}
+
+/// 入住评估
+pub async fn assess(
+    CurrentUser(login_user): CurrentUser,
+    Path(apply_id): Path<Uuid>,
+    Json(request): Json<CheckInAssessRequest>,
+) -> Result<AjaxResult<()>> {
+    CheckInService::assess(&login_user, &apply_id, &request).await?;
+    Ok(AjaxResult::success_msg("操作成功"))
+}
+
+/// 选择床位
+pub async fn select_bed(
+    CurrentUser(login_user): CurrentUser,
+    Path(apply_id): Path<Uuid>,
+    Json(request): Json<CheckInBedRequest>,
+) -> Result<AjaxResult<()>> {
+    CheckInService::select_bed(&login_user, &apply_id, &request).await?;
+    Ok(AjaxResult::success_msg("操作成功"))
+}
+
+/// 签订合同
+pub async fn sign_contract(
+    CurrentUser(login_user): CurrentUser,
+    Path(apply_id): Path<Uuid>,
+    Json(request): Json<CheckInContractRequest>,
+) -> Result<AjaxResult<()>> {
+    CheckInService::sign_contract(&login_user, &apply_id, &request).await?;
+    Ok(AjaxResult::success_msg("操作成功"))
+}
+
+/// 办理入住，返回入住记录ID
+pub async fn admit(CurrentUser(login_user): CurrentUser, Path(apply_id): Path<Uuid>) -> Result<AjaxResult<Uuid>> {
+    Ok(AjaxResult::success(CheckInService::admit(&login_user, &apply_id).await?))
+}
+
+/// 取消入住申请
+pub async fn cancel(CurrentUser(login_user): CurrentUser, Path(apply_id): Path<Uuid>) -> Result<AjaxResult<()>> {
+    CheckInService::cancel(&login_user, &apply_id).await?;
+    Ok(AjaxResult::success_msg("操作成功"))
+}
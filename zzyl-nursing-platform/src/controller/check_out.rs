@@ -0,0 +1,56 @@
+use axum::{extract::{Path, Query}, response::Response, Json};
+use uuid::Uuid;
+use zzyl_common::error::{AjaxResult, Result};
+use zzyl_framework::security::CurrentUser;
+use zzyl_framework::web::excel_response;
+use crate::domain::{
+    CheckOutApplyRequest, CheckOutCancelRequest, CheckOutRequest, CheckOutSettlement, SettlementSummaryDto,
+};
+use crate::service::CheckOutService;
+
+/// 申请退住
+pub async fn apply(
+    CurrentUser(login_user): CurrentUser,
+    Json(request): Json<CheckOutApplyRequest>,
+) -> Result<AjaxResult<()>> {
+    CheckOutService::apply(&login_user, &request).await?;
+    Ok(AjaxResult::success_msg("操作成功"))
+}
+
+/// 撤销退住申请
+pub async fn cancel(
+    CurrentUser(login_user): CurrentUser,
+    Json(request): Json<CheckOutCancelRequest>,
+) -> Result<AjaxResult<()>> {
+    CheckOutService::cancel(&login_user, &request).await?;
+    Ok(AjaxResult::success_msg("操作成功"))
+}
+
+/// 预览退住结算单
+pub async fn preview(Query(request): Query<CheckOutRequest>) -> Result<AjaxResult<SettlementSummaryDto>> {
+    Ok(AjaxResult::success(CheckOutService::preview(&request).await?))
+}
+
+/// 办理退住结算，返回结算单ID
+pub async fn check_out(
+    CurrentUser(login_user): CurrentUser,
+    Json(request): Json<CheckOutRequest>,
+) -> Result<AjaxResult<Uuid>> {
+    Ok(AjaxResult::success(CheckOutService::check_out(&login_user, &request).await?))
+}
+
+/// 老人退住结算单列表
+pub async fn list_by_elder(Path(elder_id): Path<Uuid>) -> Result<AjaxResult<Vec<CheckOutSettlement>>> {
+    Ok(AjaxResult::success(CheckOutService::select_settlements_by_elder_id(&elder_id).await?))
+}
+
+/// 退住结算单详情
+pub async fn get_info(Path(settlement_id): Path<Uuid>) -> Result<AjaxResult<SettlementSummaryDto>> {
+    Ok(AjaxResult::success(CheckOutService::select_summary(&settlement_id).await?))
+}
+
+/// 打印退住结算单
+pub async fn print(Path(settlement_id): Path<Uuid>) -> Result<Response> {
+    let rows = CheckOutService::export_summary(&settlement_id).await?;
+    excel_response("settlement", &rows)
+}
@@ -0,0 +1,54 @@
+use axum::{extract::{Path, Query}, Json};
+use uuid::Uuid;
+use zzyl_common::error::{AjaxResult, Result, TableDataInfo};
+use zzyl_common::PageDomain;
+use zzyl_framework::security::CurrentUser;
+use zzyl_framework::web::parse_ids;
+use crate::domain::{Elder, ElderDetailDto, ElderRequest, ElderSearchCondition, ElderStatusHistory, ElderStatusRequest};
+use crate::service::{ElderService, ElderStatusService};
+
+/// 老人列表
+pub async fn list(
+    Query(condition): Query<ElderSearchCondition>,
+    Query(page): Query<PageDomain>,
+) -> Result<TableDataInfo<Elder>> {
+    let page = ElderService::select_elder_list(&condition, &page).await?;
+    Ok(TableDataInfo::success(page.list, page.total))
+}
+
+/// 老人详情
+pub async fn get_info(Path(elder_id): Path<Uuid>) -> Result<AjaxResult<ElderDetailDto>> {
+    Ok(AjaxResult::success(ElderService::select_elder_detail(&elder_id).await?))
+}
+
+/// 新增老人
+pub async fn add(CurrentUser(login_user): CurrentUser, Json(request): Json<ElderRequest>) -> Result<AjaxResult<Uuid>> {
+    Ok(AjaxResult::success(ElderService::insert_elder(&login_user, &request).await?))
+}
+
+/// 修改老人
+pub async fn edit(CurrentUser(login_user): CurrentUser, Json(request): Json<ElderRequest>) -> Result<AjaxResult<()>> {
+    ElderService::update_elder(&login_user, &request).await?;
+    Ok(AjaxResult::success_msg("操作成功"))
+}
+
+/// 删除老人，多个ID以逗号分隔
+pub async fn remove(Path(elder_ids): Path<String>) -> Result<AjaxResult<()>> {
+    let elder_ids = parse_ids(&elder_ids)?;
+    ElderService::delete_elder_by_ids(&elder_ids).await?;
+    Ok(AjaxResult::success_msg("操作成功"))
+}
+
+/// 变更老人状态
+pub async fn change_status(
+    CurrentUser(login_user): CurrentUser,
+    Json(request): Json<ElderStatusRequest>,
+) -> Result<AjaxResult<()>> {
+    ElderStatusService::change_status(&login_user, &request).await?;
+    Ok(AjaxResult::success_msg("操作成功"))
+}
+
+/// 老人状态变更记录
+pub async fn status_history(Path(elder_id): Path<Uuid>) -> Result<AjaxResult<Vec<ElderStatusHistory>>> {
+    Ok(AjaxResult::success(ElderStatusService::select_history(&elder_id).await?))
+}
@@ -0,0 +1,35 @@
+use axum::{extract::Path, Json};
+use uuid::Uuid;
+use zzyl_common::error::{AjaxResult, Result};
+use zzyl_framework::security::CurrentUser;
+use zzyl_framework::web::parse_ids;
+use crate::domain::{Floor, FloorDetailDto, FloorRequest};
+use crate::service::FloorService;
+
+/// 楼层列表
+pub async fn list() -> Result<AjaxResult<Vec<Floor>>> {
+    Ok(AjaxResult::success(FloorService::select_floor_all().await?))
+}
+
+/// 楼层详情
+pub async fn get_info(Path(floor_id): Path<Uuid>) -> Result<AjaxResult<FloorDetailDto>> {
+    Ok(AjaxResult::success(FloorService::select_floor_detail(&floor_id).await?))
+}
+
+/// 新增楼层
+pub async fn add(CurrentUser(login_user): CurrentUser, Json(request): Json<FloorRequest>) -> Result<AjaxResult<Uuid>> {
+    Ok(AjaxResult::success(FloorService::insert_floor(&login_user, &request).await?))
+}
+
+/// 修改楼层
+pub async fn edit(CurrentUser(login_user): CurrentUser, Json(request): Json<FloorRequest>) -> Result<AjaxResult<()>> {
+    FloorService::update_floor(&login_user, &request).await?;
+    Ok(AjaxResult::success_msg("操作成功"))
+}
+
+/// 删除楼层，多个ID以逗号分隔
+pub async fn remove(Path(floor_ids): Path<String>) -> Result<AjaxResult<()>> {
+    let floor_ids = parse_ids(&floor_ids)?;
+    FloorService::delete_floor_by_ids(&floor_ids).await?;
+    Ok(AjaxResult::success_msg("操作成功"))
+}
@@ -0,0 +1,24 @@
+use axum::{extract::Query, Json};
+use uuid::Uuid;
+use zzyl_common::error::{AjaxResult, Result, TableDataInfo};
+use zzyl_common::PageDomain;
+use zzyl_framework::security::CurrentUser;
+use crate::domain::{NursingRecord, NursingRecordRequest, NursingRecordSearchCondition};
+use crate::service::NursingRecordService;
+
+/// 护理记录列表
+pub async fn list(
+    Query(condition): Query<NursingRecordSearchCondition>,
+    Query(page): Query<PageDomain>,
+) -> Result<TableDataInfo<NursingRecord>> {
+    let page = NursingRecordService::select_record_list(&condition, &page).await?;
+    Ok(TableDataInfo::success(page.list, page.total))
+}
+
+/// 登记护理记录
+pub async fn add(
+    CurrentUser(login_user): CurrentUser,
+    Json(request): Json<NursingRecordRequest>,
+) -> Result<AjaxResult<Uuid>> {
+    Ok(AjaxResult::success(NursingRecordService::insert_record(&login_user, &request).await?))
+}
@@ -0,0 +1,40 @@
+use axum::{extract::{Path, Query}, Json};
+use uuid::Uuid;
+use zzyl_common::error::{AjaxResult, Result, TableDataInfo};
+use zzyl_common::PageDomain;
+use zzyl_framework::security::CurrentUser;
+use zzyl_framework::web::parse_ids;
+use crate::domain::{Room, RoomDetailDto, RoomRequest, RoomSearchCondition};
+use crate::service::RoomService;
+
+/// 房间列表
+pub async fn list(
+    Query(condition): Query<RoomSearchCondition>,
+    Query(page): Query<PageDomain>,
+) -> Result<TableDataInfo<Room>> {
+    let page = RoomService::select_room_list(&condition, &page).await?;
+    Ok(TableDataInfo::success(page.list, page.total))
+}
+
+/// 房间详情
+pub async fn get_info(Path(room_id): Path<Uuid>) -> Result<AjaxResult<RoomDetailDto>> {
+    Ok(AjaxResult::success(RoomService::select_room_detail(&room_id).await?))
+}
+
+/// 新增房间
+pub async fn add(CurrentUser(login_user): CurrentUser, Json(request): Json<RoomRequest>) -> Result<AjaxResult<Uuid>> {
+    Ok(AjaxResult::success(RoomService::insert_room(&login_user, &request).await?))
+}
+
+/// 修改房间
+pub async fn edit(CurrentUser(login_user): CurrentUser, Json(request): Json<RoomRequest>) -> Result<AjaxResult<()>> {
+    RoomService::update_room(&login_user, &request).await?;
+    Ok(AjaxResult::success_msg("操作成功"))
+}
+
+/// 删除房间，多个ID以逗号分隔
+pub async fn remove(Path(room_ids): Path<String>) -> Result<AjaxResult<()>> {
+    let room_ids = parse_ids(&room_ids)?;
+    RoomService::delete_room_by_ids(&room_ids).await?;
+    Ok(AjaxResult::success_msg("操作成功"))
+}
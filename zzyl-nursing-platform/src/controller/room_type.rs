@@ -0,0 +1,41 @@
+use axum::{extract::Path, Json};
+use uuid::Uuid;
+use zzyl_common::error::{AjaxResult, Result};
+use zzyl_framework::security::CurrentUser;
+use zzyl_framework::web::parse_ids;
+use crate::domain::{RoomType, RoomTypeRequest};
+use crate::service::RoomTypeService;
+
+/// 房间类型列表
+pub async fn list() -> Result<AjaxResult<Vec<RoomType>>> {
+    Ok(AjaxResult::success(RoomTypeService::select_room_type_all().await?))
+}
+
+/// 房间类型详情
+pub async fn get_info(Path(room_type_id): Path<Uuid>) -> Result<AjaxResult<RoomType>> {
+    Ok(AjaxResult::success(RoomTypeService::select_room_type_by_id(&room_type_id).await?))
+}
+
+/// 新增房间类型
+pub async fn add(
+    CurrentUser(login_user): CurrentUser,
+    Json(request): Json<RoomTypeRequest>,
+) -> Result<AjaxResult<Uuid>> {
+    Ok(AjaxResult::success(RoomTypeService::insert_room_type(&login_user, &request).await?))
+}
+
+/// 修改房间类型
+pub async fn edit(
+    CurrentUser(login_user): CurrentUser,
+    Json(request): Json<RoomTypeRequest>,
+) -> Result<AjaxResult<()>> {
+    RoomTypeService::update_room_type(&login_user, &request).await?;
+    Ok(AjaxResult::success_msg("操作成功"))
+}
+
+/// 删除房间类型，多个ID以逗号分隔
+pub async fn remove(Path(room_type_ids): Path<String>) -> Result<AjaxResult<()>> {
+    let room_type_ids = parse_ids(&room_type_ids)?;
+    RoomTypeService::delete_room_type_by_ids(&room_type_ids).await?;
+    Ok(AjaxResult::success_msg("操作成功"))
+}
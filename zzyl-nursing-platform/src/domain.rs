@@ -1,8 +1,8 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use rust_decimal::Decimal;
-use zzyl_common::{BaseEntity, enums::{ElderStatus, Gender}};
+use zzyl_common::{BaseEntity, enums::{CheckInStage, ElderStatus, Gender}};
 
 /// 老人实体
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -197,6 +197,8 @@ pub struct CheckIn {
     pub elder_id: Option<Uuid>,
     /// 床位ID
     pub bed_id: Option<Uuid>,
+    /// 护理等级ID
+    pub nursing_level_id: Option<Uuid>,
     /// 入住时间
     pub check_in_time: Option<DateTime<Utc>>,
     /// 退住时间
@@ -223,6 +225,8 @@ pub struct Contract {
     pub end_time: Option<DateTime<Utc>>,
     /// 合同金额
     pub amount: Option<Decimal>,
+    /// 押金
+    pub deposit: Option<Decimal>,
     /// 状态（0：有效，1：无效）
     pub status: i32,
     /// 基础实体
@@ -266,6 +270,129 @@ pub struct CheckInConfig {
     pub base: BaseEntity,
 }
 
+/// 入住申请实体
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CheckInApply {
+    /// 主键ID
+    pub id: Option<Uuid>,
+    /// 老人ID
+    pub elder_id: Option<Uuid>,
+    /// 当前阶段
+    pub stage: CheckInStage,
+    /// 评估结论
+    pub assessment: Option<String>,
+    /// 评估确定的护理等级ID
+    pub nursing_level_id: Option<Uuid>,
+    /// 选定床位ID
+    pub bed_id: Option<Uuid>,
+    /// 合同编号
+    pub contract_no: Option<String>,
+    /// 合同开始时间
+    pub start_time: Option<DateTime<Utc>>,
+    /// 合同结束时间
+    pub end_time: Option<DateTime<Utc>>,
+    /// 合同金额
+    pub amount: Option<Decimal>,
+    /// 押金
+    pub deposit: Option<Decimal>,
+    /// 入住记录ID（入住后回填）
+    pub check_in_id: Option<Uuid>,
+    /// 基础实体
+    #[serde(flatten)]
+    pub base: BaseEntity,
+}
+
+/// 老人状态变更记录实体
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ElderStatusHistory {
+    /// 主键ID
+    pub id: Option<Uuid>,
+    /// 老人ID
+    pub elder_id: Option<Uuid>,
+    /// 变更前状态
+    pub from_status: ElderStatus,
+    /// 变更后状态
+    pub to_status: ElderStatus,
+    /// 变更原因
+    pub reason: Option<String>,
+    /// 基础实体（创建者即操作人）
+    #[serde(flatten)]
+    pub base: BaseEntity,
+}
+
+/// 退住结算单实体
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CheckOutSettlement {
+    /// 主键ID
+    pub id: Option<Uuid>,
+    /// 老人ID
+    pub elder_id: Option<Uuid>,
+    /// 入住记录ID
+    pub check_in_id: Option<Uuid>,
+    /// 合同ID
+    pub contract_id: Option<Uuid>,
+    /// 入住时间
+    pub check_in_time: Option<DateTime<Utc>>,
+    /// 退住时间
+    pub check_out_time: Option<DateTime<Utc>>,
+    /// 计费天数
+    pub days: i32,
+    /// 床位费
+    pub room_fee: Decimal,
+    /// 护理等级费
+    pub nursing_fee: Decimal,
+    /// 护理项目费
+    pub project_fee: Decimal,
+    /// 费用合计
+    pub total_fee: Decimal,
+    /// 押金
+    pub deposit: Decimal,
+    /// 结算余额（正数为退还，负数为补缴）
+    pub balance: Decimal,
+    /// 基础实体
+    #[serde(flatten)]
+    pub base: BaseEntity,
+}
+
+/// 退住结算明细实体
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SettlementItem {
+    /// 主键ID
+    pub id: Option<Uuid>,
+    /// 结算单ID
+    pub settlement_id: Option<Uuid>,
+    /// 费用名称
+    pub item_name: String,
+    /// 单位
+    pub unit: Option<String>,
+    /// 单价
+    pub price: Decimal,
+    /// 数量
+    pub quantity: Decimal,
+    /// 金额
+    pub amount: Decimal,
+}
+
+/// 护理执行记录实体，退住结算时按记录汇总护理项目费用
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NursingRecord {
+    /// 主键ID
+    pub id: Option<Uuid>,
+    /// 老人ID
+    pub elder_id: Option<Uuid>,
+    /// 护理项目ID
+    pub project_id: Option<Uuid>,
+    /// 护理项目名称
+    pub project_name: Option<String>,
+    /// 执行数量
+    pub quantity: Decimal,
+    /// 执行时间
+    pub execute_time: Option<DateTime<Utc>>,
+    /// 基础实体
+    #[serde(flatten)]
+    pub base: BaseEntity,
+}
+
 /// 护理计划DTO
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NursingPlanDto {
@@ -292,6 +419,29 @@ pub struct ElderDetailDto {
     pub contract: Option<Contract>,
 }
 
+/// 退住结算单DTO
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SettlementSummaryDto {
+    /// 结算单
+    pub settlement: CheckOutSettlement,
+    /// 老人姓名
+    pub elder_name: String,
+    /// 身份证号
+    pub id_card_no: String,
+    /// 楼层名称
+    pub floor_name: Option<String>,
+    /// 房间号
+    pub room_no: Option<String>,
+    /// 床位编号
+    pub bed_no: Option<String>,
+    /// 合同编号
+    pub contract_no: Option<String>,
+    /// 护理等级名称
+    pub nursing_level_name: Option<String>,
+    /// 结算明细
+    pub items: Vec<SettlementItem>,
+}
+
 /// 房间详细信息DTO
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RoomDetailDto {
@@ -331,6 +481,76 @@ pub struct OccupancyStats {
     pub occupancy_rate: f64,
 }
 
+impl OccupancyStats {
+    /// 按床位总数与占用数计算入住统计，入住率保留四位小数
+    pub fn new(total_beds: i32, occupied_beds: i32) -> Self {
+        let occupancy_rate = if total_beds > 0 {
+            (f64::from(occupied_beds) / f64::from(total_beds) * 10000.0).round() / 10000.0
+        } else {
+            0.0
+        };
+        Self {
+            total_beds,
+            occupied_beds,
+            available_beds: total_beds - occupied_beds,
+            occupancy_rate,
+        }
+    }
+}
+
+/// 床位入住情况DTO
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BedOccupancyDto {
+    /// 床位信息
+    pub bed: Bed,
+    /// 入住老人
+    pub elder: Option<Elder>,
+}
+
+/// 房间入住情况DTO
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoomOccupancyDto {
+    /// 房间信息
+    pub room: Room,
+    /// 房间类型信息
+    pub room_type: Option<RoomType>,
+    /// 床位列表
+    pub beds: Vec<BedOccupancyDto>,
+    /// 入住统计
+    pub occupancy_stats: OccupancyStats,
+}
+
+/// 楼层入住情况DTO
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FloorOccupancyDto {
+    /// 楼层信息
+    pub floor: Floor,
+    /// 房间列表
+    pub rooms: Vec<RoomOccupancyDto>,
+    /// 入住统计
+    pub occupancy_stats: OccupancyStats,
+}
+
+/// 房间类型入住统计DTO
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoomTypeOccupancyDto {
+    /// 房间类型信息
+    pub room_type: RoomType,
+    /// 入住统计
+    pub occupancy_stats: OccupancyStats,
+}
+
+/// 楼宇总览DTO
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BuildingOverviewDto {
+    /// 楼层列表
+    pub floors: Vec<FloorOccupancyDto>,
+    /// 各房间类型入住统计
+    pub room_types: Vec<RoomTypeOccupancyDto>,
+    /// 全院入住统计
+    pub occupancy_stats: OccupancyStats,
+}
+
 /// 护理统计信息
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NursingStats {
@@ -384,6 +604,152 @@ pub struct ElderSearchCondition {
     pub bed_id: Option<Uuid>,
 }
 
+/// 新增/修改老人请求
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ElderRequest {
+    /// 主键ID（修改时必填）
+    pub id: Option<Uuid>,
+    /// 名称
+    pub name: String,
+    /// 图片
+    pub image: Option<String>,
+    /// 身份证号
+    pub id_card_no: String,
+    /// 性别，为空时按身份证号推导
+    pub sex: Option<Gender>,
+    /// 手机号
+    pub phone: Option<String>,
+    /// 出生日期（yyyy-MM-dd），为空时按身份证号推导
+    pub birthday: Option<NaiveDate>,
+    /// 家庭住址
+    pub address: Option<String>,
+    /// 身份证国徽面
+    pub id_card_front: Option<String>,
+    /// 身份证人像面
+    pub id_card_back: Option<String>,
+    /// 备注
+    pub remark: Option<String>,
+}
+
+/// 老人状态变更请求
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ElderStatusRequest {
+    /// 老人ID
+    pub elder_id: Uuid,
+    /// 目标状态
+    pub status: ElderStatus,
+    /// 变更原因
+    pub reason: Option<String>,
+}
+
+/// 入住申请搜索条件
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CheckInApplySearchCondition {
+    /// 老人姓名
+    pub elder_name: Option<String>,
+    /// 老人ID
+    pub elder_id: Option<Uuid>,
+    /// 申请阶段
+    pub stage: Option<CheckInStage>,
+}
+
+/// 入住申请请求
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CheckInApplyRequest {
+    /// 老人ID
+    pub elder_id: Uuid,
+    /// 备注
+    pub remark: Option<String>,
+}
+
+/// 入住评估请求
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CheckInAssessRequest {
+    /// 评估结论
+    pub assessment: String,
+    /// 护理等级ID
+    pub nursing_level_id: Uuid,
+}
+
+/// 入住选床请求
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CheckInBedRequest {
+    /// 床位ID
+    pub bed_id: Uuid,
+}
+
+/// 入住签约请求
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CheckInContractRequest {
+    /// 合同编号，为空时自动生成
+    pub contract_no: Option<String>,
+    /// 合同开始时间
+    pub start_time: DateTime<Utc>,
+    /// 合同结束时间
+    pub end_time: DateTime<Utc>,
+    /// 合同金额
+    pub amount: Decimal,
+    /// 押金，为空时按0计
+    pub deposit: Option<Decimal>,
+}
+
+/// 退住申请请求
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CheckOutApplyRequest {
+    /// 老人ID
+    pub elder_id: Uuid,
+    /// 退住原因
+    pub reason: Option<String>,
+}
+
+/// 撤销退住申请请求
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CheckOutCancelRequest {
+    /// 老人ID
+    pub elder_id: Uuid,
+    /// 撤销原因
+    pub reason: Option<String>,
+}
+
+/// 退住结算请求
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CheckOutRequest {
+    /// 老人ID
+    pub elder_id: Uuid,
+    /// 退住时间，为空时取当前时间
+    pub check_out_time: Option<DateTime<Utc>>,
+    /// 备注
+    pub remark: Option<String>,
+}
+
+/// 护理记录搜索条件
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NursingRecordSearchCondition {
+    /// 老人ID
+    pub elder_id: Option<Uuid>,
+    /// 护理项目ID
+    pub project_id: Option<Uuid>,
+    /// 执行日期起（yyyy-MM-dd）
+    pub begin_time: Option<String>,
+    /// 执行日期止（yyyy-MM-dd）
+    pub end_time: Option<String>,
+}
+
+/// 新增护理记录请求
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NursingRecordRequest {
+    /// 老人ID
+    pub elder_id: Uuid,
+    /// 护理项目ID
+    pub project_id: Uuid,
+    /// 执行数量，为空时按1计
+    pub quantity: Option<Decimal>,
+    /// 执行时间，为空时取当前时间
+    pub execute_time: Option<DateTime<Utc>>,
+    /// 备注
+    pub remark: Option<String>,
+}
+
 /// 房间搜索条件
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RoomSearchCondition {
@@ -397,6 +763,75 @@ pub struct RoomSearchCondition {
     pub status: Option<i32>,
 }
 
+/// 新增/修改楼层请求
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FloorRequest {
+    /// 主键ID（修改时必填）
+    pub id: Option<Uuid>,
+    /// 楼层名称
+    pub floor_name: String,
+    /// 楼层号
+    pub floor_no: i32,
+    /// 状态（0：禁用，1：启用）
+    pub status: Option<i32>,
+    /// 备注
+    pub remark: Option<String>,
+}
+
+/// 新增/修改房间类型请求
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoomTypeRequest {
+    /// 主键ID（修改时必填）
+    pub id: Option<Uuid>,
+    /// 类型名称
+    pub type_name: String,
+    /// 价格（按月）
+    pub price: Option<Decimal>,
+    /// 状态（0：禁用，1：启用）
+    pub status: Option<i32>,
+    /// 备注
+    pub remark: Option<String>,
+}
+
+/// 新增/修改房间请求
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoomRequest {
+    /// 主键ID（修改时必填）
+    pub id: Option<Uuid>,
+    /// 房间号
+    pub room_no: String,
+    /// 楼层ID
+    pub floor_id: Uuid,
+    /// 房间类型ID
+    pub room_type_id: Uuid,
+    /// 备注
+    pub remark: Option<String>,
+}
+
+/// 床位搜索条件
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BedSearchCondition {
+    /// 床位编号
+    pub bed_no: Option<String>,
+    /// 房间ID
+    pub room_id: Option<Uuid>,
+    /// 状态
+    pub status: Option<i32>,
+}
+
+/// 新增/修改床位请求
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BedRequest {
+    /// 主键ID（修改时必填）
+    pub id: Option<Uuid>,
+    /// 床位编号
+    pub bed_no: String,
+    /// 房间ID
+    pub room_id: Uuid,
+    /// 备注
+    pub remark: Option<String>,
+}
+
 /// 护理计划搜索条件
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NursingPlanSearchCondition {
@@ -0,0 +1,29 @@
+mod bed;
+mod check_in;
+mod check_in_apply;
+mod contract;
+mod elder;
+mod elder_status_history;
+mod family_member;
+mod floor;
+mod nursing_level;
+mod nursing_project;
+mod nursing_record;
+mod room;
+mod room_type;
+mod settlement;
+
+pub use bed::*;
+pub use check_in::*;
+pub use check_in_apply::*;
+pub use contract::*;
+pub use elder::*;
+pub use elder_status_history::*;
+pub use family_member::*;
+pub use floor::*;
+pub use nursing_level::*;
+pub use nursing_project::*;
+pub use nursing_record::*;
+pub use room::*;
+pub use room_type::*;
+pub use settlement::*;
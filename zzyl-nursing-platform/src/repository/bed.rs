@@ -0,0 +1,190 @@
+use chrono::Utc;
+use sqlx::{mysql::MySqlRow, MySqlConnection, Row};
+use uuid::Uuid;
+use zzyl_common::error::Result;
+use zzyl_framework::database::{require_database_pool, DatabaseUtils};
+use crate::domain::{Bed, BedSearchCondition};
+
+/// 床位表查询列
+pub const BED_COLUMNS: &str = "b.id, b.bed_no, b.room_id, b.status, \
+    b.create_by, b.create_time, b.update_by, b.update_time, b.remark";
+
+/// 床位数据访问
+pub struct BedRepository;
+
+impl BedRepository {
+    /// 将查询结果映射为床位实体
+    pub fn map_row(row: &MySqlRow) -> Result<Bed> {
+        Ok(Bed {
+            id: DatabaseUtils::get_uuid(row, "id")?,
+            bed_no: row.try_get("bed_no")?,
+            room_id: DatabaseUtils::get_uuid(row, "room_id")?,
+            status: row.try_get("status")?,
+            base: DatabaseUtils::get_base_entity(row)?,
+        })
+    }
+
+    /// 通过ID查询床位
+    pub async fn select_bed_by_id(bed_id: &Uuid) -> Result<Option<Bed>> {
+        let sql = format!("SELECT {} FROM bed b WHERE b.id = ?", BED_COLUMNS);
+        let row = sqlx::query(&sql)
+            .bind(bed_id.to_string())
+            .fetch_optional(require_database_pool()?)
+            .await?;
+        row.as_ref().map(Self::map_row).transpose()
+    }
+
+    /// 查询老人当前入住的床位
+    pub async fn select_bed_by_elder_id(elder_id: &Uuid) -> Result<Option<Bed>> {
+        let sql = format!(
+            "SELECT {} FROM bed b INNER JOIN check_in ci ON ci.bed_id = b.id \
+             WHERE ci.elder_id = ? AND ci.status = 0 LIMIT 1",
+            BED_COLUMNS
+        );
+        let row = sqlx::query(&sql)
+            .bind(elder_id.to_string())
+            .fetch_optional(require_database_pool()?)
+            .await?;
+        row.as_ref().map(Self::map_row).transpose()
+    }
+
+    /// 占用空闲床位，床位已被占用时不更新
+    pub async fn occupy_bed(conn: &mut MySqlConnection, bed_id: &Uuid, update_by: &str) -> Result<u64> {
+        let result = sqlx::query("UPDATE bed SET status = 1, update_by = ?, update_time = ? WHERE id = ? AND status = 0")
+            .bind(update_by)
+            .bind(Utc::now())
+            .bind(bed_id.to_string())
+            .execute(conn)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// 释放已占用床位
+    pub async fn release_bed(conn: &mut MySqlConnection, bed_id: &Uuid, update_by: &str) -> Result<u64> {
+        let result = sqlx::query("UPDATE bed SET status = 0, update_by = ?, update_time = ? WHERE id = ? AND status = 1")
+            .bind(update_by)
+            .bind(Utc::now())
+            .bind(bed_id.to_string())
+            .execute(conn)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// 根据条件查询床位，按房间与床位编号排序
+    pub async fn select_bed_list(condition: &BedSearchCondition) -> Result<Vec<Bed>> {
+        let (where_sql, params) = Self::build_where(condition);
+        let sql = format!("SELECT {} FROM bed b {} ORDER BY b.room_id, b.bed_no", BED_COLUMNS, where_sql);
+        let mut query = sqlx::query(&sql);
+        for param in &params {
+            query = query.bind(param);
+        }
+        let rows = query.fetch_all(require_database_pool()?).await?;
+        rows.iter().map(Self::map_row).collect()
+    }
+
+    /// 查询房间内的床位
+    pub async fn select_beds_by_room_id(room_id: &Uuid) -> Result<Vec<Bed>> {
+        let sql = format!("SELECT {} FROM bed b WHERE b.room_id = ? ORDER BY b.bed_no", BED_COLUMNS);
+        let rows = sqlx::query(&sql)
+            .bind(room_id.to_string())
+            .fetch_all(require_database_pool()?)
+            .await?;
+        rows.iter().map(Self::map_row).collect()
+    }
+
+    /// 通过房间与床位编号查询床位ID
+    pub async fn select_bed_id_by_no(room_id: &Uuid, bed_no: &str) -> Result<Option<Uuid>> {
+        let row = sqlx::query("SELECT id FROM bed WHERE room_id = ? AND bed_no = ? LIMIT 1")
+            .bind(room_id.to_string())
+            .bind(bed_no)
+            .fetch_optional(require_database_pool()?)
+            .await?;
+        match row {
+            Some(row) => DatabaseUtils::get_uuid(&row, "id"),
+            None => Ok(None),
+        }
+    }
+
+    /// 统计房间内已占用的床位数量
+    pub async fn count_occupied_bed_by_room_id(room_id: &Uuid) -> Result<i64> {
+        let count = sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM bed WHERE room_id = ? AND status = 1")
+            .bind(room_id.to_string())
+            .fetch_one(require_database_pool()?)
+            .await?;
+        Ok(count)
+    }
+
+    /// 新增床位
+    pub async fn insert_bed(bed: &Bed) -> Result<u64> {
+        let result = sqlx::query(
+            "INSERT INTO bed (id, bed_no, room_id, status, create_by, create_time, remark) VALUES (?, ?, ?, ?, ?, ?, ?)",
+        )
+            .bind(bed.id.map(|id| id.to_string()))
+            .bind(&bed.bed_no)
+            .bind(bed.room_id.map(|id| id.to_string()))
+            .bind(bed.status)
+            .bind(&bed.base.create_by)
+            .bind(bed.base.create_time)
+            .bind(&bed.base.remark)
+            .execute(require_database_pool()?)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// 修改床位，状态由入住、退住流程维护
+    pub async fn update_bed(bed: &Bed) -> Result<u64> {
+        let result = sqlx::query(
+            "UPDATE bed SET bed_no = ?, room_id = ?, update_by = ?, update_time = ?, remark = ? WHERE id = ?",
+        )
+            .bind(&bed.bed_no)
+            .bind(bed.room_id.map(|id| id.to_string()))
+            .bind(&bed.base.update_by)
+            .bind(bed.base.update_time)
+            .bind(&bed.base.remark)
+            .bind(bed.id.map(|id| id.to_string()))
+            .execute(require_database_pool()?)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// 删除空闲床位，床位已被占用时不删除
+    pub async fn delete_free_bed_by_id(conn: &mut MySqlConnection, bed_id: &Uuid) -> Result<u64> {
+        let result = sqlx::query("DELETE FROM bed WHERE id = ? AND status = 0")
+            .bind(bed_id.to_string())
+            .execute(conn)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// 删除房间内的空闲床位
+    pub async fn delete_free_beds_by_room_id(conn: &mut MySqlConnection, room_id: &Uuid) -> Result<u64> {
+        let result = sqlx::query("DELETE FROM bed WHERE room_id = ? AND status = 0")
+            .bind(room_id.to_string())
+            .execute(conn)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// 构建查询条件
+    fn build_where(condition: &BedSearchCondition) -> (String, Vec<String>) {
+        let mut conditions = Vec::new();
+        let mut params = Vec::new();
+        if let Some(bed_no) = condition.bed_no.as_deref().filter(|value| !value.trim().is_empty()) {
+            conditions.push("b.bed_no LIKE ?");
+            params.push(format!("%{}%", bed_no.trim()));
+        }
+        if let Some(room_id) = condition.room_id {
+            conditions.push("b.room_id = ?");
+            params.push(room_id.to_string());
+        }
+        if let Some(status) = condition.status {
+            conditions.push("b.status = ?");
+            params.push(status.to_string());
+        }
+        if conditions.is_empty() {
+            (String::new(), params)
+        } else {
+            (format!("WHERE {}", conditions.join(" AND ")), params)
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use chrono::{DateTime, Utc};
+use sqlx::{mysql::MySqlRow, MySqlConnection, Row};
+use uuid::Uuid;
+use zzyl_common::error::Result;
+use zzyl_framework::database::{require_database_pool, DatabaseUtils};
+use crate::domain::CheckIn;
+
+/// 入住记录表查询列
+pub const CHECK_IN_COLUMNS: &str = "ci.id, ci.elder_id, ci.bed_id, ci.nursing_level_id, ci.check_in_time, \
+    ci.check_out_time, ci.status, ci.create_by, ci.create_time, ci.update_by, ci.update_time, ci.remark";
+
+/// 入住记录数据访问
+pub struct CheckInRepository;
+
+impl CheckInRepository {
+    /// 将查询结果映射为入住记录实体
+    pub fn map_row(row: &MySqlRow) -> Result<CheckIn> {
+        Ok(CheckIn {
+            id: DatabaseUtils::get_uuid(row, "id")?,
+            elder_id: DatabaseUtils::get_uuid(row, "elder_id")?,
+            bed_id: DatabaseUtils::get_uuid(row, "bed_id")?,
+            nursing_level_id: DatabaseUtils::get_uuid(row, "nursing_level_id")?,
+            check_in_time: row.try_get("check_in_time")?,
+            check_out_time: row.try_get("check_out_time")?,
+            status: row.try_get("status")?,
+            base: DatabaseUtils::get_base_entity(row)?,
+        })
+    }
+
+    /// 通过ID查询入住记录
+    pub async fn select_check_in_by_id(check_in_id: &Uuid) -> Result<Option<CheckIn>> {
+        let sql = format!("SELECT {} FROM check_in ci WHERE ci.id = ?", CHECK_IN_COLUMNS);
+        let row = sqlx::query(&sql)
+            .bind(check_in_id.to_string())
+            .fetch_optional(require_database_pool()?)
+            .await?;
+        row.as_ref().map(Self::map_row).transpose()
+    }
+
+    /// 查询老人当前的入住记录
+    pub async fn select_active_by_elder_id(elder_id: &Uuid) -> Result<Option<CheckIn>> {
+        let sql = format!(
+            "SELECT {} FROM check_in ci WHERE ci.elder_id = ? AND ci.status = 0 LIMIT 1",
+            CHECK_IN_COLUMNS
+        );
+        let row = sqlx::query(&sql)
+            .bind(elder_id.to_string())
+            .fetch_optional(require_database_pool()?)
+            .await?;
+        row.as_ref().map(Self::map_row).transpose()
+    }
+
+    /// 新增入住记录
+    pub async fn insert_check_in(conn: &mut MySqlConnection, check_in: &CheckIn) -> Result<u64> {
+        let result = sqlx::query(
+            "INSERT INTO check_in (id, elder_id, bed_id, nursing_level_id, check_in_time, status, create_by, \
+             create_time, remark) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+            .bind(check_in.id.map(|id| id.to_string()))
+            .bind(check_in.elder_id.map(|id| id.to_string()))
+            .bind(check_in.bed_id.map(|id| id.to_string()))
+            .bind(check_in.nursing_level_id.map(|id| id.to_string()))
+            .bind(check_in.check_in_time)
+            .bind(check_in.status)
+            .bind(&check_in.base.create_by)
+            .bind(check_in.base.create_time)
+            .bind(&check_in.base.remark)
+            .execute(conn)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// 登记退住，入住记录已退住时不更新
+    pub async fn check_out(
+        conn: &mut MySqlConnection,
+        check_in_id: &Uuid,
+        check_out_time: DateTime<Utc>,
+        update_by: &str,
+    ) -> Result<u64> {
+        let result = sqlx::query(
+            "UPDATE check_in SET status = 1, check_out_time = ?, update_by = ?, update_time = ? \
+             WHERE id = ? AND status = 0",
+        )
+            .bind(check_out_time)
+            .bind(update_by)
+            .bind(Utc::now())
+            .bind(check_in_id.to_string())
+            .execute(conn)
+            .await?;
+        Ok(result.rows_affected())
+    }
+}
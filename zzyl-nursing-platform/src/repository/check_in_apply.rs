@@ -0,0 +1,188 @@
+use sqlx::{mysql::MySqlRow, MySqlConnection, Row};
+use uuid::Uuid;
+use zzyl_common::enums::CheckInStage;
+use zzyl_common::error::Result;
+use zzyl_common::PageDomain;
+use zzyl_framework::database::{require_database_pool, DatabaseUtils};
+use crate::domain::{CheckInApply, CheckInApplySearchCondition};
+
+/// 入住申请表查询列
+pub const CHECK_IN_APPLY_COLUMNS: &str = "a.id, a.elder_id, a.stage, a.assessment, a.nursing_level_id, a.bed_id, \
+    a.contract_no, a.start_time, a.end_time, a.amount, a.deposit, a.check_in_id, \
+    a.create_by, a.create_time, a.update_by, a.update_time, a.remark";
+
+/// 入住申请数据访问
+pub struct CheckInApplyRepository;
+
+impl CheckInApplyRepository {
+    /// 将查询结果映射为入住申请实体
+    pub fn map_row(row: &MySqlRow) -> Result<CheckInApply> {
+        Ok(CheckInApply {
+            id: DatabaseUtils::get_uuid(row, "id")?,
+            elder_id: DatabaseUtils::get_uuid(row, "elder_id")?,
+            stage: CheckInStage::from(row.try_get::<i32, _>("stage")?),
+            assessment: row.try_get("assessment")?,
+            nursing_level_id: DatabaseUtils::get_uuid(row, "nursing_level_id")?,
+            bed_id: DatabaseUtils::get_uuid(row, "bed_id")?,
+            contract_no: row.try_get("contract_no")?,
+            start_time: row.try_get("start_time")?,
+            end_time: row.try_get("end_time")?,
+            amount: row.try_get("amount")?,
+            deposit: row.try_get("deposit")?,
+            check_in_id: DatabaseUtils::get_uuid(row, "check_in_id")?,
+            base: DatabaseUtils::get_base_entity(row)?,
+        })
+    }
+
+    /// 根据条件分页查询入住申请，返回当前页数据与总数
+    pub async fn select_apply_list(
+        condition: &CheckInApplySearchCondition,
+        page: &PageDomain,
+    ) -> Result<(Vec<CheckInApply>, i64)> {
+        let (where_sql, params) = Self::build_where(condition);
+        let pool = require_database_pool()?;
+
+        let count_sql = format!(
+            "SELECT COUNT(*) FROM check_in_apply a INNER JOIN elder e ON e.id = a.elder_id {}",
+            where_sql
+        );
+        let mut count_query = sqlx::query_scalar::<_, i64>(&count_sql);
+        for param in &params {
+            count_query = count_query.bind(param);
+        }
+        let total = count_query.fetch_one(pool).await?;
+
+        let list_sql = format!(
+            "SELECT {} FROM check_in_apply a INNER JOIN elder e ON e.id = a.elder_id {} \
+             ORDER BY a.create_time DESC LIMIT ? OFFSET ?",
+            CHECK_IN_APPLY_COLUMNS, where_sql
+        );
+        let mut list_query = sqlx::query(&list_sql);
+        for param in &params {
+            list_query = list_query.bind(param);
+        }
+        let rows = list_query
+            .bind(page.page_size() as i64)
+            .bind(page.offset())
+            .fetch_all(pool)
+            .await?;
+        let list = rows.iter().map(Self::map_row).collect::<Result<Vec<_>>>()?;
+
+        Ok((list, total))
+    }
+
+    /// 通过ID查询入住申请
+    pub async fn select_apply_by_id(apply_id: &Uuid) -> Result<Option<CheckInApply>> {
+        let sql = format!("SELECT {} FROM check_in_apply a WHERE a.id = ?", CHECK_IN_APPLY_COLUMNS);
+        let row = sqlx::query(&sql)
+            .bind(apply_id.to_string())
+            .fetch_optional(require_database_pool()?)
+            .await?;
+        row.as_ref().map(Self::map_row).transpose()
+    }
+
+    /// 查询老人办理中（未入住且未取消）的入住申请
+    pub async fn select_pending_by_elder_id(elder_id: &Uuid) -> Result<Option<CheckInApply>> {
+        let sql = format!(
+            "SELECT {} FROM check_in_apply a WHERE a.elder_id = ? AND a.stage NOT IN (?, ?) LIMIT 1",
+            CHECK_IN_APPLY_COLUMNS
+        );
+        let row = sqlx::query(&sql)
+            .bind(elder_id.to_string())
+            .bind(i32::from(CheckInStage::Admitted))
+            .bind(i32::from(CheckInStage::Cancelled))
+            .fetch_optional(require_database_pool()?)
+            .await?;
+        row.as_ref().map(Self::map_row).transpose()
+    }
+
+    /// 新增入住申请，老人已有办理中的申请时不插入
+    ///
+    /// 并发申请由唯一索引 `uk_check_in_apply_pending` 兜底，冲突时同样返回 0
+    pub async fn insert_apply(apply: &CheckInApply) -> Result<u64> {
+        let result = sqlx::query(
+            "INSERT INTO check_in_apply (id, elder_id, stage, create_by, create_time, remark) \
+             SELECT ?, ?, ?, ?, ?, ? FROM DUAL \
+             WHERE NOT EXISTS (SELECT 1 FROM check_in_apply WHERE elder_id = ? AND stage NOT IN (?, ?))",
+        )
+            .bind(apply.id.map(|id| id.to_string()))
+            .bind(apply.elder_id.map(|id| id.to_string()))
+            .bind(i32::from(apply.stage))
+            .bind(&apply.base.create_by)
+            .bind(apply.base.create_time)
+            .bind(&apply.base.remark)
+            .bind(apply.elder_id.map(|id| id.to_string()))
+            .bind(i32::from(CheckInStage::Admitted))
+            .bind(i32::from(CheckInStage::Cancelled))
+            .execute(require_database_pool()?)
+            .await;
+        match result {
+            Ok(result) => Ok(result.rows_affected()),
+            Err(sqlx::Error::Database(e)) if e.is_unique_violation() => Ok(0),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// 按原阶段修改入住申请，阶段不符时不更新
+    pub async fn update_apply(conn: &mut MySqlConnection, apply: &CheckInApply, from: CheckInStage) -> Result<u64> {
+        let result = sqlx::query(
+            "UPDATE check_in_apply SET stage = ?, assessment = ?, nursing_level_id = ?, bed_id = ?, contract_no = ?, \
+             start_time = ?, end_time = ?, amount = ?, deposit = ?, check_in_id = ?, update_by = ?, update_time = ? \
+             WHERE id = ? AND stage = ?",
+        )
+            .bind(i32::from(apply.stage))
+            .bind(&apply.assessment)
+            .bind(apply.nursing_level_id.map(|id| id.to_string()))
+            .bind(apply.bed_id.map(|id| id.to_string()))
+            .bind(&apply.contract_no)
+            .bind(apply.start_time)
+            .bind(apply.end_time)
+            .bind(apply.amount)
+            .bind(apply.deposit)
+            .bind(apply.check_in_id.map(|id| id.to_string()))
+            .bind(&apply.base.update_by)
+            .bind(apply.base.update_time)
+            .bind(apply.id.map(|id| id.to_string()))
+            .bind(i32::from(from))
+            .execute(conn)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// 统计选定该床位且办理中的入住申请数量
+    pub async fn count_pending_by_bed_id(bed_id: &Uuid) -> Result<i64> {
+        let count = sqlx::query_scalar::<_, i64>(
+            "SELECT COUNT(*) FROM check_in_apply WHERE bed_id = ? AND stage NOT IN (?, ?)",
+        )
+            .bind(bed_id.to_string())
+            .bind(i32::from(CheckInStage::Admitted))
+            .bind(i32::from(CheckInStage::Cancelled))
+            .fetch_one(require_database_pool()?)
+            .await?;
+        Ok(count)
+    }
+
+    /// 构建查询条件
+    fn build_where(condition: &CheckInApplySearchCondition) -> (String, Vec<String>) {
+        let mut conditions = Vec::new();
+        let mut params = Vec::new();
+        if let Some(elder_name) = condition.elder_name.as_deref().filter(|value| !value.trim().is_empty()) {
+            conditions.push("e.name LIKE ?");
+            params.push(format!("%{}%", elder_name.trim()));
+        }
+        if let Some(elder_id) = condition.elder_id {
+            conditions.push("a.elder_id = ?");
+            params.push(elder_id.to_string());
+        }
+        if let Some(stage) = condition.stage {
+            conditions.push("a.stage = ?");
+            params.push(i32::from(stage).to_string());
+        }
+        if conditions.is_empty() {
+            (String::new(), params)
+        } else {
+            (format!("WHERE {}", conditions.join(" AND ")), params)
+        }
+    }
+
+}
@@ -0,0 +1,100 @@
+use chrono::Utc;
+use sqlx::{mysql::MySqlRow, MySqlConnection, Row};
+use uuid::Uuid;
+use zzyl_common::error::Result;
+use zzyl_framework::database::{require_database_pool, DatabaseUtils};
+use crate::domain::Contract;
+
+/// 合同表查询列
+pub const CONTRACT_COLUMNS: &str = "c.id, c.contract_no, c.elder_id, c.start_time, c.end_time, c.amount, c.deposit, \
+    c.status, c.create_by, c.create_time, c.update_by, c.update_time, c.remark";
+
+/// 合同数据访问
+pub struct ContractRepository;
+
+impl ContractRepository {
+    /// 将查询结果映射为合同实体
+    pub fn map_row(row: &MySqlRow) -> Result<Contract> {
+        Ok(Contract {
+            id: DatabaseUtils::get_uuid(row, "id")?,
+            contract_no: row.try_get("contract_no")?,
+            elder_id: DatabaseUtils::get_uuid(row, "elder_id")?,
+            start_time: row.try_get("start_time")?,
+            end_time: row.try_get("end_time")?,
+            amount: row.try_get("amount")?,
+            deposit: row.try_get("deposit")?,
+            status: row.try_get("status")?,
+            base: DatabaseUtils::get_base_entity(row)?,
+        })
+    }
+
+    /// 通过ID查询合同
+    pub async fn select_contract_by_id(contract_id: &Uuid) -> Result<Option<Contract>> {
+        let sql = format!("SELECT {} FROM contract c WHERE c.id = ?", CONTRACT_COLUMNS);
+        let row = sqlx::query(&sql)
+            .bind(contract_id.to_string())
+            .fetch_optional(require_database_pool()?)
+            .await?;
+        row.as_ref().map(Self::map_row).transpose()
+    }
+
+    /// 查询老人当前有效的合同
+    pub async fn select_valid_by_elder_id(elder_id: &Uuid) -> Result<Option<Contract>> {
+        let sql = format!(
+            "SELECT {} FROM contract c WHERE c.elder_id = ? AND c.status = 0 ORDER BY c.start_time DESC LIMIT 1",
+            CONTRACT_COLUMNS
+        );
+        let row = sqlx::query(&sql)
+            .bind(elder_id.to_string())
+            .fetch_optional(require_database_pool()?)
+            .await?;
+        row.as_ref().map(Self::map_row).transpose()
+    }
+
+    /// 通过合同编号查询合同ID
+    pub async fn select_contract_id_by_no(contract_no: &str) -> Result<Option<Uuid>> {
+        let row = sqlx::query("SELECT id FROM contract WHERE contract_no = ? LIMIT 1")
+            .bind(contract_no)
+            .fetch_optional(require_database_pool()?)
+            .await?;
+        match row {
+            Some(row) => DatabaseUtils::get_uuid(&row, "id"),
+            None => Ok(None),
+        }
+    }
+
+    /// 新增合同
+    pub async fn insert_contract(conn: &mut MySqlConnection, contract: &Contract) -> Result<u64> {
+        let result = sqlx::query(
+            "INSERT INTO contract (id, contract_no, elder_id, start_time, end_time, amount, deposit, status, \
+             create_by, create_time, remark) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+            .bind(contract.id.map(|id| id.to_string()))
+            .bind(&contract.contract_no)
+            .bind(contract.elder_id.map(|id| id.to_string()))
+            .bind(contract.start_time)
+            .bind(contract.end_time)
+            .bind(contract.amount)
+            .bind(contract.deposit)
+            .bind(contract.status)
+            .bind(&contract.base.create_by)
+            .bind(contract.base.create_time)
+            .bind(&contract.base.remark)
+            .execute(conn)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// 终止有效合同
+    pub async fn terminate_contract(conn: &mut MySqlConnection, contract_id: &Uuid, update_by: &str) -> Result<u64> {
+        let result = sqlx::query(
+            "UPDATE contract SET status = 1, update_by = ?, update_time = ? WHERE id = ? AND status = 0",
+        )
+            .bind(update_by)
+            .bind(Utc::now())
+            .bind(contract_id.to_string())
+            .execute(conn)
+            .await?;
+        Ok(result.rows_affected())
+    }
+}
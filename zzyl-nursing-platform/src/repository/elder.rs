@@ -0,0 +1,234 @@
+use chrono::Utc;
+use sqlx::{mysql::MySqlRow, MySqlConnection, Row};
+use uuid::Uuid;
+use zzyl_common::enums::{ElderStatus, Gender};
+use zzyl_common::error::Result;
+use zzyl_common::PageDomain;
+use zzyl_framework::database::{require_database_pool, DatabaseUtils};
+use crate::domain::{Elder, ElderSearchCondition};
+
+/// 老人表查询列
+pub const ELDER_COLUMNS: &str = "e.id, e.name, e.image, e.id_card_no, e.sex, e.status, e.phone, e.birthday, \
+    e.address, e.id_card_front, e.id_card_back, e.create_by, e.create_time, e.update_by, e.update_time, e.remark";
+
+/// 老人与当前入住床位、房间的关联
+const ELDER_JOINS: &str = "LEFT JOIN check_in ci ON ci.elder_id = e.id AND ci.status = 0 \
+    LEFT JOIN bed b ON b.id = ci.bed_id \
+    LEFT JOIN room r ON r.id = b.room_id";
+
+/// 老人数据访问
+pub struct ElderRepository;
+
+impl ElderRepository {
+    /// 将查询结果映射为老人实体
+    pub fn map_row(row: &MySqlRow) -> Result<Elder> {
+        Ok(Elder {
+            id: DatabaseUtils::get_uuid(row, "id")?,
+            name: row.try_get("name")?,
+            image: row.try_get("image")?,
+            id_card_no: row.try_get("id_card_no")?,
+            sex: Gender::from(row.try_get::<i32, _>("sex")?),
+            status: ElderStatus::from(row.try_get::<i32, _>("status")?),
+            phone: row.try_get("phone")?,
+            birthday: row.try_get("birthday")?,
+            address: row.try_get("address")?,
+            id_card_front: row.try_get("id_card_front")?,
+            id_card_back: row.try_get("id_card_back")?,
+            base: DatabaseUtils::get_base_entity(row)?,
+        })
+    }
+
+    /// 根据条件分页查询老人，返回当前页数据与总数
+    pub async fn select_elder_list(condition: &ElderSearchCondition, page: &PageDomain) -> Result<(Vec<Elder>, i64)> {
+        let (where_sql, params) = Self::build_where(condition);
+        let pool = require_database_pool()?;
+
+        let count_sql = format!("SELECT COUNT(DISTINCT e.id) FROM elder e {} {}", ELDER_JOINS, where_sql);
+        let mut count_query = sqlx::query_scalar::<_, i64>(&count_sql);
+        for param in &params {
+            count_query = count_query.bind(param);
+        }
+        let total = count_query.fetch_one(pool).await?;
+
+        let list_sql = format!(
+            "SELECT DISTINCT {} FROM elder e {} {} ORDER BY e.create_time DESC LIMIT ? OFFSET ?",
+            ELDER_COLUMNS, ELDER_JOINS, where_sql
+        );
+        let mut list_query = sqlx::query(&list_sql);
+        for param in &params {
+            list_query = list_query.bind(param);
+        }
+        let rows = list_query
+            .bind(page.page_size() as i64)
+            .bind(page.offset())
+            .fetch_all(pool)
+            .await?;
+        let list = rows.iter().map(Self::map_row).collect::<Result<Vec<_>>>()?;
+
+        Ok((list, total))
+    }
+
+    /// 通过ID查询老人
+    pub async fn select_elder_by_id(elder_id: &Uuid) -> Result<Option<Elder>> {
+        let sql = format!("SELECT {} FROM elder e WHERE e.id = ?", ELDER_COLUMNS);
+        let row = sqlx::query(&sql)
+            .bind(elder_id.to_string())
+            .fetch_optional(require_database_pool()?)
+            .await?;
+        row.as_ref().map(Self::map_row).transpose()
+    }
+
+    /// 通过身份证号查询老人ID（用于唯一性校验）
+    pub async fn select_elder_id_by_id_card_no(id_card_no: &str) -> Result<Option<Uuid>> {
+        let row = sqlx::query("SELECT id FROM elder WHERE id_card_no = ? LIMIT 1")
+            .bind(id_card_no)
+            .fetch_optional(require_database_pool()?)
+            .await?;
+        match row {
+            Some(row) => DatabaseUtils::get_uuid(&row, "id"),
+            None => Ok(None),
+        }
+    }
+
+    /// 新增老人
+    pub async fn insert_elder(elder: &Elder) -> Result<u64> {
+        let result = sqlx::query(
+            "INSERT INTO elder (id, name, image, id_card_no, sex, status, phone, birthday, address, id_card_front, \
+             id_card_back, create_by, create_time, remark) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+            .bind(elder.id.map(|id| id.to_string()))
+            .bind(&elder.name)
+            .bind(&elder.image)
+            .bind(&elder.id_card_no)
+            .bind(i32::from(elder.sex))
+            .bind(i32::from(elder.status))
+            .bind(&elder.phone)
+            .bind(elder.birthday)
+            .bind(&elder.address)
+            .bind(&elder.id_card_front)
+            .bind(&elder.id_card_back)
+            .bind(&elder.base.create_by)
+            .bind(elder.base.create_time)
+            .bind(&elder.base.remark)
+            .execute(require_database_pool()?)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// 修改老人基本信息（不含状态）
+    pub async fn update_elder(elder: &Elder) -> Result<u64> {
+        let result = sqlx::query(
+            "UPDATE elder SET name = ?, image = ?, id_card_no = ?, sex = ?, phone = ?, birthday = ?, address = ?, \
+             id_card_front = ?, id_card_back = ?, update_by = ?, update_time = ?, remark = ? WHERE id = ?",
+        )
+            .bind(&elder.name)
+            .bind(&elder.image)
+            .bind(&elder.id_card_no)
+            .bind(i32::from(elder.sex))
+            .bind(&elder.phone)
+            .bind(elder.birthday)
+            .bind(&elder.address)
+            .bind(&elder.id_card_front)
+            .bind(&elder.id_card_back)
+            .bind(&elder.base.update_by)
+            .bind(elder.base.update_time)
+            .bind(&elder.base.remark)
+            .bind(elder.id.map(|id| id.to_string()))
+            .execute(require_database_pool()?)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// 按原状态修改老人状态，原状态不符时不更新
+    pub async fn update_status(
+        conn: &mut MySqlConnection,
+        elder_id: &Uuid,
+        from: ElderStatus,
+        to: ElderStatus,
+        update_by: &str,
+    ) -> Result<u64> {
+        let result = sqlx::query("UPDATE elder SET status = ?, update_by = ?, update_time = ? WHERE id = ? AND status = ?")
+            .bind(i32::from(to))
+            .bind(update_by)
+            .bind(Utc::now())
+            .bind(elder_id.to_string())
+            .bind(i32::from(from))
+            .execute(conn)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// 删除老人
+    pub async fn delete_elder_by_id(conn: &mut MySqlConnection, elder_id: &Uuid) -> Result<u64> {
+        let result = sqlx::query("DELETE FROM elder WHERE id = ?")
+            .bind(elder_id.to_string())
+            .execute(conn)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// 查询在住老人及其床位ID，可按房间过滤
+    pub async fn select_checked_in_elders(room_id: Option<&Uuid>) -> Result<Vec<(Uuid, Elder)>> {
+        let room_sql = if room_id.is_some() { "AND b.room_id = ?" } else { "" };
+        let sql = format!(
+            "SELECT {}, ci.bed_id FROM check_in ci INNER JOIN elder e ON e.id = ci.elder_id \
+             INNER JOIN bed b ON b.id = ci.bed_id WHERE ci.status = 0 {}",
+            ELDER_COLUMNS, room_sql
+        );
+        let mut query = sqlx::query(&sql);
+        if let Some(room_id) = room_id {
+            query = query.bind(room_id.to_string());
+        }
+        let rows = query.fetch_all(require_database_pool()?).await?;
+        rows.iter()
+            .filter_map(|row| match DatabaseUtils::get_uuid(row, "bed_id") {
+                Ok(Some(bed_id)) => Some(Self::map_row(row).map(|elder| (bed_id, elder))),
+                Ok(None) => None,
+                Err(e) => Some(Err(e)),
+            })
+            .collect()
+    }
+
+    /// 构建查询条件
+    fn build_where(condition: &ElderSearchCondition) -> (String, Vec<String>) {
+        let mut conditions = Vec::new();
+        let mut params = Vec::new();
+        if let Some(name) = condition.name.as_deref().filter(|value| !value.trim().is_empty()) {
+            conditions.push("e.name LIKE ?");
+            params.push(format!("%{}%", name.trim()));
+        }
+        if let Some(id_card_no) = condition.id_card_no.as_deref().filter(|value| !value.trim().is_empty()) {
+            conditions.push("e.id_card_no LIKE ?");
+            params.push(format!("%{}%", id_card_no.trim()));
+        }
+        if let Some(phone) = condition.phone.as_deref().filter(|value| !value.trim().is_empty()) {
+            conditions.push("e.phone LIKE ?");
+            params.push(format!("%{}%", phone.trim()));
+        }
+        if let Some(sex) = condition.sex {
+            conditions.push("e.sex = ?");
+            params.push(i32::from(sex).to_string());
+        }
+        if let Some(status) = condition.status {
+            conditions.push("e.status = ?");
+            params.push(i32::from(status).to_string());
+        }
+        if let Some(floor_id) = condition.floor_id {
+            conditions.push("r.floor_id = ?");
+            params.push(floor_id.to_string());
+        }
+        if let Some(room_id) = condition.room_id {
+            conditions.push("r.id = ?");
+            params.push(room_id.to_string());
+        }
+        if let Some(bed_id) = condition.bed_id {
+            conditions.push("b.id = ?");
+            params.push(bed_id.to_string());
+        }
+        if conditions.is_empty() {
+            (String::new(), params)
+        } else {
+            (format!("WHERE {}", conditions.join(" AND ")), params)
+        }
+    }
+}
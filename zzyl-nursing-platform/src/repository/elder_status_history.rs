@@ -0,0 +1,58 @@
+use sqlx::{mysql::MySqlRow, MySqlConnection, Row};
+use uuid::Uuid;
+use zzyl_common::enums::ElderStatus;
+use zzyl_common::error::Result;
+use zzyl_framework::database::{require_database_pool, DatabaseUtils};
+use crate::domain::ElderStatusHistory;
+
+/// 老人状态变更记录表查询列
+pub const ELDER_STATUS_HISTORY_COLUMNS: &str = "h.id, h.elder_id, h.from_status, h.to_status, h.reason, \
+    h.create_by, h.create_time, h.update_by, h.update_time, h.remark";
+
+/// 老人状态变更记录数据访问
+pub struct ElderStatusHistoryRepository;
+
+impl ElderStatusHistoryRepository {
+    /// 将查询结果映射为状态变更记录
+    pub fn map_row(row: &MySqlRow) -> Result<ElderStatusHistory> {
+        Ok(ElderStatusHistory {
+            id: DatabaseUtils::get_uuid(row, "id")?,
+            elder_id: DatabaseUtils::get_uuid(row, "elder_id")?,
+            from_status: ElderStatus::from(row.try_get::<i32, _>("from_status")?),
+            to_status: ElderStatus::from(row.try_get::<i32, _>("to_status")?),
+            reason: row.try_get("reason")?,
+            base: DatabaseUtils::get_base_entity(row)?,
+        })
+    }
+
+    /// 查询老人的状态变更记录，按时间倒序
+    pub async fn select_by_elder_id(elder_id: &Uuid) -> Result<Vec<ElderStatusHistory>> {
+        let sql = format!(
+            "SELECT {} FROM elder_status_history h WHERE h.elder_id = ? ORDER BY h.create_time DESC",
+            ELDER_STATUS_HISTORY_COLUMNS
+        );
+        let rows = sqlx::query(&sql)
+            .bind(elder_id.to_string())
+            .fetch_all(require_database_pool()?)
+            .await?;
+        rows.iter().map(Self::map_row).collect()
+    }
+
+    /// 新增状态变更记录
+    pub async fn insert_history(conn: &mut MySqlConnection, history: &ElderStatusHistory) -> Result<u64> {
+        let result = sqlx::query(
+            "INSERT INTO elder_status_history (id, elder_id, from_status, to_status, reason, create_by, create_time) \
+             VALUES (?, ?, ?, ?, ?, ?, ?)",
+        )
+            .bind(history.id.map(|id| id.to_string()))
+            .bind(history.elder_id.map(|id| id.to_string()))
+            .bind(i32::from(history.from_status))
+            .bind(i32::from(history.to_status))
+            .bind(&history.reason)
+            .bind(&history.base.create_by)
+            .bind(history.base.create_time)
+            .execute(conn)
+            .await?;
+        Ok(result.rows_affected())
+    }
+}
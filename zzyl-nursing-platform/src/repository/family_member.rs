@@ -0,0 +1,45 @@
+use sqlx::{mysql::MySqlRow, MySqlConnection, Row};
+use uuid::Uuid;
+use zzyl_common::error::Result;
+use zzyl_framework::database::{require_database_pool, DatabaseUtils};
+use crate::domain::FamilyMember;
+
+/// 家庭成员表查询列
+pub const FAMILY_MEMBER_COLUMNS: &str = "f.id, f.elder_id, f.member_name, f.relationship, f.phone, \
+    f.create_by, f.create_time, f.update_by, f.update_time, f.remark";
+
+/// 家庭成员数据访问
+pub struct FamilyMemberRepository;
+
+impl FamilyMemberRepository {
+    /// 将查询结果映射为家庭成员实体
+    pub fn map_row(row: &MySqlRow) -> Result<FamilyMember> {
+        Ok(FamilyMember {
+            id: DatabaseUtils::get_uuid(row, "id")?,
+            elder_id: DatabaseUtils::get_uuid(row, "elder_id")?,
+            member_name: row.try_get("member_name")?,
+            relationship: row.try_get("relationship")?,
+            phone: row.try_get("phone")?,
+            base: DatabaseUtils::get_base_entity(row)?,
+        })
+    }
+
+    /// 查询老人的家庭成员
+    pub async fn select_by_elder_id(elder_id: &Uuid) -> Result<Vec<FamilyMember>> {
+        let sql = format!("SELECT {} FROM family_member f WHERE f.elder_id = ? ORDER BY f.create_time", FAMILY_MEMBER_COLUMNS);
+        let rows = sqlx::query(&sql)
+            .bind(elder_id.to_string())
+            .fetch_all(require_database_pool()?)
+            .await?;
+        rows.iter().map(Self::map_row).collect()
+    }
+
+    /// 删除老人的全部家庭成员
+    pub async fn delete_by_elder_id(conn: &mut MySqlConnection, elder_id: &Uuid) -> Result<u64> {
+        let result = sqlx::query("DELETE FROM family_member WHERE elder_id = ?")
+            .bind(elder_id.to_string())
+            .execute(conn)
+            .await?;
+        Ok(result.rows_affected())
+    }
+}
@@ -0,0 +1,102 @@
+use sqlx::{mysql::MySqlRow, MySqlConnection, Row};
+use uuid::Uuid;
+use zzyl_common::error::Result;
+use zzyl_framework::database::{require_database_pool, DatabaseUtils};
+use crate::domain::Floor;
+
+/// 楼层表查询列
+pub const FLOOR_COLUMNS: &str = "fl.id, fl.floor_name, fl.floor_no, fl.status, \
+    fl.create_by, fl.create_time, fl.update_by, fl.update_time, fl.remark";
+
+/// 楼层数据访问
+pub struct FloorRepository;
+
+impl FloorRepository {
+    /// 将查询结果映射为楼层实体
+    pub fn map_row(row: &MySqlRow) -> Result<Floor> {
+        Ok(Floor {
+            id: DatabaseUtils::get_uuid(row, "id")?,
+            floor_name: row.try_get("floor_name")?,
+            floor_no: row.try_get("floor_no")?,
+            status: row.try_get("status")?,
+            base: DatabaseUtils::get_base_entity(row)?,
+        })
+    }
+
+    /// 通过ID查询楼层
+    pub async fn select_floor_by_id(floor_id: &Uuid) -> Result<Option<Floor>> {
+        let sql = format!("SELECT {} FROM floor fl WHERE fl.id = ?", FLOOR_COLUMNS);
+        let row = sqlx::query(&sql)
+            .bind(floor_id.to_string())
+            .fetch_optional(require_database_pool()?)
+            .await?;
+        row.as_ref().map(Self::map_row).transpose()
+    }
+
+    /// 查询全部楼层，按楼层号排序
+    pub async fn select_floor_all() -> Result<Vec<Floor>> {
+        let sql = format!("SELECT {} FROM floor fl ORDER BY fl.floor_no", FLOOR_COLUMNS);
+        let rows = sqlx::query(&sql).fetch_all(require_database_pool()?).await?;
+        rows.iter().map(Self::map_row).collect()
+    }
+
+    /// 通过楼层号查询楼层ID
+    pub async fn select_floor_id_by_no(floor_no: i32) -> Result<Option<Uuid>> {
+        let row = sqlx::query("SELECT id FROM floor WHERE floor_no = ? LIMIT 1")
+            .bind(floor_no)
+            .fetch_optional(require_database_pool()?)
+            .await?;
+        match row {
+            Some(row) => DatabaseUtils::get_uuid(&row, "id"),
+            None => Ok(None),
+        }
+    }
+
+    /// 新增楼层
+    pub async fn insert_floor(floor: &Floor) -> Result<u64> {
+        let result = sqlx::query(
+            "INSERT INTO floor (id, floor_name, floor_no, status, create_by, create_time, remark) \
+             VALUES (?, ?, ?, ?, ?, ?, ?)",
+        )
+            .bind(floor.id.map(|id| id.to_string()))
+            .bind(&floor.floor_name)
+            .bind(floor.floor_no)
+            .bind(floor.status)
+            .bind(&floor.base.create_by)
+            .bind(floor.base.create_time)
+            .bind(&floor.base.remark)
+            .execute(require_database_pool()?)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// 修改楼层
+    pub async fn update_floor(floor: &Floor) -> Result<u64> {
+        let result = sqlx::query(
+            "UPDATE floor SET floor_name = ?, floor_no = ?, status = ?, update_by = ?, update_time = ?, remark = ? \
+             WHERE id = ?",
+        )
+            .bind(&floor.floor_name)
+            .bind(floor.floor_no)
+            .bind(floor.status)
+            .bind(&floor.base.update_by)
+            .bind(floor.base.update_time)
+            .bind(&floor.base.remark)
+            .bind(floor.id.map(|id| id.to_string()))
+            .execute(require_database_pool()?)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// 删除已无房间的楼层，楼层下仍有房间时不删除
+    pub async fn delete_empty_floor_by_id(conn: &mut MySqlConnection, floor_id: &Uuid) -> Result<u64> {
+        let result = sqlx::query(
+            "DELETE FROM floor WHERE id = ? AND NOT EXISTS (SELECT 1 FROM room WHERE floor_id = ?)",
+        )
+            .bind(floor_id.to_string())
+            .bind(floor_id.to_string())
+            .execute(conn)
+            .await?;
+        Ok(result.rows_affected())
+    }
+}
@@ -0,0 +1,37 @@
+use sqlx::{mysql::MySqlRow, Row};
+use uuid::Uuid;
+use zzyl_common::error::Result;
+use zzyl_framework::database::{require_database_pool, DatabaseUtils};
+use crate::domain::NursingLevel;
+
+/// 护理等级表查询列
+pub const NURSING_LEVEL_COLUMNS: &str = "nl.id, nl.name, nl.plan_id, nl.fee, nl.status, nl.description, \
+    nl.create_by, nl.create_time, nl.update_by, nl.update_time, nl.remark";
+
+/// 护理等级数据访问
+pub struct NursingLevelRepository;
+
+impl NursingLevelRepository {
+    /// 将查询结果映射为护理等级实体
+    pub fn map_row(row: &MySqlRow) -> Result<NursingLevel> {
+        Ok(NursingLevel {
+            id: DatabaseUtils::get_uuid(row, "id")?,
+            name: row.try_get("name")?,
+            plan_id: DatabaseUtils::get_uuid(row, "plan_id")?,
+            fee: row.try_get("fee")?,
+            status: row.try_get("status")?,
+            description: row.try_get("description")?,
+            base: DatabaseUtils::get_base_entity(row)?,
+        })
+    }
+
+    /// 通过ID查询护理等级
+    pub async fn select_level_by_id(level_id: &Uuid) -> Result<Option<NursingLevel>> {
+        let sql = format!("SELECT {} FROM nursing_level nl WHERE nl.id = ?", NURSING_LEVEL_COLUMNS);
+        let row = sqlx::query(&sql)
+            .bind(level_id.to_string())
+            .fetch_optional(require_database_pool()?)
+            .await?;
+        row.as_ref().map(Self::map_row).transpose()
+    }
+}
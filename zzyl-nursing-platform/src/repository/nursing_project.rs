@@ -0,0 +1,39 @@
+use sqlx::{mysql::MySqlRow, Row};
+use uuid::Uuid;
+use zzyl_common::error::Result;
+use zzyl_framework::database::{require_database_pool, DatabaseUtils};
+use crate::domain::NursingProject;
+
+/// 护理项目表查询列
+pub const NURSING_PROJECT_COLUMNS: &str = "p.id, p.name, p.order_no, p.unit, p.price, p.image, \
+    p.nursing_requirement, p.status, p.create_by, p.create_time, p.update_by, p.update_time, p.remark";
+
+/// 护理项目数据访问
+pub struct NursingProjectRepository;
+
+impl NursingProjectRepository {
+    /// 将查询结果映射为护理项目实体
+    pub fn map_row(row: &MySqlRow) -> Result<NursingProject> {
+        Ok(NursingProject {
+            id: DatabaseUtils::get_uuid(row, "id")?,
+            name: row.try_get("name")?,
+            order_no: row.try_get("order_no")?,
+            unit: row.try_get("unit")?,
+            price: row.try_get("price")?,
+            image: row.try_get("image")?,
+            nursing_requirement: row.try_get("nursing_requirement")?,
+            status: row.try_get("status")?,
+            base: DatabaseUtils::get_base_entity(row)?,
+        })
+    }
+
+    /// 通过ID查询护理项目
+    pub async fn select_project_by_id(project_id: &Uuid) -> Result<Option<NursingProject>> {
+        let sql = format!("SELECT {} FROM nursing_project p WHERE p.id = ?", NURSING_PROJECT_COLUMNS);
+        let row = sqlx::query(&sql)
+            .bind(project_id.to_string())
+            .fetch_optional(require_database_pool()?)
+            .await?;
+        row.as_ref().map(Self::map_row).transpose()
+    }
+}
@@ -0,0 +1,107 @@
+use sqlx::{mysql::MySqlRow, Row};
+use zzyl_common::error::Result;
+use zzyl_common::PageDomain;
+use zzyl_framework::database::{require_database_pool, DatabaseUtils};
+use crate::domain::{NursingRecord, NursingRecordSearchCondition};
+
+/// 护理记录表查询列
+pub const NURSING_RECORD_COLUMNS: &str = "nr.id, nr.elder_id, nr.project_id, p.name AS project_name, nr.quantity, \
+    nr.execute_time, nr.create_by, nr.create_time, nr.update_by, nr.update_time, nr.remark";
+
+/// 护理记录数据访问
+pub struct NursingRecordRepository;
+
+impl NursingRecordRepository {
+    /// 将查询结果映射为护理记录实体
+    pub fn map_row(row: &MySqlRow) -> Result<NursingRecord> {
+        Ok(NursingRecord {
+            id: DatabaseUtils::get_uuid(row, "id")?,
+            elder_id: DatabaseUtils::get_uuid(row, "elder_id")?,
+            project_id: DatabaseUtils::get_uuid(row, "project_id")?,
+            project_name: row.try_get("project_name")?,
+            quantity: row.try_get("quantity")?,
+            execute_time: row.try_get("execute_time")?,
+            base: DatabaseUtils::get_base_entity(row)?,
+        })
+    }
+
+    /// 根据条件分页查询护理记录，返回当前页数据与总数
+    pub async fn select_record_list(
+        condition: &NursingRecordSearchCondition,
+        page: &PageDomain,
+    ) -> Result<(Vec<NursingRecord>, i64)> {
+        let (where_sql, params) = Self::build_where(condition);
+        let pool = require_database_pool()?;
+
+        let count_sql = format!("SELECT COUNT(*) FROM nursing_record nr {}", where_sql);
+        let mut count_query = sqlx::query_scalar::<_, i64>(&count_sql);
+        for param in &params {
+            count_query = count_query.bind(param);
+        }
+        let total = count_query.fetch_one(pool).await?;
+
+        let list_sql = format!(
+            "SELECT {} FROM nursing_record nr LEFT JOIN nursing_project p ON p.id = nr.project_id {} \
+             ORDER BY nr.execute_time DESC LIMIT ? OFFSET ?",
+            NURSING_RECORD_COLUMNS, where_sql
+        );
+        let mut list_query = sqlx::query(&list_sql);
+        for param in &params {
+            list_query = list_query.bind(param);
+        }
+        let rows = list_query
+            .bind(page.page_size() as i64)
+            .bind(page.offset())
+            .fetch_all(pool)
+            .await?;
+        let list = rows.iter().map(Self::map_row).collect::<Result<Vec<_>>>()?;
+
+        Ok((list, total))
+    }
+
+    /// 新增护理记录
+    pub async fn insert_record(record: &NursingRecord) -> Result<u64> {
+        let result = sqlx::query(
+            "INSERT INTO nursing_record (id, elder_id, project_id, quantity, execute_time, create_by, create_time, \
+             remark) VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+            .bind(record.id.map(|id| id.to_string()))
+            .bind(record.elder_id.map(|id| id.to_string()))
+            .bind(record.project_id.map(|id| id.to_string()))
+            .bind(record.quantity)
+            .bind(record.execute_time)
+            .bind(&record.base.create_by)
+            .bind(record.base.create_time)
+            .bind(&record.base.remark)
+            .execute(require_database_pool()?)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// 构建查询条件
+    fn build_where(condition: &NursingRecordSearchCondition) -> (String, Vec<String>) {
+        let mut conditions = Vec::new();
+        let mut params = Vec::new();
+        if let Some(elder_id) = condition.elder_id {
+            conditions.push("nr.elder_id = ?");
+            params.push(elder_id.to_string());
+        }
+        if let Some(project_id) = condition.project_id {
+            conditions.push("nr.project_id = ?");
+            params.push(project_id.to_string());
+        }
+        if let Some(begin_time) = condition.begin_time.as_deref().filter(|value| !value.trim().is_empty()) {
+            conditions.push("DATE(nr.execute_time) >= DATE(?)");
+            params.push(begin_time.trim().to_string());
+        }
+        if let Some(end_time) = condition.end_time.as_deref().filter(|value| !value.trim().is_empty()) {
+            conditions.push("DATE(nr.execute_time) <= DATE(?)");
+            params.push(end_time.trim().to_string());
+        }
+        if conditions.is_empty() {
+            (String::new(), params)
+        } else {
+            (format!("WHERE {}", conditions.join(" AND ")), params)
+        }
+    }
+}
@@ -0,0 +1,198 @@
+use sqlx::{mysql::MySqlRow, MySqlConnection, Row};
+use uuid::Uuid;
+use zzyl_common::error::Result;
+use zzyl_common::PageDomain;
+use zzyl_framework::database::{require_database_pool, DatabaseUtils};
+use crate::domain::{Room, RoomSearchCondition};
+
+/// 房间表查询列
+pub const ROOM_COLUMNS: &str = "r.id, r.room_no, r.floor_id, r.room_type_id, r.status, \
+    r.create_by, r.create_time, r.update_by, r.update_time, r.remark";
+
+/// 房间数据访问
+pub struct RoomRepository;
+
+impl RoomRepository {
+    /// 将查询结果映射为房间实体
+    pub fn map_row(row: &MySqlRow) -> Result<Room> {
+        Ok(Room {
+            id: DatabaseUtils::get_uuid(row, "id")?,
+            room_no: row.try_get("room_no")?,
+            floor_id: DatabaseUtils::get_uuid(row, "floor_id")?,
+            room_type_id: DatabaseUtils::get_uuid(row, "room_type_id")?,
+            status: row.try_get("status")?,
+            base: DatabaseUtils::get_base_entity(row)?,
+        })
+    }
+
+    /// 通过ID查询房间
+    pub async fn select_room_by_id(room_id: &Uuid) -> Result<Option<Room>> {
+        let sql = format!("SELECT {} FROM room r WHERE r.id = ?", ROOM_COLUMNS);
+        let row = sqlx::query(&sql)
+            .bind(room_id.to_string())
+            .fetch_optional(require_database_pool()?)
+            .await?;
+        row.as_ref().map(Self::map_row).transpose()
+    }
+
+    /// 按床位占用情况刷新房间状态
+    pub async fn refresh_room_status(conn: &mut MySqlConnection, room_id: &Uuid) -> Result<u64> {
+        let result = sqlx::query(
+            "UPDATE room SET status = IF(EXISTS(SELECT 1 FROM bed WHERE room_id = ? AND status = 1), 1, 0) WHERE id = ?",
+        )
+            .bind(room_id.to_string())
+            .bind(room_id.to_string())
+            .execute(conn)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// 根据条件分页查询房间，返回当前页数据与总数
+    pub async fn select_room_list(condition: &RoomSearchCondition, page: &PageDomain) -> Result<(Vec<Room>, i64)> {
+        let (where_sql, params) = Self::build_where(condition);
+        let pool = require_database_pool()?;
+
+        let count_sql = format!("SELECT COUNT(*) FROM room r {}", where_sql);
+        let mut count_query = sqlx::query_scalar::<_, i64>(&count_sql);
+        for param in &params {
+            count_query = count_query.bind(param);
+        }
+        let total = count_query.fetch_one(pool).await?;
+
+        let list_sql = format!("SELECT {} FROM room r {} ORDER BY r.room_no LIMIT ? OFFSET ?", ROOM_COLUMNS, where_sql);
+        let mut list_query = sqlx::query(&list_sql);
+        for param in &params {
+            list_query = list_query.bind(param);
+        }
+        let rows = list_query
+            .bind(page.page_size() as i64)
+            .bind(page.offset())
+            .fetch_all(pool)
+            .await?;
+        let list = rows.iter().map(Self::map_row).collect::<Result<Vec<_>>>()?;
+
+        Ok((list, total))
+    }
+
+    /// 查询全部房间，按房间号排序
+    pub async fn select_room_all() -> Result<Vec<Room>> {
+        let sql = format!("SELECT {} FROM room r ORDER BY r.room_no", ROOM_COLUMNS);
+        let rows = sqlx::query(&sql).fetch_all(require_database_pool()?).await?;
+        rows.iter().map(Self::map_row).collect()
+    }
+
+    /// 查询楼层下的房间
+    pub async fn select_rooms_by_floor_id(floor_id: &Uuid) -> Result<Vec<Room>> {
+        let sql = format!("SELECT {} FROM room r WHERE r.floor_id = ? ORDER BY r.room_no", ROOM_COLUMNS);
+        let rows = sqlx::query(&sql)
+            .bind(floor_id.to_string())
+            .fetch_all(require_database_pool()?)
+            .await?;
+        rows.iter().map(Self::map_row).collect()
+    }
+
+    /// 通过楼层与房间号查询房间ID
+    pub async fn select_room_id_by_no(floor_id: &Uuid, room_no: &str) -> Result<Option<Uuid>> {
+        let row = sqlx::query("SELECT id FROM room WHERE floor_id = ? AND room_no = ? LIMIT 1")
+            .bind(floor_id.to_string())
+            .bind(room_no)
+            .fetch_optional(require_database_pool()?)
+            .await?;
+        match row {
+            Some(row) => DatabaseUtils::get_uuid(&row, "id"),
+            None => Ok(None),
+        }
+    }
+
+    /// 统计楼层下的房间数量
+    pub async fn count_room_by_floor_id(floor_id: &Uuid) -> Result<i64> {
+        let count = sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM room WHERE floor_id = ?")
+            .bind(floor_id.to_string())
+            .fetch_one(require_database_pool()?)
+            .await?;
+        Ok(count)
+    }
+
+    /// 统计使用该房间类型的房间数量
+    pub async fn count_room_by_room_type_id(room_type_id: &Uuid) -> Result<i64> {
+        let count = sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM room WHERE room_type_id = ?")
+            .bind(room_type_id.to_string())
+            .fetch_one(require_database_pool()?)
+            .await?;
+        Ok(count)
+    }
+
+    /// 新增房间
+    pub async fn insert_room(room: &Room) -> Result<u64> {
+        let result = sqlx::query(
+            "INSERT INTO room (id, room_no, floor_id, room_type_id, status, create_by, create_time, remark) \
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+            .bind(room.id.map(|id| id.to_string()))
+            .bind(&room.room_no)
+            .bind(room.floor_id.map(|id| id.to_string()))
+            .bind(room.room_type_id.map(|id| id.to_string()))
+            .bind(room.status)
+            .bind(&room.base.create_by)
+            .bind(room.base.create_time)
+            .bind(&room.base.remark)
+            .execute(require_database_pool()?)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// 修改房间，状态由床位占用情况维护
+    pub async fn update_room(room: &Room) -> Result<u64> {
+        let result = sqlx::query(
+            "UPDATE room SET room_no = ?, floor_id = ?, room_type_id = ?, update_by = ?, update_time = ?, remark = ? \
+             WHERE id = ?",
+        )
+            .bind(&room.room_no)
+            .bind(room.floor_id.map(|id| id.to_string()))
+            .bind(room.room_type_id.map(|id| id.to_string()))
+            .bind(&room.base.update_by)
+            .bind(room.base.update_time)
+            .bind(&room.base.remark)
+            .bind(room.id.map(|id| id.to_string()))
+            .execute(require_database_pool()?)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// 删除已无床位的房间，房间仍有床位时不删除
+    pub async fn delete_empty_room_by_id(conn: &mut MySqlConnection, room_id: &Uuid) -> Result<u64> {
+        let result = sqlx::query("DELETE FROM room WHERE id = ? AND NOT EXISTS (SELECT 1 FROM bed WHERE room_id = ?)")
+            .bind(room_id.to_string())
+            .bind(room_id.to_string())
+            .execute(conn)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// 构建查询条件
+    fn build_where(condition: &RoomSearchCondition) -> (String, Vec<String>) {
+        let mut conditions = Vec::new();
+        let mut params = Vec::new();
+        if let Some(room_no) = condition.room_no.as_deref().filter(|value| !value.trim().is_empty()) {
+            conditions.push("r.room_no LIKE ?");
+            params.push(format!("%{}%", room_no.trim()));
+        }
+        if let Some(floor_id) = condition.floor_id {
+            conditions.push("r.floor_id = ?");
+            params.push(floor_id.to_string());
+        }
+        if let Some(room_type_id) = condition.room_type_id {
+            conditions.push("r.room_type_id = ?");
+            params.push(room_type_id.to_string());
+        }
+        if let Some(status) = condition.status {
+            conditions.push("r.status = ?");
+            params.push(status.to_string());
+        }
+        if conditions.is_empty() {
+            (String::new(), params)
+        } else {
+            (format!("WHERE {}", conditions.join(" AND ")), params)
+        }
+    }
+}
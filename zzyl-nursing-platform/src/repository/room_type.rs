@@ -0,0 +1,102 @@
+use sqlx::{mysql::MySqlRow, MySqlConnection, Row};
+use uuid::Uuid;
+use zzyl_common::error::Result;
+use zzyl_framework::database::{require_database_pool, DatabaseUtils};
+use crate::domain::RoomType;
+
+/// 房间类型表查询列
+pub const ROOM_TYPE_COLUMNS: &str = "rt.id, rt.type_name, rt.price, rt.status, \
+    rt.create_by, rt.create_time, rt.update_by, rt.update_time, rt.remark";
+
+/// 房间类型数据访问
+pub struct RoomTypeRepository;
+
+impl RoomTypeRepository {
+    /// 将查询结果映射为房间类型实体
+    pub fn map_row(row: &MySqlRow) -> Result<RoomType> {
+        Ok(RoomType {
+            id: DatabaseUtils::get_uuid(row, "id")?,
+            type_name: row.try_get("type_name")?,
+            price: row.try_get("price")?,
+            status: row.try_get("status")?,
+            base: DatabaseUtils::get_base_entity(row)?,
+        })
+    }
+
+    /// 通过ID查询房间类型
+    pub async fn select_room_type_by_id(room_type_id: &Uuid) -> Result<Option<RoomType>> {
+        let sql = format!("SELECT {} FROM room_type rt WHERE rt.id = ?", ROOM_TYPE_COLUMNS);
+        let row = sqlx::query(&sql)
+            .bind(room_type_id.to_string())
+            .fetch_optional(require_database_pool()?)
+            .await?;
+        row.as_ref().map(Self::map_row).transpose()
+    }
+
+    /// 查询全部房间类型
+    pub async fn select_room_type_all() -> Result<Vec<RoomType>> {
+        let sql = format!("SELECT {} FROM room_type rt ORDER BY rt.create_time", ROOM_TYPE_COLUMNS);
+        let rows = sqlx::query(&sql).fetch_all(require_database_pool()?).await?;
+        rows.iter().map(Self::map_row).collect()
+    }
+
+    /// 通过类型名称查询房间类型ID
+    pub async fn select_room_type_id_by_name(type_name: &str) -> Result<Option<Uuid>> {
+        let row = sqlx::query("SELECT id FROM room_type WHERE type_name = ? LIMIT 1")
+            .bind(type_name)
+            .fetch_optional(require_database_pool()?)
+            .await?;
+        match row {
+            Some(row) => DatabaseUtils::get_uuid(&row, "id"),
+            None => Ok(None),
+        }
+    }
+
+    /// 新增房间类型
+    pub async fn insert_room_type(room_type: &RoomType) -> Result<u64> {
+        let result = sqlx::query(
+            "INSERT INTO room_type (id, type_name, price, status, create_by, create_time, remark) \
+             VALUES (?, ?, ?, ?, ?, ?, ?)",
+        )
+            .bind(room_type.id.map(|id| id.to_string()))
+            .bind(&room_type.type_name)
+            .bind(room_type.price)
+            .bind(room_type.status)
+            .bind(&room_type.base.create_by)
+            .bind(room_type.base.create_time)
+            .bind(&room_type.base.remark)
+            .execute(require_database_pool()?)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// 修改房间类型
+    pub async fn update_room_type(room_type: &RoomType) -> Result<u64> {
+        let result = sqlx::query(
+            "UPDATE room_type SET type_name = ?, price = ?, status = ?, update_by = ?, update_time = ?, remark = ? \
+             WHERE id = ?",
+        )
+            .bind(&room_type.type_name)
+            .bind(room_type.price)
+            .bind(room_type.status)
+            .bind(&room_type.base.update_by)
+            .bind(room_type.base.update_time)
+            .bind(&room_type.base.remark)
+            .bind(room_type.id.map(|id| id.to_string()))
+            .execute(require_database_pool()?)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// 删除未被房间使用的房间类型，已被使用时不删除
+    pub async fn delete_unused_room_type_by_id(conn: &mut MySqlConnection, room_type_id: &Uuid) -> Result<u64> {
+        let result = sqlx::query(
+            "DELETE FROM room_type WHERE id = ? AND NOT EXISTS (SELECT 1 FROM room WHERE room_type_id = ?)",
+        )
+            .bind(room_type_id.to_string())
+            .bind(room_type_id.to_string())
+            .execute(conn)
+            .await?;
+        Ok(result.rows_affected())
+    }
+}
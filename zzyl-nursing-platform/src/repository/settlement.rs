@@ -0,0 +1,175 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::{Decimal, RoundingStrategy};
+use sqlx::{mysql::MySqlRow, MySqlConnection, Row};
+use uuid::Uuid;
+use zzyl_common::error::Result;
+use zzyl_framework::database::{require_database_pool, DatabaseUtils};
+use crate::domain::{CheckOutSettlement, SettlementItem};
+
+/// 退住结算单表查询列
+pub const SETTLEMENT_COLUMNS: &str = "s.id, s.elder_id, s.check_in_id, s.contract_id, s.check_in_time, \
+    s.check_out_time, s.days, s.room_fee, s.nursing_fee, s.project_fee, s.total_fee, s.deposit, s.balance, \
+    s.create_by, s.create_time, s.update_by, s.update_time, s.remark";
+
+/// 退住结算明细表查询列
+pub const SETTLEMENT_ITEM_COLUMNS: &str = "si.id, si.settlement_id, si.item_name, si.unit, si.price, si.quantity, \
+    si.amount";
+
+/// 退住结算数据访问
+pub struct SettlementRepository;
+
+impl SettlementRepository {
+    /// 将查询结果映射为结算单实体
+    pub fn map_row(row: &MySqlRow) -> Result<CheckOutSettlement> {
+        Ok(CheckOutSettlement {
+            id: DatabaseUtils::get_uuid(row, "id")?,
+            elder_id: DatabaseUtils::get_uuid(row, "elder_id")?,
+            check_in_id: DatabaseUtils::get_uuid(row, "check_in_id")?,
+            contract_id: DatabaseUtils::get_uuid(row, "contract_id")?,
+            check_in_time: row.try_get("check_in_time")?,
+            check_out_time: row.try_get("check_out_time")?,
+            days: row.try_get("days")?,
+            room_fee: row.try_get("room_fee")?,
+            nursing_fee: row.try_get("nursing_fee")?,
+            project_fee: row.try_get("project_fee")?,
+            total_fee: row.try_get("total_fee")?,
+            deposit: row.try_get("deposit")?,
+            balance: row.try_get("balance")?,
+            base: DatabaseUtils::get_base_entity(row)?,
+        })
+    }
+
+    /// 将查询结果映射为结算明细实体
+    pub fn map_item_row(row: &MySqlRow) -> Result<SettlementItem> {
+        Ok(SettlementItem {
+            id: DatabaseUtils::get_uuid(row, "id")?,
+            settlement_id: DatabaseUtils::get_uuid(row, "settlement_id")?,
+            item_name: row.try_get("item_name")?,
+            unit: row.try_get("unit")?,
+            price: row.try_get("price")?,
+            quantity: row.try_get("quantity")?,
+            amount: row.try_get("amount")?,
+        })
+    }
+
+    /// 通过ID查询结算单
+    pub async fn select_settlement_by_id(settlement_id: &Uuid) -> Result<Option<CheckOutSettlement>> {
+        let sql = format!("SELECT {} FROM check_out_settlement s WHERE s.id = ?", SETTLEMENT_COLUMNS);
+        let row = sqlx::query(&sql)
+            .bind(settlement_id.to_string())
+            .fetch_optional(require_database_pool()?)
+            .await?;
+        row.as_ref().map(Self::map_row).transpose()
+    }
+
+    /// 查询老人的结算单，按退住时间倒序
+    pub async fn select_settlements_by_elder_id(elder_id: &Uuid) -> Result<Vec<CheckOutSettlement>> {
+        let sql = format!(
+            "SELECT {} FROM check_out_settlement s WHERE s.elder_id = ? ORDER BY s.check_out_time DESC",
+            SETTLEMENT_COLUMNS
+        );
+        let rows = sqlx::query(&sql)
+            .bind(elder_id.to_string())
+            .fetch_all(require_database_pool()?)
+            .await?;
+        rows.iter().map(Self::map_row).collect()
+    }
+
+    /// 查询结算单明细
+    pub async fn select_items_by_settlement_id(settlement_id: &Uuid) -> Result<Vec<SettlementItem>> {
+        let sql = format!(
+            "SELECT {} FROM check_out_settlement_item si WHERE si.settlement_id = ? ORDER BY si.sort_no",
+            SETTLEMENT_ITEM_COLUMNS
+        );
+        let rows = sqlx::query(&sql)
+            .bind(settlement_id.to_string())
+            .fetch_all(require_database_pool()?)
+            .await?;
+        rows.iter().map(Self::map_item_row).collect()
+    }
+
+    /// 汇总老人在指定时间段内执行的护理项目，按项目生成费用明细
+    pub async fn select_project_usage(
+        elder_id: &Uuid,
+        begin_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    ) -> Result<Vec<SettlementItem>> {
+        let rows = sqlx::query(
+            "SELECT p.name, p.unit, p.price, SUM(nr.quantity) AS quantity FROM nursing_record nr \
+             INNER JOIN nursing_project p ON p.id = nr.project_id \
+             WHERE nr.elder_id = ? AND nr.execute_time >= ? AND nr.execute_time <= ? \
+             GROUP BY p.id, p.name, p.unit, p.price ORDER BY p.order_no",
+        )
+            .bind(elder_id.to_string())
+            .bind(begin_time)
+            .bind(end_time)
+            .fetch_all(require_database_pool()?)
+            .await?;
+        rows.iter()
+            .map(|row| {
+                let price = row.try_get::<Option<Decimal>, _>("price")?.unwrap_or_default();
+                let quantity = row.try_get::<Option<Decimal>, _>("quantity")?.unwrap_or_default();
+                Ok(SettlementItem {
+                    id: None,
+                    settlement_id: None,
+                    item_name: row.try_get("name")?,
+                    unit: row.try_get("unit")?,
+                    price,
+                    quantity,
+                    amount: (price * quantity).round_dp_with_strategy(2, RoundingStrategy::MidpointAwayFromZero),
+                })
+            })
+            .collect()
+    }
+
+    /// 新增结算单
+    pub async fn insert_settlement(conn: &mut MySqlConnection, settlement: &CheckOutSettlement) -> Result<u64> {
+        let result = sqlx::query(
+            "INSERT INTO check_out_settlement (id, elder_id, check_in_id, contract_id, check_in_time, check_out_time, \
+             days, room_fee, nursing_fee, project_fee, total_fee, deposit, balance, create_by, create_time, remark) \
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+            .bind(settlement.id.map(|id| id.to_string()))
+            .bind(settlement.elder_id.map(|id| id.to_string()))
+            .bind(settlement.check_in_id.map(|id| id.to_string()))
+            .bind(settlement.contract_id.map(|id| id.to_string()))
+            .bind(settlement.check_in_time)
+            .bind(settlement.check_out_time)
+            .bind(settlement.days)
+            .bind(settlement.room_fee)
+            .bind(settlement.nursing_fee)
+            .bind(settlement.project_fee)
+            .bind(settlement.total_fee)
+            .bind(settlement.deposit)
+            .bind(settlement.balance)
+            .bind(&settlement.base.create_by)
+            .bind(settlement.base.create_time)
+            .bind(&settlement.base.remark)
+            .execute(conn)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// 新增结算明细，按传入顺序记录排序号
+    pub async fn insert_items(conn: &mut MySqlConnection, items: &[SettlementItem]) -> Result<u64> {
+        let mut affected = 0;
+        for (sort_no, item) in items.iter().enumerate() {
+            let result = sqlx::query(
+                "INSERT INTO check_out_settlement_item (id, settlement_id, item_name, unit, price, quantity, amount, \
+                 sort_no) VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+            )
+                .bind(item.id.map(|id| id.to_string()))
+                .bind(item.settlement_id.map(|id| id.to_string()))
+                .bind(&item.item_name)
+                .bind(&item.unit)
+                .bind(item.price)
+                .bind(item.quantity)
+                .bind(item.amount)
+                .bind(sort_no as i32)
+                .execute(&mut *conn)
+                .await?;
+            affected += result.rows_affected();
+        }
+        Ok(affected)
+    }
+}
@@ -0,0 +1,21 @@
+mod bed;
+mod building;
+mod check_in;
+mod check_out;
+mod elder;
+mod elder_status;
+mod floor;
+mod nursing_record;
+mod room;
+mod room_type;
+
+pub use bed::*;
+pub use building::*;
+pub use check_in::*;
+pub use check_out::*;
+pub use elder::*;
+pub use elder_status::*;
+pub use floor::*;
+pub use nursing_record::*;
+pub use room::*;
+pub use room_type::*;
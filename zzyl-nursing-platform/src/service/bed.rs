@@ -0,0 +1,111 @@
+use chrono::Utc;
+use uuid::Uuid;
+use zzyl_common::error::{Result, ZzylError};
+use zzyl_common::{BaseEntity, LoginUser};
+use zzyl_framework::database::require_database_pool;
+use crate::domain::{Bed, BedRequest, BedSearchCondition};
+use crate::repository::{BedRepository, CheckInApplyRepository};
+use crate::service::RoomService;
+
+/// 床位管理服务
+pub struct BedService;
+
+impl BedService {
+    /// 根据条件查询床位
+    pub async fn select_bed_list(condition: &BedSearchCondition) -> Result<Vec<Bed>> {
+        BedRepository::select_bed_list(condition).await
+    }
+
+    /// 查询床位
+    pub async fn select_bed_by_id(bed_id: &Uuid) -> Result<Bed> {
+        BedRepository::select_bed_by_id(bed_id)
+            .await?
+            .ok_or_else(|| ZzylError::NotFound("床位不存在".to_string()))
+    }
+
+    /// 新增床位，初始为空闲状态
+    pub async fn insert_bed(login_user: &LoginUser, request: &BedRequest) -> Result<Uuid> {
+        let bed_no = request.bed_no.trim();
+        Self::check_bed(request, bed_no, None).await?;
+
+        let bed_id = Uuid::new_v4();
+        let bed = Bed {
+            id: Some(bed_id),
+            bed_no: bed_no.to_string(),
+            room_id: Some(request.room_id),
+            status: 0,
+            base: BaseEntity {
+                create_by: Some(login_user.username.clone()),
+                create_time: Some(Utc::now()),
+                update_by: None,
+                update_time: None,
+                remark: request.remark.clone(),
+            },
+        };
+        BedRepository::insert_bed(&bed).await?;
+        Ok(bed_id)
+    }
+
+    /// 修改床位，已入住的床位不能调整房间
+    pub async fn update_bed(login_user: &LoginUser, request: &BedRequest) -> Result<()> {
+        let bed_id = request.id
+            .ok_or_else(|| ZzylError::InvalidParameter("床位ID不能为空".to_string()))?;
+        let existing = Self::select_bed_by_id(&bed_id).await?;
+        if existing.status != 0 && existing.room_id != Some(request.room_id) {
+            return Err(ZzylError::Business(format!("床位【{}】已入住，不能调整房间", existing.bed_no)));
+        }
+        let bed_no = request.bed_no.trim();
+        Self::check_bed(request, bed_no, Some(&bed_id)).await?;
+
+        let bed = Bed {
+            bed_no: bed_no.to_string(),
+            room_id: Some(request.room_id),
+            base: BaseEntity {
+                update_by: Some(login_user.username.clone()),
+                update_time: Some(Utc::now()),
+                remark: request.remark.clone(),
+                ..existing.base
+            },
+            ..existing
+        };
+        BedRepository::update_bed(&bed).await?;
+        Ok(())
+    }
+
+    /// 批量删除床位，已入住或已被入住申请选定的床位不允许删除
+    pub async fn delete_bed_by_ids(bed_ids: &[Uuid]) -> Result<()> {
+        if bed_ids.is_empty() {
+            return Err(ZzylError::InvalidParameter("请选择要删除的床位".to_string()));
+        }
+        for bed_id in bed_ids {
+            let bed = Self::select_bed_by_id(bed_id).await?;
+            if bed.status != 0 {
+                return Err(ZzylError::Business(format!("床位【{}】已入住，不能删除", bed.bed_no)));
+            }
+            if CheckInApplyRepository::count_pending_by_bed_id(bed_id).await? > 0 {
+                return Err(ZzylError::Business(format!("床位【{}】已被入住申请选定，不能删除", bed.bed_no)));
+            }
+        }
+        let mut tx = require_database_pool()?.begin().await?;
+        for bed_id in bed_ids {
+            if BedRepository::delete_free_bed_by_id(&mut tx, bed_id).await? == 0 {
+                return Err(ZzylError::Business("床位已被占用，请刷新后重试".to_string()));
+            }
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// 校验床位编号在房间内唯一
+    async fn check_bed(request: &BedRequest, bed_no: &str, bed_id: Option<&Uuid>) -> Result<()> {
+        if bed_no.is_empty() {
+            return Err(ZzylError::InvalidParameter("床位编号不能为空".to_string()));
+        }
+        RoomService::select_room_by_id(&request.room_id).await?;
+        let found = BedRepository::select_bed_id_by_no(&request.room_id, bed_no).await?;
+        if found.is_some_and(|found| Some(&found) != bed_id) {
+            return Err(ZzylError::AlreadyExists(format!("床位编号'{}'在该房间已存在", bed_no)));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,105 @@
+use std::collections::HashMap;
+use uuid::Uuid;
+use zzyl_common::error::Result;
+use crate::domain::{
+    Bed, BedOccupancyDto, BedSearchCondition, BuildingOverviewDto, FloorOccupancyDto, OccupancyStats,
+    RoomOccupancyDto, RoomTypeOccupancyDto,
+};
+use crate::repository::{BedRepository, ElderRepository, FloorRepository, RoomRepository, RoomTypeRepository};
+
+/// 楼宇总览服务
+pub struct BuildingService;
+
+impl BuildingService {
+    /// 查询楼宇总览：各楼层的房间、床位与在住老人，以及按楼层、房间类型和全院的入住统计
+    pub async fn select_overview() -> Result<BuildingOverviewDto> {
+        let floors = FloorRepository::select_floor_all().await?;
+        let rooms = RoomRepository::select_room_all().await?;
+        let room_types = RoomTypeRepository::select_room_type_all().await?;
+        let condition = BedSearchCondition { bed_no: None, room_id: None, status: None };
+        let beds = BedRepository::select_bed_list(&condition).await?;
+        let mut elders = ElderRepository::select_checked_in_elders(None)
+            .await?
+            .into_iter()
+            .collect::<HashMap<_, _>>();
+
+        let mut beds_by_room: HashMap<Uuid, Vec<Bed>> = HashMap::new();
+        for bed in &beds {
+            if let Some(room_id) = bed.room_id {
+                beds_by_room.entry(room_id).or_default().push(bed.clone());
+            }
+        }
+        let room_type_by_id = room_types
+            .iter()
+            .filter_map(|room_type| room_type.id.map(|id| (id, room_type)))
+            .collect::<HashMap<_, _>>();
+
+        let room_type_stats = room_types
+            .iter()
+            .map(|room_type| {
+                let type_beds = rooms
+                    .iter()
+                    .filter(|room| room.room_type_id.is_some() && room.room_type_id == room_type.id)
+                    .filter_map(|room| room.id.and_then(|room_id| beds_by_room.get(&room_id)))
+                    .flatten()
+                    .cloned()
+                    .collect::<Vec<_>>();
+                RoomTypeOccupancyDto {
+                    room_type: room_type.clone(),
+                    occupancy_stats: Self::stats(&type_beds),
+                }
+            })
+            .collect();
+
+        let floors = floors
+            .into_iter()
+            .map(|floor| {
+                let floor_rooms = rooms
+                    .iter()
+                    .filter(|room| room.floor_id.is_some() && room.floor_id == floor.id)
+                    .map(|room| {
+                        let room_beds = room.id.and_then(|room_id| beds_by_room.remove(&room_id)).unwrap_or_default();
+                        let occupancy_stats = Self::stats(&room_beds);
+                        let beds = room_beds
+                            .into_iter()
+                            .map(|bed| {
+                                let elder = bed.id.and_then(|bed_id| elders.remove(&bed_id));
+                                BedOccupancyDto { bed, elder }
+                            })
+                            .collect();
+                        RoomOccupancyDto {
+                            room: room.clone(),
+                            room_type: room.room_type_id
+                                .and_then(|room_type_id| room_type_by_id.get(&room_type_id))
+                                .map(|room_type| (*room_type).clone()),
+                            beds,
+                            occupancy_stats,
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                let occupancy_stats = Self::sum(floor_rooms.iter().map(|room| &room.occupancy_stats));
+                FloorOccupancyDto { floor, rooms: floor_rooms, occupancy_stats }
+            })
+            .collect();
+
+        Ok(BuildingOverviewDto {
+            floors,
+            room_types: room_type_stats,
+            occupancy_stats: Self::stats(&beds),
+        })
+    }
+
+    /// 按床位状态计算入住统计
+    pub fn stats(beds: &[Bed]) -> OccupancyStats {
+        let occupied = beds.iter().filter(|bed| bed.status != 0).count();
+        OccupancyStats::new(beds.len() as i32, occupied as i32)
+    }
+
+    /// 汇总多组入住统计
+    fn sum<'a>(stats: impl Iterator<Item = &'a OccupancyStats>) -> OccupancyStats {
+        let (total, occupied) = stats.fold((0, 0), |(total, occupied), stats| {
+            (total + stats.total_beds, occupied + stats.occupied_beds)
+        });
+        OccupancyStats::new(total, occupied)
+    }
+}
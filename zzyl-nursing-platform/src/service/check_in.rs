@@ -0,0 +1,290 @@
+use chrono::Utc;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+use zzyl_common::enums::{CheckInStage, ElderStatus};
+use zzyl_common::error::{Result, ZzylError};
+use zzyl_common::{BaseEntity, LoginUser, PageDomain, PageResult};
+use zzyl_framework::database::{require_database_manager, require_database_pool};
+use crate::domain::{
+    Bed, CheckIn, CheckInApply, CheckInApplyRequest, CheckInApplySearchCondition, CheckInAssessRequest,
+    CheckInBedRequest, CheckInContractRequest, Contract,
+};
+use crate::repository::{
+    BedRepository, CheckInApplyRepository, CheckInRepository, ContractRepository, NursingLevelRepository,
+    RoomRepository,
+};
+use crate::service::{ElderService, ElderStatusService};
+
+/// 入住办理服务
+pub struct CheckInService;
+
+impl CheckInService {
+    /// 根据条件分页查询入住申请
+    pub async fn select_apply_list(
+        condition: &CheckInApplySearchCondition,
+        page: &PageDomain,
+    ) -> Result<PageResult<CheckInApply>> {
+        let (list, total) = CheckInApplyRepository::select_apply_list(condition, page).await?;
+        Ok(PageResult::new(page.page_num(), page.page_size(), total, list))
+    }
+
+    /// 查询入住申请
+    pub async fn select_apply_by_id(apply_id: &Uuid) -> Result<CheckInApply> {
+        CheckInApplyRepository::select_apply_by_id(apply_id)
+            .await?
+            .ok_or_else(|| ZzylError::NotFound("入住申请不存在".to_string()))
+    }
+
+    /// 提交入住申请，同一老人只能有一条办理中的申请
+    pub async fn apply(login_user: &LoginUser, request: &CheckInApplyRequest) -> Result<Uuid> {
+        let elder = ElderService::select_elder_by_id(&request.elder_id).await?;
+        if !elder.status.can_transition_to(ElderStatus::CheckedIn) {
+            return Err(ZzylError::Business(format!(
+                "老人【{}】当前状态为【{}】，不能申请入住",
+                elder.name,
+                elder.status.label()
+            )));
+        }
+        let apply_id = Uuid::new_v4();
+        let apply = CheckInApply {
+            id: Some(apply_id),
+            elder_id: Some(request.elder_id),
+            stage: CheckInStage::Applied,
+            assessment: None,
+            nursing_level_id: None,
+            bed_id: None,
+            contract_no: None,
+            start_time: None,
+            end_time: None,
+            amount: None,
+            deposit: None,
+            check_in_id: None,
+            base: BaseEntity {
+                create_by: Some(login_user.username.clone()),
+                create_time: Some(Utc::now()),
+                update_by: None,
+                update_time: None,
+                remark: request.remark.clone(),
+            },
+        };
+        if CheckInApplyRepository::insert_apply(&apply).await? == 0 {
+            return Err(ZzylError::AlreadyExists(format!("老人【{}】已有办理中的入住申请", elder.name)));
+        }
+        Ok(apply_id)
+    }
+
+    /// 入住评估，确定老人的护理等级
+    pub async fn assess(login_user: &LoginUser, apply_id: &Uuid, request: &CheckInAssessRequest) -> Result<()> {
+        let apply = Self::select_apply_by_id(apply_id).await?;
+        Self::check_stage(&apply, &[CheckInStage::Applied], "进行评估")?;
+        let assessment = request.assessment.trim();
+        if assessment.is_empty() {
+            return Err(ZzylError::InvalidParameter("评估结论不能为空".to_string()));
+        }
+        let level = NursingLevelRepository::select_level_by_id(&request.nursing_level_id)
+            .await?
+            .ok_or_else(|| ZzylError::NotFound("护理等级不存在".to_string()))?;
+        if level.status != 1 {
+            return Err(ZzylError::Business(format!("护理等级【{}】已禁用", level.name)));
+        }
+
+        let from = apply.stage;
+        let apply = CheckInApply {
+            stage: CheckInStage::Assessed,
+            assessment: Some(assessment.to_string()),
+            nursing_level_id: level.id,
+            ..apply
+        };
+        Self::save_stage(login_user, apply, from).await
+    }
+
+    /// 选择床位，签约后仍可因床位被占用而重新选择
+    pub async fn select_bed(login_user: &LoginUser, apply_id: &Uuid, request: &CheckInBedRequest) -> Result<()> {
+        let apply = Self::select_apply_by_id(apply_id).await?;
+        Self::check_stage(
+            &apply,
+            &[CheckInStage::Assessed, CheckInStage::BedSelected, CheckInStage::ContractSigned],
+            "选择床位",
+        )?;
+        let bed = Self::select_free_bed(&request.bed_id).await?;
+
+        let from = apply.stage;
+        let apply = CheckInApply {
+            stage: if from == CheckInStage::Assessed { CheckInStage::BedSelected } else { from },
+            bed_id: bed.id,
+            ..apply
+        };
+        Self::save_stage(login_user, apply, from).await
+    }
+
+    /// 签订合同
+    pub async fn sign_contract(
+        login_user: &LoginUser,
+        apply_id: &Uuid,
+        request: &CheckInContractRequest,
+    ) -> Result<()> {
+        let apply = Self::select_apply_by_id(apply_id).await?;
+        Self::check_stage(&apply, &[CheckInStage::BedSelected], "签订合同")?;
+        if request.end_time <= request.start_time {
+            return Err(ZzylError::InvalidParameter("合同结束时间须晚于开始时间".to_string()));
+        }
+        if request.amount < Decimal::ZERO {
+            return Err(ZzylError::InvalidParameter("合同金额不能为负数".to_string()));
+        }
+        let deposit = request.deposit.unwrap_or_default();
+        if deposit < Decimal::ZERO {
+            return Err(ZzylError::InvalidParameter("押金不能为负数".to_string()));
+        }
+        let contract_no = match request.contract_no.as_deref().map(str::trim).filter(|no| !no.is_empty()) {
+            Some(contract_no) => contract_no.to_string(),
+            None => format!("HT{}", Utc::now().format("%Y%m%d%H%M%S%3f")),
+        };
+        if ContractRepository::select_contract_id_by_no(&contract_no).await?.is_some() {
+            return Err(ZzylError::AlreadyExists(format!("合同编号'{}'已存在", contract_no)));
+        }
+
+        let from = apply.stage;
+        let apply = CheckInApply {
+            stage: CheckInStage::ContractSigned,
+            contract_no: Some(contract_no),
+            start_time: Some(request.start_time),
+            end_time: Some(request.end_time),
+            amount: Some(request.amount),
+            deposit: Some(deposit),
+            ..apply
+        };
+        Self::save_stage(login_user, apply, from).await
+    }
+
+    /// 办理入住：在同一事务中占用床位、生成入住记录与合同并将老人置为入住中
+    pub async fn admit(login_user: &LoginUser, apply_id: &Uuid) -> Result<Uuid> {
+        let apply = Self::select_apply_by_id(apply_id).await?;
+        Self::check_stage(&apply, &[CheckInStage::ContractSigned], "办理入住")?;
+        let elder_id = apply.elder_id
+            .ok_or_else(|| ZzylError::InvalidParameter("老人ID不能为空".to_string()))?;
+        let bed_id = apply.bed_id
+            .ok_or_else(|| ZzylError::InvalidParameter("入住申请未选择床位".to_string()))?;
+        let elder = ElderService::select_elder_by_id(&elder_id).await?;
+        let bed = Self::select_free_bed(&bed_id).await?;
+
+        let now = Utc::now();
+        let created = BaseEntity {
+            create_by: Some(login_user.username.clone()),
+            create_time: Some(now),
+            ..BaseEntity::default()
+        };
+        let check_in_id = Uuid::new_v4();
+        let check_in = CheckIn {
+            id: Some(check_in_id),
+            elder_id: Some(elder_id),
+            bed_id: Some(bed_id),
+            nursing_level_id: apply.nursing_level_id,
+            check_in_time: Some(now),
+            check_out_time: None,
+            status: 0,
+            base: created.clone(),
+        };
+        let contract = Contract {
+            id: Some(Uuid::new_v4()),
+            contract_no: apply.contract_no.clone().unwrap_or_default(),
+            elder_id: Some(elder_id),
+            start_time: apply.start_time,
+            end_time: apply.end_time,
+            amount: apply.amount,
+            deposit: apply.deposit,
+            status: 0,
+            base: created,
+        };
+        let from = apply.stage;
+        let apply = CheckInApply {
+            stage: CheckInStage::Admitted,
+            check_in_id: Some(check_in_id),
+            base: BaseEntity {
+                update_by: Some(login_user.username.clone()),
+                update_time: Some(now),
+                ..apply.base
+            },
+            ..apply
+        };
+
+        let login_user = login_user.clone();
+        require_database_manager()?
+            .transaction(move |tx| {
+                Box::pin(async move {
+                    if BedRepository::occupy_bed(&mut **tx, &bed_id, &login_user.username).await? == 0 {
+                        return Err(ZzylError::Business(format!("床位【{}】已被占用，请重新选择床位", bed.bed_no)));
+                    }
+                    if let Some(room_id) = bed.room_id {
+                        RoomRepository::refresh_room_status(&mut **tx, &room_id).await?;
+                    }
+                    CheckInRepository::insert_check_in(&mut **tx, &check_in).await?;
+                    ContractRepository::insert_contract(&mut **tx, &contract).await?;
+                    ElderStatusService::transition(&mut **tx, &login_user, &elder, ElderStatus::CheckedIn, Some("办理入住"))
+                        .await?;
+                    if CheckInApplyRepository::update_apply(&mut **tx, &apply, from).await? == 0 {
+                        return Err(ZzylError::Business("入住申请已变更，请刷新后重试".to_string()));
+                    }
+                    Ok(())
+                })
+            })
+            .await?;
+        Ok(check_in_id)
+    }
+
+    /// 取消入住申请，已入住或已取消的申请不能取消
+    pub async fn cancel(login_user: &LoginUser, apply_id: &Uuid) -> Result<()> {
+        let apply = Self::select_apply_by_id(apply_id).await?;
+        Self::check_stage(
+            &apply,
+            &[CheckInStage::Applied, CheckInStage::Assessed, CheckInStage::BedSelected, CheckInStage::ContractSigned],
+            "取消",
+        )?;
+
+        let from = apply.stage;
+        let apply = CheckInApply {
+            stage: CheckInStage::Cancelled,
+            ..apply
+        };
+        Self::save_stage(login_user, apply, from).await
+    }
+
+    /// 校验申请所处阶段
+    fn check_stage(apply: &CheckInApply, allowed: &[CheckInStage], action: &str) -> Result<()> {
+        if !allowed.contains(&apply.stage) {
+            return Err(ZzylError::Business(format!(
+                "入住申请当前为【{}】阶段，不能{}",
+                apply.stage.label(),
+                action
+            )));
+        }
+        Ok(())
+    }
+
+    /// 查询空闲床位
+    async fn select_free_bed(bed_id: &Uuid) -> Result<Bed> {
+        let bed = BedRepository::select_bed_by_id(bed_id)
+            .await?
+            .ok_or_else(|| ZzylError::NotFound("床位不存在".to_string()))?;
+        if bed.status != 0 {
+            return Err(ZzylError::Business(format!("床位【{}】已被占用，请重新选择床位", bed.bed_no)));
+        }
+        Ok(bed)
+    }
+
+    /// 保存申请阶段变更，阶段已被并发修改时报错
+    async fn save_stage(login_user: &LoginUser, apply: CheckInApply, from: CheckInStage) -> Result<()> {
+        let apply = CheckInApply {
+            base: BaseEntity {
+                update_by: Some(login_user.username.clone()),
+                update_time: Some(Utc::now()),
+                ..apply.base
+            },
+            ..apply
+        };
+        let mut conn = require_database_pool()?.acquire().await?;
+        if CheckInApplyRepository::update_apply(&mut conn, &apply, from).await? == 0 {
+            return Err(ZzylError::Business("入住申请已变更，请刷新后重试".to_string()));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,444 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::{Decimal, RoundingStrategy};
+use uuid::Uuid;
+use zzyl_common::enums::ElderStatus;
+use zzyl_common::error::{Result, ZzylError};
+use zzyl_common::utils::DateUtils;
+use zzyl_common::{BaseEntity, LoginUser};
+use zzyl_framework::database::{require_database_manager, require_database_pool};
+use crate::domain::{
+    Bed, CheckIn, CheckOutApplyRequest, CheckOutCancelRequest, CheckOutRequest, CheckOutSettlement, Contract, Elder,
+    Floor, NursingLevel, Room, SettlementItem, SettlementSummaryDto,
+};
+use crate::repository::{
+    BedRepository, CheckInRepository, ContractRepository, FloorRepository, NursingLevelRepository, RoomRepository,
+    RoomTypeRepository, SettlementRepository,
+};
+use crate::service::{ElderService, ElderStatusService};
+
+/// 床位费、护理费按月计价，折算日价的天数
+const DAYS_PER_MONTH: i64 = 30;
+
+/// 退住结算草稿
+struct SettlementDraft {
+    check_in: CheckIn,
+    bed: Option<Bed>,
+    contract: Option<Contract>,
+    summary: SettlementSummaryDto,
+}
+
+/// 退住办理服务
+pub struct CheckOutService;
+
+impl CheckOutService {
+    /// 申请退住，老人由入住中变为退住中
+    pub async fn apply(login_user: &LoginUser, request: &CheckOutApplyRequest) -> Result<()> {
+        let elder = ElderService::select_elder_by_id(&request.elder_id).await?;
+        let reason = request.reason.as_deref().unwrap_or("申请退住");
+        let mut tx = require_database_pool()?.begin().await?;
+        ElderStatusService::transition(&mut tx, login_user, &elder, ElderStatus::Leaving, Some(reason)).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// 撤销退住申请，老人由退住中恢复为入住中
+    pub async fn cancel(login_user: &LoginUser, request: &CheckOutCancelRequest) -> Result<()> {
+        let elder = ElderService::select_elder_by_id(&request.elder_id).await?;
+        if elder.status != ElderStatus::Leaving {
+            return Err(ZzylError::Business(format!(
+                "老人【{}】当前状态为【{}】，没有可撤销的退住申请",
+                elder.name,
+                elder.status.label()
+            )));
+        }
+        let reason = request.reason.as_deref().unwrap_or("撤销退住申请");
+        let mut tx = require_database_pool()?.begin().await?;
+        ElderStatusService::transition(&mut tx, login_user, &elder, ElderStatus::CheckedIn, Some(reason)).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// 预览退住结算单，不保存
+    pub async fn preview(request: &CheckOutRequest) -> Result<SettlementSummaryDto> {
+        let elder = ElderService::select_elder_by_id(&request.elder_id).await?;
+        let check_out_time = request.check_out_time.unwrap_or_else(Utc::now);
+        Ok(Self::build_settlement(&elder, check_out_time).await?.summary)
+    }
+
+    /// 办理退住：在同一事务中结算费用、终止合同、释放床位并将老人置为已退住
+    ///
+    /// 返回结算单ID
+    pub async fn check_out(login_user: &LoginUser, request: &CheckOutRequest) -> Result<Uuid> {
+        let elder = ElderService::select_elder_by_id(&request.elder_id).await?;
+        if elder.status != ElderStatus::Leaving {
+            return Err(ZzylError::Business(format!(
+                "老人【{}】当前状态为【{}】，请先申请退住",
+                elder.name,
+                elder.status.label()
+            )));
+        }
+        let now = Utc::now();
+        let check_out_time = request.check_out_time.unwrap_or(now);
+        if check_out_time > now {
+            return Err(ZzylError::InvalidParameter("退住时间不能晚于当前时间".to_string()));
+        }
+        let SettlementDraft { check_in, bed, contract, summary } =
+            Self::build_settlement(&elder, check_out_time).await?;
+
+        let settlement_id = Uuid::new_v4();
+        let settlement = CheckOutSettlement {
+            id: Some(settlement_id),
+            base: BaseEntity {
+                create_by: Some(login_user.username.clone()),
+                create_time: Some(now),
+                remark: request.remark.clone(),
+                ..BaseEntity::default()
+            },
+            ..summary.settlement
+        };
+        let items = summary.items
+            .into_iter()
+            .map(|item| SettlementItem { id: Some(Uuid::new_v4()), settlement_id: Some(settlement_id), ..item })
+            .collect::<Vec<_>>();
+        let check_in_id = check_in.id
+            .ok_or_else(|| ZzylError::InvalidParameter("入住记录ID不能为空".to_string()))?;
+
+        let login_user = login_user.clone();
+        require_database_manager()?
+            .transaction(move |tx| {
+                Box::pin(async move {
+                    let username = login_user.username.as_str();
+                    if CheckInRepository::check_out(&mut **tx, &check_in_id, check_out_time, username).await? == 0 {
+                        return Err(ZzylError::Business("入住记录已退住，请刷新后重试".to_string()));
+                    }
+                    if let Some(contract_id) = contract.as_ref().and_then(|contract| contract.id) {
+                        ContractRepository::terminate_contract(&mut **tx, &contract_id, username).await?;
+                    }
+                    if let Some(bed) = bed.as_ref() {
+                        if let Some(bed_id) = bed.id {
+                            BedRepository::release_bed(&mut **tx, &bed_id, username).await?;
+                        }
+                        if let Some(room_id) = bed.room_id {
+                            RoomRepository::refresh_room_status(&mut **tx, &room_id).await?;
+                        }
+                    }
+                    SettlementRepository::insert_settlement(&mut **tx, &settlement).await?;
+                    SettlementRepository::insert_items(&mut **tx, &items).await?;
+                    let target = ElderStatus::CheckedOut;
+                    ElderStatusService::transition(&mut **tx, &login_user, &elder, target, Some("退住结算")).await?;
+                    Ok(())
+                })
+            })
+            .await?;
+        Ok(settlement_id)
+    }
+
+    /// 查询老人的退住结算单
+    pub async fn select_settlements_by_elder_id(elder_id: &Uuid) -> Result<Vec<CheckOutSettlement>> {
+        ElderService::select_elder_by_id(elder_id).await?;
+        SettlementRepository::select_settlements_by_elder_id(elder_id).await
+    }
+
+    /// 查询结算单详情
+    pub async fn select_summary(settlement_id: &Uuid) -> Result<SettlementSummaryDto> {
+        let settlement = SettlementRepository::select_settlement_by_id(settlement_id)
+            .await?
+            .ok_or_else(|| ZzylError::NotFound("结算单不存在".to_string()))?;
+        let elder_id = settlement.elder_id
+            .ok_or_else(|| ZzylError::InvalidParameter("老人ID不能为空".to_string()))?;
+        let elder = ElderService::select_elder_by_id(&elder_id).await?;
+        let check_in = match settlement.check_in_id {
+            Some(check_in_id) => CheckInRepository::select_check_in_by_id(&check_in_id).await?,
+            None => None,
+        };
+        let (bed, room, floor) = Self::select_location(check_in.as_ref().and_then(|check_in| check_in.bed_id)).await?;
+        let level = match check_in.as_ref().and_then(|check_in| check_in.nursing_level_id) {
+            Some(level_id) => NursingLevelRepository::select_level_by_id(&level_id).await?,
+            None => None,
+        };
+        let contract = match settlement.contract_id {
+            Some(contract_id) => ContractRepository::select_contract_by_id(&contract_id).await?,
+            None => None,
+        };
+        let items = SettlementRepository::select_items_by_settlement_id(settlement_id).await?;
+        Ok(Self::summary(
+            settlement,
+            &elder,
+            bed.as_ref(),
+            room.as_ref(),
+            floor.as_ref(),
+            contract.as_ref(),
+            level.as_ref(),
+            items,
+        ))
+    }
+
+    /// 生成可打印的结算单表格
+    pub async fn export_summary(settlement_id: &Uuid) -> Result<Vec<Vec<String>>> {
+        let summary = Self::select_summary(settlement_id).await?;
+        let settlement = &summary.settlement;
+        let format_time = |time: Option<DateTime<Utc>>| {
+            time.map(|time| DateUtils::local_date(&time).format("%Y-%m-%d").to_string()).unwrap_or_default()
+        };
+        let location = [&summary.floor_name, &summary.room_no, &summary.bed_no]
+            .iter()
+            .filter_map(|value| value.as_deref())
+            .collect::<Vec<_>>()
+            .join(" / ");
+        let balance_label = Self::balance_label(settlement.balance);
+
+        let mut rows = vec![
+            vec!["退住结算单".to_string()],
+            vec![
+                "老人姓名".to_string(),
+                summary.elder_name.clone(),
+                "身份证号".to_string(),
+                summary.id_card_no.clone(),
+            ],
+            vec![
+                "床位".to_string(),
+                location,
+                "护理等级".to_string(),
+                summary.nursing_level_name.clone().unwrap_or_default(),
+            ],
+            vec![
+                "入住日期".to_string(),
+                format_time(settlement.check_in_time),
+                "退住日期".to_string(),
+                format_time(settlement.check_out_time),
+            ],
+            vec![
+                "合同编号".to_string(),
+                summary.contract_no.clone().unwrap_or_default(),
+                "计费天数".to_string(),
+                settlement.days.to_string(),
+            ],
+            Vec::new(),
+            ["费用项目", "单位", "单价", "数量", "金额"].iter().map(|header| header.to_string()).collect(),
+        ];
+        for item in &summary.items {
+            rows.push(vec![
+                item.item_name.clone(),
+                item.unit.clone().unwrap_or_default(),
+                item.price.to_string(),
+                item.quantity.to_string(),
+                item.amount.to_string(),
+            ]);
+        }
+        let totals = [
+            ("费用合计", settlement.total_fee),
+            ("押金", settlement.deposit),
+            (balance_label, settlement.balance.abs()),
+        ];
+        for (label, amount) in totals {
+            rows.push(vec![label.to_string(), String::new(), String::new(), String::new(), amount.to_string()]);
+        }
+        Ok(rows)
+    }
+
+    /// 按入住记录计算截至退住时间的费用，床位费与护理费按日折算
+    async fn build_settlement(elder: &Elder, check_out_time: DateTime<Utc>) -> Result<SettlementDraft> {
+        let elder_id = elder.id
+            .ok_or_else(|| ZzylError::InvalidParameter("老人ID不能为空".to_string()))?;
+        let check_in = CheckInRepository::select_active_by_elder_id(&elder_id)
+            .await?
+            .ok_or_else(|| ZzylError::Business(format!("老人【{}】没有在住记录", elder.name)))?;
+        let check_in_time = check_in.check_in_time.unwrap_or(check_out_time);
+        if check_out_time < check_in_time {
+            return Err(ZzylError::InvalidParameter("退住时间不能早于入住时间".to_string()));
+        }
+        let days = Self::billing_days(check_in_time, check_out_time);
+
+        let (bed, room, floor) = Self::select_location(check_in.bed_id).await?;
+        let room_type = match room.as_ref().and_then(|room| room.room_type_id) {
+            Some(room_type_id) => RoomTypeRepository::select_room_type_by_id(&room_type_id).await?,
+            None => None,
+        };
+        let level = match check_in.nursing_level_id {
+            Some(level_id) => NursingLevelRepository::select_level_by_id(&level_id).await?,
+            None => None,
+        };
+        let contract = ContractRepository::select_valid_by_elder_id(&elder_id).await?;
+
+        let room_item = room_type
+            .map(|room_type| Self::prorate(format!("床位费（{}）", room_type.type_name), room_type.price, days));
+        let nursing_item = level
+            .as_ref()
+            .map(|level| Self::prorate(format!("护理费（{}）", level.name), level.fee, days));
+        let usage = SettlementRepository::select_project_usage(&elder_id, check_in_time, check_out_time).await?;
+        let room_fee = room_item.as_ref().map_or(Decimal::ZERO, |item| item.amount);
+        let nursing_fee = nursing_item.as_ref().map_or(Decimal::ZERO, |item| item.amount);
+        let project_fee = usage.iter().map(|item| item.amount).sum::<Decimal>();
+        let items = room_item.into_iter().chain(nursing_item).chain(usage).collect();
+
+        let total_fee = room_fee + nursing_fee + project_fee;
+        let deposit = contract.as_ref().and_then(|contract| contract.deposit).unwrap_or_default();
+        let settlement = CheckOutSettlement {
+            id: None,
+            elder_id: Some(elder_id),
+            check_in_id: check_in.id,
+            contract_id: contract.as_ref().and_then(|contract| contract.id),
+            check_in_time: Some(check_in_time),
+            check_out_time: Some(check_out_time),
+            days: days as i32,
+            room_fee,
+            nursing_fee,
+            project_fee,
+            total_fee,
+            deposit,
+            balance: Self::balance(deposit, total_fee),
+            base: BaseEntity::default(),
+        };
+        let summary = Self::summary(
+            settlement,
+            elder,
+            bed.as_ref(),
+            room.as_ref(),
+            floor.as_ref(),
+            contract.as_ref(),
+            level.as_ref(),
+            items,
+        );
+        Ok(SettlementDraft { check_in, bed, contract, summary })
+    }
+
+    /// 按本地日期计算计费天数，入住当天即退住按1天计
+    fn billing_days(check_in_time: DateTime<Utc>, check_out_time: DateTime<Utc>) -> i64 {
+        (DateUtils::local_date(&check_out_time) - DateUtils::local_date(&check_in_time)).num_days().max(1)
+    }
+
+    /// 按日折算月度价格生成费用明细
+    fn prorate(item_name: String, monthly_price: Option<Decimal>, days: i64) -> SettlementItem {
+        let price = (monthly_price.unwrap_or_default() / Decimal::from(DAYS_PER_MONTH))
+            .round_dp_with_strategy(2, RoundingStrategy::MidpointAwayFromZero);
+        let quantity = Decimal::from(days);
+        SettlementItem {
+            id: None,
+            settlement_id: None,
+            item_name,
+            unit: Some("天".to_string()),
+            price,
+            quantity,
+            amount: price * quantity,
+        }
+    }
+
+    /// 结算余额，正数为应退押金，负数为应补缴费用
+    fn balance(deposit: Decimal, total_fee: Decimal) -> Decimal {
+        deposit - total_fee
+    }
+
+    /// 结算余额名称
+    fn balance_label(balance: Decimal) -> &'static str {
+        if balance >= Decimal::ZERO { "应退金额" } else { "应补缴金额" }
+    }
+
+    /// 查询床位及其所在房间、楼层
+    async fn select_location(bed_id: Option<Uuid>) -> Result<(Option<Bed>, Option<Room>, Option<Floor>)> {
+        let bed = match bed_id {
+            Some(bed_id) => BedRepository::select_bed_by_id(&bed_id).await?,
+            None => None,
+        };
+        let room = match bed.as_ref().and_then(|bed| bed.room_id) {
+            Some(room_id) => RoomRepository::select_room_by_id(&room_id).await?,
+            None => None,
+        };
+        let floor = match room.as_ref().and_then(|room| room.floor_id) {
+            Some(floor_id) => FloorRepository::select_floor_by_id(&floor_id).await?,
+            None => None,
+        };
+        Ok((bed, room, floor))
+    }
+
+    /// 组装结算单DTO
+    #[allow(clippy::too_many_arguments)]
+    fn summary(
+        settlement: CheckOutSettlement,
+        elder: &Elder,
+        bed: Option<&Bed>,
+        room: Option<&Room>,
+        floor: Option<&Floor>,
+        contract: Option<&Contract>,
+        level: Option<&NursingLevel>,
+        items: Vec<SettlementItem>,
+    ) -> SettlementSummaryDto {
+        SettlementSummaryDto {
+            settlement,
+            elder_name: elder.name.clone(),
+            id_card_no: elder.id_card_no.clone(),
+            floor_name: floor.map(|floor| floor.floor_name.clone()),
+            room_no: room.map(|room| room.room_no.clone()),
+            bed_no: bed.map(|bed| bed.bed_no.clone()),
+            contract_no: contract.map(|contract| contract.contract_no.clone()),
+            nursing_level_name: level.map(|level| level.name.clone()),
+            items,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn dec(value: &str) -> Decimal {
+        Decimal::from_str(value).unwrap()
+    }
+
+    #[test]
+    fn prorate_divides_monthly_price_by_thirty_days() {
+        let item = CheckOutService::prorate("床位费".to_string(), Some(dec("3000")), 31);
+        assert_eq!(item.price, dec("100.00"));
+        assert_eq!(item.quantity, dec("31"));
+        assert_eq!(item.amount, dec("3100.00"));
+        assert_eq!(item.unit.as_deref(), Some("天"));
+    }
+
+    #[test]
+    fn prorate_rounds_daily_price_half_up() {
+        // 1000 / 30 = 33.333… 向下舍入
+        let item = CheckOutService::prorate("护理费".to_string(), Some(dec("1000")), 7);
+        assert_eq!(item.price, dec("33.33"));
+        assert_eq!(item.amount, dec("233.31"));
+        // 2000 / 30 = 66.666… 向上进位
+        let item = CheckOutService::prorate("护理费".to_string(), Some(dec("2000")), 3);
+        assert_eq!(item.price, dec("66.67"));
+        assert_eq!(item.amount, dec("200.01"));
+        // 3.75 / 30 = 0.125 恰为中点，四舍五入而非银行家舍入
+        let item = CheckOutService::prorate("护理费".to_string(), Some(dec("3.75")), 2);
+        assert_eq!(item.price, dec("0.13"));
+        assert_eq!(item.amount, dec("0.26"));
+    }
+
+    #[test]
+    fn prorate_without_price_is_free() {
+        for monthly_price in [None, Some(Decimal::ZERO)] {
+            let item = CheckOutService::prorate("床位费".to_string(), monthly_price, 10);
+            assert_eq!(item.price, Decimal::ZERO);
+            assert_eq!(item.amount, Decimal::ZERO);
+            assert_eq!(item.quantity, dec("10"));
+        }
+    }
+
+    #[test]
+    fn balance_refunds_surplus_deposit() {
+        let balance = CheckOutService::balance(dec("5000"), dec("3100.50"));
+        assert_eq!(balance, dec("1899.50"));
+        assert_eq!(CheckOutService::balance_label(balance), "应退金额");
+    }
+
+    #[test]
+    fn balance_requires_supplementary_payment_when_fees_exceed_deposit() {
+        let balance = CheckOutService::balance(dec("1000"), dec("3100.50"));
+        assert_eq!(balance, dec("-2100.50"));
+        assert_eq!(CheckOutService::balance_label(balance), "应补缴金额");
+        assert_eq!(balance.abs(), dec("2100.50"));
+    }
+
+    #[test]
+    fn balance_settles_exactly_as_refund_of_zero() {
+        let balance = CheckOutService::balance(dec("3100.50"), dec("3100.50"));
+        assert_eq!(balance, Decimal::ZERO);
+        assert_eq!(CheckOutService::balance_label(balance), "应退金额");
+    }
+}
@@ -0,0 +1,166 @@
+use chrono::Utc;
+use uuid::Uuid;
+use zzyl_common::enums::ElderStatus;
+use zzyl_common::error::{Result, ZzylError};
+use zzyl_common::utils::{IdCardUtils, ValidationUtils};
+use zzyl_common::{BaseEntity, LoginUser, PageDomain, PageResult};
+use zzyl_framework::database::require_database_pool;
+use crate::domain::{Elder, ElderDetailDto, ElderRequest, ElderSearchCondition};
+use crate::repository::{
+    BedRepository, CheckInApplyRepository, ContractRepository, ElderRepository, FamilyMemberRepository,
+    FloorRepository, RoomRepository,
+};
+
+/// 老人档案服务
+pub struct ElderService;
+
+impl ElderService {
+    /// 根据条件分页查询老人
+    pub async fn select_elder_list(condition: &ElderSearchCondition, page: &PageDomain) -> Result<PageResult<Elder>> {
+        let (list, total) = ElderRepository::select_elder_list(condition, page).await?;
+        Ok(PageResult::new(page.page_num(), page.page_size(), total, list))
+    }
+
+    /// 查询老人
+    pub async fn select_elder_by_id(elder_id: &Uuid) -> Result<Elder> {
+        ElderRepository::select_elder_by_id(elder_id)
+            .await?
+            .ok_or_else(|| ZzylError::NotFound("老人不存在".to_string()))
+    }
+
+    /// 查询老人详情及床位、房间、楼层、家属与合同
+    pub async fn select_elder_detail(elder_id: &Uuid) -> Result<ElderDetailDto> {
+        let elder = Self::select_elder_by_id(elder_id).await?;
+        let bed = BedRepository::select_bed_by_elder_id(elder_id).await?;
+        let room = match bed.as_ref().and_then(|bed| bed.room_id) {
+            Some(room_id) => RoomRepository::select_room_by_id(&room_id).await?,
+            None => None,
+        };
+        let floor = match room.as_ref().and_then(|room| room.floor_id) {
+            Some(floor_id) => FloorRepository::select_floor_by_id(&floor_id).await?,
+            None => None,
+        };
+        Ok(ElderDetailDto {
+            elder,
+            bed,
+            room,
+            floor,
+            family_members: FamilyMemberRepository::select_by_elder_id(elder_id).await?,
+            contract: ContractRepository::select_valid_by_elder_id(elder_id).await?,
+        })
+    }
+
+    /// 新增老人，性别与出生日期以身份证号为准
+    pub async fn insert_elder(login_user: &LoginUser, request: &ElderRequest) -> Result<Uuid> {
+        let id_card_no = request.id_card_no.trim().to_ascii_uppercase();
+        let mut elder = Self::build_elder(request, &id_card_no)?;
+        Self::check_id_card_no_unique(&id_card_no, None).await?;
+
+        let elder_id = Uuid::new_v4();
+        elder.id = Some(elder_id);
+        elder.base = BaseEntity {
+            create_by: Some(login_user.username.clone()),
+            create_time: Some(Utc::now()),
+            update_by: None,
+            update_time: None,
+            remark: request.remark.clone(),
+        };
+        ElderRepository::insert_elder(&elder).await?;
+        Ok(elder_id)
+    }
+
+    /// 修改老人基本信息，状态由入住、退住等流程维护
+    pub async fn update_elder(login_user: &LoginUser, request: &ElderRequest) -> Result<()> {
+        let elder_id = request.id
+            .ok_or_else(|| ZzylError::InvalidParameter("老人ID不能为空".to_string()))?;
+        let existing = Self::select_elder_by_id(&elder_id).await?;
+        let id_card_no = request.id_card_no.trim().to_ascii_uppercase();
+        let elder = Self::build_elder(request, &id_card_no)?;
+        Self::check_id_card_no_unique(&id_card_no, Some(&elder_id)).await?;
+
+        let elder = Elder {
+            id: Some(elder_id),
+            status: existing.status,
+            base: BaseEntity {
+                update_by: Some(login_user.username.clone()),
+                update_time: Some(Utc::now()),
+                remark: request.remark.clone(),
+                ..existing.base
+            },
+            ..elder
+        };
+        ElderRepository::update_elder(&elder).await?;
+        Ok(())
+    }
+
+    /// 删除老人及其家属信息，在住老人不允许删除
+    pub async fn delete_elder_by_ids(elder_ids: &[Uuid]) -> Result<()> {
+        if elder_ids.is_empty() {
+            return Err(ZzylError::InvalidParameter("请选择要删除的老人".to_string()));
+        }
+        for elder_id in elder_ids {
+            let elder = Self::select_elder_by_id(elder_id).await?;
+            if matches!(elder.status, ElderStatus::CheckedIn | ElderStatus::Leave | ElderStatus::Leaving) {
+                return Err(ZzylError::Business(format!("老人【{}】尚未退住，不能删除", elder.name)));
+            }
+            if CheckInApplyRepository::select_pending_by_elder_id(elder_id).await?.is_some() {
+                return Err(ZzylError::Business(format!("老人【{}】有办理中的入住申请，请先取消申请", elder.name)));
+            }
+        }
+
+        let mut tx = require_database_pool()?.begin().await?;
+        for elder_id in elder_ids {
+            FamilyMemberRepository::delete_by_elder_id(&mut tx, elder_id).await?;
+            ElderRepository::delete_elder_by_id(&mut tx, elder_id).await?;
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// 校验请求并构建老人实体，性别与出生日期须与身份证号一致
+    fn build_elder(request: &ElderRequest, id_card_no: &str) -> Result<Elder> {
+        let name = request.name.trim();
+        if name.is_empty() {
+            return Err(ZzylError::InvalidParameter("老人姓名不能为空".to_string()));
+        }
+        let info = IdCardUtils::parse(id_card_no).map_err(|e| {
+            ZzylError::InvalidParameter(e.message.map(|message| message.to_string()).unwrap_or_else(|| e.code.to_string()))
+        })?;
+        if request.sex.is_some_and(|sex| sex != info.sex) {
+            return Err(ZzylError::InvalidParameter("性别与身份证号不一致".to_string()));
+        }
+        let birthday = info.birthday_time();
+        if request.birthday.is_some_and(|value| value != info.birthday) {
+            return Err(ZzylError::InvalidParameter("出生日期与身份证号不一致".to_string()));
+        }
+        let phone = request.phone.as_deref().map(str::trim).filter(|phone| !phone.is_empty());
+        if let Some(phone) = phone {
+            ValidationUtils::validate_phone(phone)
+                .map_err(|_| ZzylError::InvalidParameter(format!("手机号'{}'格式不正确", phone)))?;
+        }
+
+        Ok(Elder {
+            id: request.id,
+            name: name.to_string(),
+            image: request.image.clone(),
+            id_card_no: id_card_no.to_string(),
+            sex: info.sex,
+            status: ElderStatus::Enabled,
+            phone: phone.map(str::to_string),
+            birthday: Some(birthday),
+            address: request.address.clone(),
+            id_card_front: request.id_card_front.clone(),
+            id_card_back: request.id_card_back.clone(),
+            base: BaseEntity::default(),
+        })
+    }
+
+    /// 校验身份证号唯一
+    async fn check_id_card_no_unique(id_card_no: &str, elder_id: Option<&Uuid>) -> Result<()> {
+        let found = ElderRepository::select_elder_id_by_id_card_no(id_card_no).await?;
+        if found.is_some_and(|found| Some(&found) != elder_id) {
+            return Err(ZzylError::AlreadyExists(format!("身份证号'{}'已登记", id_card_no)));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,133 @@
+use chrono::Utc;
+use sqlx::MySqlConnection;
+use uuid::Uuid;
+use zzyl_common::enums::ElderStatus;
+use zzyl_common::error::{Result, ZzylError};
+use zzyl_common::{BaseEntity, LoginUser};
+use zzyl_framework::database::require_database_pool;
+use crate::domain::{Elder, ElderStatusHistory, ElderStatusRequest};
+use crate::repository::{ElderRepository, ElderStatusHistoryRepository};
+use crate::service::ElderService;
+
+/// 老人状态流转服务
+pub struct ElderStatusService;
+
+impl ElderStatusService {
+    /// 在给定连接上变更老人状态并记录变更历史，供入住、退住等流程在同一事务中调用
+    pub async fn transition(
+        conn: &mut MySqlConnection,
+        login_user: &LoginUser,
+        elder: &Elder,
+        target: ElderStatus,
+        reason: Option<&str>,
+    ) -> Result<()> {
+        let elder_id = elder.id
+            .ok_or_else(|| ZzylError::InvalidParameter("老人ID不能为空".to_string()))?;
+        if !elder.status.can_transition_to(target) {
+            return Err(ZzylError::Business(format!(
+                "老人状态不能从【{}】变更为【{}】",
+                elder.status.label(),
+                target.label()
+            )));
+        }
+        let updated = ElderRepository::update_status(
+            &mut *conn,
+            &elder_id,
+            elder.status,
+            target,
+            &login_user.username,
+        )
+        .await?;
+        if updated == 0 {
+            return Err(ZzylError::Business("老人状态已变更，请刷新后重试".to_string()));
+        }
+
+        let history = ElderStatusHistory {
+            id: Some(Uuid::new_v4()),
+            elder_id: Some(elder_id),
+            from_status: elder.status,
+            to_status: target,
+            reason: reason.map(str::trim).filter(|reason| !reason.is_empty()).map(str::to_string),
+            base: BaseEntity {
+                create_by: Some(login_user.username.clone()),
+                create_time: Some(Utc::now()),
+                ..BaseEntity::default()
+            },
+        };
+        ElderStatusHistoryRepository::insert_history(conn, &history).await?;
+        Ok(())
+    }
+
+    /// 手动变更老人状态，办理入住与退住须通过对应业务流程
+    pub async fn change_status(login_user: &LoginUser, request: &ElderStatusRequest) -> Result<()> {
+        let elder = ElderService::select_elder_by_id(&request.elder_id).await?;
+        Self::check_manual_change(elder.status, request.status)?;
+
+        let mut tx = require_database_pool()?.begin().await?;
+        Self::transition(&mut tx, login_user, &elder, request.status, request.reason.as_deref()).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// 查询老人状态变更记录
+    pub async fn select_history(elder_id: &Uuid) -> Result<Vec<ElderStatusHistory>> {
+        ElderService::select_elder_by_id(elder_id).await?;
+        ElderStatusHistoryRepository::select_by_elder_id(elder_id).await
+    }
+
+    /// 校验是否允许手动变更状态：请假归来与撤销退住申请可直接恢复为入住中，
+    /// 其余变为入住中、退住中、已退住的操作须通过入住或退住流程办理
+    fn check_manual_change(from: ElderStatus, target: ElderStatus) -> Result<()> {
+        let via_flow = match target {
+            ElderStatus::Leaving | ElderStatus::CheckedOut => true,
+            ElderStatus::CheckedIn => !matches!(from, ElderStatus::Leave | ElderStatus::Leaving),
+            _ => false,
+        };
+        if via_flow {
+            return Err(ZzylError::Business(format!(
+                "老人状态变更为【{}】请通过入住或退住流程办理",
+                target.label()
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STATUSES: [ElderStatus; 6] = [
+        ElderStatus::Disabled,
+        ElderStatus::Enabled,
+        ElderStatus::Leave,
+        ElderStatus::Leaving,
+        ElderStatus::CheckedIn,
+        ElderStatus::CheckedOut,
+    ];
+
+    /// 由入住、退住流程完成的状态流转
+    const FLOW_TRANSITIONS: [(ElderStatus, ElderStatus); 4] = [
+        (ElderStatus::Enabled, ElderStatus::CheckedIn),
+        (ElderStatus::CheckedOut, ElderStatus::CheckedIn),
+        (ElderStatus::CheckedIn, ElderStatus::Leaving),
+        (ElderStatus::Leaving, ElderStatus::CheckedOut),
+    ];
+
+    #[test]
+    fn leaving_elder_can_be_restored_to_checked_in() {
+        assert!(ElderStatus::Leaving.can_transition_to(ElderStatus::CheckedIn));
+        assert!(ElderStatusService::check_manual_change(ElderStatus::Leaving, ElderStatus::CheckedIn).is_ok());
+    }
+
+    #[test]
+    fn every_allowed_transition_is_reachable() {
+        for from in STATUSES {
+            for target in STATUSES.into_iter().filter(|target| from.can_transition_to(*target)) {
+                let manual = ElderStatusService::check_manual_change(from, target).is_ok();
+                let via_flow = FLOW_TRANSITIONS.contains(&(from, target));
+                assert!(manual != via_flow, "{:?} -> {:?} manual={} via_flow={}", from, target, manual, via_flow);
+            }
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use chrono::Utc;
+use uuid::Uuid;
+use zzyl_common::error::{Result, ZzylError};
+use zzyl_common::{BaseEntity, LoginUser};
+use zzyl_framework::database::require_database_pool;
+use crate::domain::{Floor, FloorDetailDto, FloorRequest};
+use crate::repository::{BedRepository, FloorRepository, RoomRepository};
+use crate::service::BuildingService;
+
+/// 楼层管理服务
+pub struct FloorService;
+
+impl FloorService {
+    /// 查询全部楼层
+    pub async fn select_floor_all() -> Result<Vec<Floor>> {
+        FloorRepository::select_floor_all().await
+    }
+
+    /// 查询楼层
+    pub async fn select_floor_by_id(floor_id: &Uuid) -> Result<Floor> {
+        FloorRepository::select_floor_by_id(floor_id)
+            .await?
+            .ok_or_else(|| ZzylError::NotFound("楼层不存在".to_string()))
+    }
+
+    /// 查询楼层详情及房间与入住统计
+    pub async fn select_floor_detail(floor_id: &Uuid) -> Result<FloorDetailDto> {
+        let floor = Self::select_floor_by_id(floor_id).await?;
+        let rooms = RoomRepository::select_rooms_by_floor_id(floor_id).await?;
+        let mut beds = Vec::new();
+        for room_id in rooms.iter().filter_map(|room| room.id) {
+            beds.extend(BedRepository::select_beds_by_room_id(&room_id).await?);
+        }
+        Ok(FloorDetailDto {
+            floor,
+            rooms,
+            occupancy_stats: BuildingService::stats(&beds),
+        })
+    }
+
+    /// 新增楼层
+    pub async fn insert_floor(login_user: &LoginUser, request: &FloorRequest) -> Result<Uuid> {
+        let floor_name = request.floor_name.trim();
+        Self::check_floor_unique(floor_name, request.floor_no, None).await?;
+
+        let floor_id = Uuid::new_v4();
+        let floor = Floor {
+            id: Some(floor_id),
+            floor_name: floor_name.to_string(),
+            floor_no: request.floor_no,
+            status: request.status.unwrap_or(1),
+            base: BaseEntity {
+                create_by: Some(login_user.username.clone()),
+                create_time: Some(Utc::now()),
+                update_by: None,
+                update_time: None,
+                remark: request.remark.clone(),
+            },
+        };
+        FloorRepository::insert_floor(&floor).await?;
+        Ok(floor_id)
+    }
+
+    /// 修改楼层
+    pub async fn update_floor(login_user: &LoginUser, request: &FloorRequest) -> Result<()> {
+        let floor_id = request.id
+            .ok_or_else(|| ZzylError::InvalidParameter("楼层ID不能为空".to_string()))?;
+        let existing = Self::select_floor_by_id(&floor_id).await?;
+        let floor_name = request.floor_name.trim();
+        Self::check_floor_unique(floor_name, request.floor_no, Some(&floor_id)).await?;
+
+        let floor = Floor {
+            floor_name: floor_name.to_string(),
+            floor_no: request.floor_no,
+            status: request.status.unwrap_or(existing.status),
+            base: BaseEntity {
+                update_by: Some(login_user.username.clone()),
+                update_time: Some(Utc::now()),
+                remark: request.remark.clone(),
+                ..existing.base
+            },
+            ..existing
+        };
+        FloorRepository::update_floor(&floor).await?;
+        Ok(())
+    }
+
+    /// 批量删除楼层，存在房间的楼层不允许删除
+    pub async fn delete_floor_by_ids(floor_ids: &[Uuid]) -> Result<()> {
+        if floor_ids.is_empty() {
+            return Err(ZzylError::InvalidParameter("请选择要删除的楼层".to_string()));
+        }
+        for floor_id in floor_ids {
+            let floor = Self::select_floor_by_id(floor_id).await?;
+            if RoomRepository::count_room_by_floor_id(floor_id).await? > 0 {
+                return Err(ZzylError::Business(format!("楼层【{}】下存在房间，不能删除", floor.floor_name)));
+            }
+        }
+        let mut tx = require_database_pool()?.begin().await?;
+        for floor_id in floor_ids {
+            if FloorRepository::delete_empty_floor_by_id(&mut tx, floor_id).await? == 0 {
+                return Err(ZzylError::Business("楼层下已新增房间，请刷新后重试".to_string()));
+            }
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// 校验楼层名称不为空且楼层号唯一
+    async fn check_floor_unique(floor_name: &str, floor_no: i32, floor_id: Option<&Uuid>) -> Result<()> {
+        if floor_name.is_empty() {
+            return Err(ZzylError::InvalidParameter("楼层名称不能为空".to_string()));
+        }
+        let found = FloorRepository::select_floor_id_by_no(floor_no).await?;
+        if found.is_some_and(|found| Some(&found) != floor_id) {
+            return Err(ZzylError::AlreadyExists(format!("楼层号'{}'已存在", floor_no)));
+        }
+        Ok(())
+    }
+}
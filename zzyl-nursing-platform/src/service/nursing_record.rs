@@ -0,0 +1,69 @@
+use chrono::Utc;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+use zzyl_common::enums::ElderStatus;
+use zzyl_common::error::{Result, ZzylError};
+use zzyl_common::{BaseEntity, LoginUser, PageDomain, PageResult};
+use crate::domain::{NursingRecord, NursingRecordRequest, NursingRecordSearchCondition};
+use crate::repository::{NursingProjectRepository, NursingRecordRepository};
+use crate::service::ElderService;
+
+/// 护理记录服务
+pub struct NursingRecordService;
+
+impl NursingRecordService {
+    /// 根据条件分页查询护理记录
+    pub async fn select_record_list(
+        condition: &NursingRecordSearchCondition,
+        page: &PageDomain,
+    ) -> Result<PageResult<NursingRecord>> {
+        let (list, total) = NursingRecordRepository::select_record_list(condition, page).await?;
+        Ok(PageResult::new(page.page_num(), page.page_size(), total, list))
+    }
+
+    /// 登记护理项目执行记录，仅在院老人可登记，退住结算时按记录计费
+    pub async fn insert_record(login_user: &LoginUser, request: &NursingRecordRequest) -> Result<Uuid> {
+        let elder = ElderService::select_elder_by_id(&request.elder_id).await?;
+        if !matches!(elder.status, ElderStatus::CheckedIn | ElderStatus::Leaving) {
+            return Err(ZzylError::Business(format!(
+                "老人【{}】当前状态为【{}】，不能登记护理记录",
+                elder.name,
+                elder.status.label()
+            )));
+        }
+        let project = NursingProjectRepository::select_project_by_id(&request.project_id)
+            .await?
+            .ok_or_else(|| ZzylError::NotFound("护理项目不存在".to_string()))?;
+        if project.status != 1 {
+            return Err(ZzylError::Business(format!("护理项目【{}】已停用", project.name)));
+        }
+        let quantity = request.quantity.unwrap_or(Decimal::ONE);
+        if quantity <= Decimal::ZERO {
+            return Err(ZzylError::InvalidParameter("执行数量必须大于0".to_string()));
+        }
+        let now = Utc::now();
+        let execute_time = request.execute_time.unwrap_or(now);
+        if execute_time > now {
+            return Err(ZzylError::InvalidParameter("执行时间不能晚于当前时间".to_string()));
+        }
+
+        let record_id = Uuid::new_v4();
+        let record = NursingRecord {
+            id: Some(record_id),
+            elder_id: Some(request.elder_id),
+            project_id: Some(request.project_id),
+            project_name: Some(project.name),
+            quantity,
+            execute_time: Some(execute_time),
+            base: BaseEntity {
+                create_by: Some(login_user.username.clone()),
+                create_time: Some(now),
+                update_by: None,
+                update_time: None,
+                remark: request.remark.clone(),
+            },
+        };
+        NursingRecordRepository::insert_record(&record).await?;
+        Ok(record_id)
+    }
+}
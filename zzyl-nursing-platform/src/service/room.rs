@@ -0,0 +1,150 @@
+use chrono::Utc;
+use uuid::Uuid;
+use zzyl_common::error::{Result, ZzylError};
+use zzyl_common::{BaseEntity, LoginUser, PageDomain, PageResult};
+use zzyl_framework::database::require_database_pool;
+use crate::domain::{Room, RoomDetailDto, RoomRequest, RoomSearchCondition};
+use crate::repository::{
+    BedRepository, CheckInApplyRepository, ElderRepository, FloorRepository, RoomRepository, RoomTypeRepository,
+};
+use crate::service::{FloorService, RoomTypeService};
+
+/// 房间管理服务
+pub struct RoomService;
+
+impl RoomService {
+    /// 根据条件分页查询房间
+    pub async fn select_room_list(condition: &RoomSearchCondition, page: &PageDomain) -> Result<PageResult<Room>> {
+        let (list, total) = RoomRepository::select_room_list(condition, page).await?;
+        Ok(PageResult::new(page.page_num(), page.page_size(), total, list))
+    }
+
+    /// 查询房间
+    pub async fn select_room_by_id(room_id: &Uuid) -> Result<Room> {
+        RoomRepository::select_room_by_id(room_id)
+            .await?
+            .ok_or_else(|| ZzylError::NotFound("房间不存在".to_string()))
+    }
+
+    /// 查询房间详情及楼层、房间类型、床位与在住老人
+    pub async fn select_room_detail(room_id: &Uuid) -> Result<RoomDetailDto> {
+        let room = Self::select_room_by_id(room_id).await?;
+        let floor = match room.floor_id {
+            Some(floor_id) => FloorRepository::select_floor_by_id(&floor_id).await?,
+            None => None,
+        };
+        let room_type = match room.room_type_id {
+            Some(room_type_id) => RoomTypeRepository::select_room_type_by_id(&room_type_id).await?,
+            None => None,
+        };
+        let elders = ElderRepository::select_checked_in_elders(Some(room_id))
+            .await?
+            .into_iter()
+            .map(|(_, elder)| elder)
+            .collect();
+        Ok(RoomDetailDto {
+            room,
+            floor,
+            room_type,
+            beds: BedRepository::select_beds_by_room_id(room_id).await?,
+            elders,
+        })
+    }
+
+    /// 新增房间，初始为空闲状态
+    pub async fn insert_room(login_user: &LoginUser, request: &RoomRequest) -> Result<Uuid> {
+        let room_no = request.room_no.trim();
+        Self::check_room(request, room_no, None).await?;
+
+        let room_id = Uuid::new_v4();
+        let room = Room {
+            id: Some(room_id),
+            room_no: room_no.to_string(),
+            floor_id: Some(request.floor_id),
+            room_type_id: Some(request.room_type_id),
+            status: 0,
+            base: BaseEntity {
+                create_by: Some(login_user.username.clone()),
+                create_time: Some(Utc::now()),
+                update_by: None,
+                update_time: None,
+                remark: request.remark.clone(),
+            },
+        };
+        RoomRepository::insert_room(&room).await?;
+        Ok(room_id)
+    }
+
+    /// 修改房间
+    pub async fn update_room(login_user: &LoginUser, request: &RoomRequest) -> Result<()> {
+        let room_id = request.id
+            .ok_or_else(|| ZzylError::InvalidParameter("房间ID不能为空".to_string()))?;
+        let existing = Self::select_room_by_id(&room_id).await?;
+        let room_no = request.room_no.trim();
+        Self::check_room(request, room_no, Some(&room_id)).await?;
+
+        let room = Room {
+            room_no: room_no.to_string(),
+            floor_id: Some(request.floor_id),
+            room_type_id: Some(request.room_type_id),
+            base: BaseEntity {
+                update_by: Some(login_user.username.clone()),
+                update_time: Some(Utc::now()),
+                remark: request.remark.clone(),
+                ..existing.base
+            },
+            ..existing
+        };
+        RoomRepository::update_room(&room).await?;
+        Ok(())
+    }
+
+    /// 批量删除房间及其空闲床位，存在已入住或已被入住申请选定床位的房间不允许删除
+    pub async fn delete_room_by_ids(room_ids: &[Uuid]) -> Result<()> {
+        if room_ids.is_empty() {
+            return Err(ZzylError::InvalidParameter("请选择要删除的房间".to_string()));
+        }
+        for room_id in room_ids {
+            let room = Self::select_room_by_id(room_id).await?;
+            if BedRepository::count_occupied_bed_by_room_id(room_id).await? > 0 {
+                return Err(ZzylError::Business(format!("房间【{}】存在已入住床位，不能删除", room.room_no)));
+            }
+            for bed in BedRepository::select_beds_by_room_id(room_id).await? {
+                let Some(bed_id) = bed.id else { continue };
+                if CheckInApplyRepository::count_pending_by_bed_id(&bed_id).await? > 0 {
+                    return Err(ZzylError::Business(format!(
+                        "房间【{}】的床位【{}】已被入住申请选定，不能删除",
+                        room.room_no, bed.bed_no
+                    )));
+                }
+            }
+        }
+
+        let mut tx = require_database_pool()?.begin().await?;
+        for room_id in room_ids {
+            BedRepository::delete_free_beds_by_room_id(&mut tx, room_id).await?;
+            if RoomRepository::delete_empty_room_by_id(&mut tx, room_id).await? == 0 {
+                return Err(ZzylError::Business("房间床位已被占用，请刷新后重试".to_string()));
+            }
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// 校验房间号、楼层与房间类型
+    async fn check_room(request: &RoomRequest, room_no: &str, room_id: Option<&Uuid>) -> Result<()> {
+        if room_no.is_empty() {
+            return Err(ZzylError::InvalidParameter("房间号不能为空".to_string()));
+        }
+        FloorService::select_floor_by_id(&request.floor_id).await?;
+        let room_type = RoomTypeService::select_room_type_by_id(&request.room_type_id).await?;
+        if room_type.status != 1 {
+            return Err(ZzylError::Business(format!("房间类型【{}】已禁用", room_type.type_name)));
+        }
+        let found = RoomRepository::select_room_id_by_no(&request.floor_id, room_no).await?;
+        if found.is_some_and(|found| Some(&found) != room_id) {
+            return Err(ZzylError::AlreadyExists(format!("房间号'{}'在该楼层已存在", room_no)));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,108 @@
+use chrono::Utc;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+use zzyl_common::error::{Result, ZzylError};
+use zzyl_common::{BaseEntity, LoginUser};
+use zzyl_framework::database::require_database_pool;
+use crate::domain::{RoomType, RoomTypeRequest};
+use crate::repository::{RoomRepository, RoomTypeRepository};
+
+/// 房间类型管理服务
+pub struct RoomTypeService;
+
+impl RoomTypeService {
+    /// 查询全部房间类型
+    pub async fn select_room_type_all() -> Result<Vec<RoomType>> {
+        RoomTypeRepository::select_room_type_all().await
+    }
+
+    /// 查询房间类型
+    pub async fn select_room_type_by_id(room_type_id: &Uuid) -> Result<RoomType> {
+        RoomTypeRepository::select_room_type_by_id(room_type_id)
+            .await?
+            .ok_or_else(|| ZzylError::NotFound("房间类型不存在".to_string()))
+    }
+
+    /// 新增房间类型
+    pub async fn insert_room_type(login_user: &LoginUser, request: &RoomTypeRequest) -> Result<Uuid> {
+        let type_name = request.type_name.trim();
+        Self::check_room_type(type_name, request.price, None).await?;
+
+        let room_type_id = Uuid::new_v4();
+        let room_type = RoomType {
+            id: Some(room_type_id),
+            type_name: type_name.to_string(),
+            price: request.price,
+            status: request.status.unwrap_or(1),
+            base: BaseEntity {
+                create_by: Some(login_user.username.clone()),
+                create_time: Some(Utc::now()),
+                update_by: None,
+                update_time: None,
+                remark: request.remark.clone(),
+            },
+        };
+        RoomTypeRepository::insert_room_type(&room_type).await?;
+        Ok(room_type_id)
+    }
+
+    /// 修改房间类型
+    pub async fn update_room_type(login_user: &LoginUser, request: &RoomTypeRequest) -> Result<()> {
+        let room_type_id = request.id
+            .ok_or_else(|| ZzylError::InvalidParameter("房间类型ID不能为空".to_string()))?;
+        let existing = Self::select_room_type_by_id(&room_type_id).await?;
+        let type_name = request.type_name.trim();
+        Self::check_room_type(type_name, request.price, Some(&room_type_id)).await?;
+
+        let room_type = RoomType {
+            type_name: type_name.to_string(),
+            price: request.price,
+            status: request.status.unwrap_or(existing.status),
+            base: BaseEntity {
+                update_by: Some(login_user.username.clone()),
+                update_time: Some(Utc::now()),
+                remark: request.remark.clone(),
+                ..existing.base
+            },
+            ..existing
+        };
+        RoomTypeRepository::update_room_type(&room_type).await?;
+        Ok(())
+    }
+
+    /// 批量删除房间类型，已被房间使用的类型不允许删除
+    pub async fn delete_room_type_by_ids(room_type_ids: &[Uuid]) -> Result<()> {
+        if room_type_ids.is_empty() {
+            return Err(ZzylError::InvalidParameter("请选择要删除的房间类型".to_string()));
+        }
+        for room_type_id in room_type_ids {
+            let room_type = Self::select_room_type_by_id(room_type_id).await?;
+            if RoomRepository::count_room_by_room_type_id(room_type_id).await? > 0 {
+                return Err(ZzylError::Business(format!("房间类型【{}】已被使用，不能删除", room_type.type_name)));
+            }
+        }
+        let mut tx = require_database_pool()?.begin().await?;
+        for room_type_id in room_type_ids {
+            if RoomTypeRepository::delete_unused_room_type_by_id(&mut tx, room_type_id).await? == 0 {
+                return Err(ZzylError::Business("房间类型已被使用，请刷新后重试".to_string()));
+            }
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// 校验类型名称唯一且价格不为负数
+    async fn check_room_type(type_name: &str, price: Option<Decimal>, room_type_id: Option<&Uuid>) -> Result<()> {
+        if type_name.is_empty() {
+            return Err(ZzylError::InvalidParameter("房间类型名称不能为空".to_string()));
+        }
+        if price.is_some_and(|price| price < Decimal::ZERO) {
+            return Err(ZzylError::InvalidParameter("房间类型价格不能为负数".to_string()));
+        }
+        let found = RoomTypeRepository::select_room_type_id_by_name(type_name).await?;
+        if found.is_some_and(|found| Some(&found) != room_type_id) {
+            return Err(ZzylError::AlreadyExists(format!("房间类型'{}'已存在", type_name)));
+        }
+        Ok(())
+    }
+}
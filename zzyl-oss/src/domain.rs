@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+/// 已上传文件信息
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OssFile {
+    /// 存储键（相对路径）
+    pub key: String,
+    /// 访问地址
+    pub url: String,
+    /// 原始文件名
+    pub original_name: String,
+    /// 文件大小（字节）
+    pub size: u64,
+    /// 文件类型
+    pub content_type: Option<String>,
+}
@@ -0,0 +1,82 @@
+use chrono::Utc;
+use uuid::Uuid;
+use zzyl_common::error::{Result, ZzylError};
+use zzyl_framework::config::ZzylConfig;
+use crate::domain::OssFile;
+use crate::storage::OssStorage;
+
+/// 全局对象存储实例
+static mut OSS_STORAGE: Option<OssStorage> = None;
+
+/// 初始化对象存储
+pub fn init_oss(config: &ZzylConfig) -> Result<()> {
+    let storage = OssStorage::from_config(config)?;
+    unsafe {
+        OSS_STORAGE = Some(storage);
+    }
+    Ok(())
+}
+
+/// 获取对象存储
+pub fn get_oss_storage() -> Option<&'static OssStorage> {
+    unsafe {
+        OSS_STORAGE.as_ref()
+    }
+}
+
+/// 获取对象存储（未初始化时返回错误）
+pub fn require_oss_storage() -> Result<&'static OssStorage> {
+    get_oss_storage().ok_or_else(|| ZzylError::ServiceUnavailable("对象存储未初始化".to_string()))
+}
+
+/// 对象存储服务
+pub struct OssService;
+
+impl OssService {
+    /// 上传文件，按 目录/年/月/日/随机名 生成存储键
+    pub async fn upload(
+        dir: &str,
+        original_name: &str,
+        content_type: Option<&str>,
+        bytes: Vec<u8>,
+    ) -> Result<OssFile> {
+        let storage = require_oss_storage()?;
+        let key = Self::build_key(dir, original_name);
+        let size = bytes.len() as u64;
+        storage.put(&key, bytes, content_type).await?;
+
+        Ok(OssFile {
+            url: storage.url(&key),
+            key,
+            original_name: original_name.to_string(),
+            size,
+            content_type: content_type.map(str::to_string),
+        })
+    }
+
+    /// 按访问地址删除文件，非本存储的地址直接忽略
+    pub async fn delete_by_url(url: &str) -> Result<()> {
+        let storage = require_oss_storage()?;
+        match storage.key_of(url) {
+            Some(key) => storage.delete(&key).await,
+            None => Ok(()),
+        }
+    }
+
+    /// 获取文件扩展名（小写）
+    pub fn extension(file_name: &str) -> Option<String> {
+        file_name
+            .rsplit_once('.')
+            .map(|(_, ext)| ext.to_ascii_lowercase())
+            .filter(|ext| !ext.is_empty())
+    }
+
+    /// 生成存储键
+    fn build_key(dir: &str, original_name: &str) -> String {
+        let file_name = match Self::extension(original_name) {
+            Some(ext) => format!("{}.{}", Uuid::new_v4().simple(), ext),
+            None => Uuid::new_v4().simple().to_string(),
+        };
+        format!("{}/{}/{}", dir.trim_matches('/'), Utc::now().format("%Y/%m/%d"), file_name)
+    }
+}
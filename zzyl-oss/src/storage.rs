@@ -0,0 +1,202 @@
+use std::path::{Component, Path, PathBuf};
+use aws_sdk_s3::config::{BehaviorVersion, Credentials, Region};
+use aws_sdk_s3::primitives::ByteStream;
+use zzyl_common::error::{Result, ZzylError};
+use zzyl_framework::config::{OssConfig, OssStorageType, ZzylConfig};
+
+/// 本地文件访问前缀
+pub const LOCAL_RESOURCE_PREFIX: &str = "/profile";
+
+/// 本地磁盘存储
+pub struct LocalStorage {
+    /// 存储根目录
+    base_path: PathBuf,
+    /// 访问域名
+    domain: String,
+}
+
+impl LocalStorage {
+    /// 创建本地存储
+    pub fn new(base_path: impl Into<PathBuf>, domain: &str) -> Self {
+        Self {
+            base_path: base_path.into(),
+            domain: domain.trim_end_matches('/').to_string(),
+        }
+    }
+
+    /// 写入文件
+    pub async fn put(&self, key: &str, bytes: Vec<u8>) -> Result<()> {
+        let path = self.resolve(key)?;
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        tokio::fs::write(&path, bytes).await?;
+        Ok(())
+    }
+
+    /// 删除文件，文件不存在时忽略
+    pub async fn delete(&self, key: &str) -> Result<()> {
+        match tokio::fs::remove_file(self.resolve(key)?).await {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
+
+    /// 获取文件访问地址
+    pub fn url(&self, key: &str) -> String {
+        format!("{}{}/{}", self.domain, LOCAL_RESOURCE_PREFIX, key)
+    }
+
+    /// 由访问地址解析存储键
+    pub fn key_of(&self, url: &str) -> Option<String> {
+        url.strip_prefix(&self.domain)?
+            .strip_prefix(LOCAL_RESOURCE_PREFIX)?
+            .strip_prefix('/')
+            .filter(|key| Self::is_safe_key(key))
+            .map(str::to_string)
+    }
+
+    /// 将存储键解析为根目录下的文件路径，拒绝越出根目录的键
+    fn resolve(&self, key: &str) -> Result<PathBuf> {
+        if !Self::is_safe_key(key) {
+            return Err(ZzylError::InvalidParameter(format!("非法的文件路径: {}", key)));
+        }
+        let path = self.base_path.join(key);
+        if !path.starts_with(&self.base_path) {
+            return Err(ZzylError::InvalidParameter(format!("非法的文件路径: {}", key)));
+        }
+        Ok(path)
+    }
+
+    /// 存储键只能由普通路径段组成，不允许为空、绝对路径或包含“..”
+    fn is_safe_key(key: &str) -> bool {
+        !key.is_empty()
+            && !key.contains('\\')
+            && Path::new(key).components().all(|component| matches!(component, Component::Normal(_)))
+    }
+}
+
+/// S3兼容对象存储
+pub struct S3Storage {
+    /// S3客户端
+    client: aws_sdk_s3::Client,
+    /// 存储桶
+    bucket: String,
+    /// 访问地址前缀
+    base_url: String,
+}
+
+impl S3Storage {
+    /// 创建S3存储
+    pub fn new(config: &OssConfig) -> Result<Self> {
+        if config.bucket.is_empty() {
+            return Err(ZzylError::Configuration("对象存储未配置存储桶".to_string()));
+        }
+        let credentials = Credentials::new(&config.access_key, &config.secret_key, None, None, "zzyl-oss");
+        let mut builder = aws_sdk_s3::Config::builder()
+            .behavior_version(BehaviorVersion::latest())
+            .region(Region::new(config.region.clone()))
+            .credentials_provider(credentials)
+            .force_path_style(true);
+        if !config.endpoint.is_empty() {
+            builder = builder.endpoint_url(&config.endpoint);
+        }
+
+        let base_url = if config.domain.is_empty() {
+            format!("{}/{}", config.endpoint.trim_end_matches('/'), config.bucket)
+        } else {
+            config.domain.trim_end_matches('/').to_string()
+        };
+        Ok(Self {
+            client: aws_sdk_s3::Client::from_conf(builder.build()),
+            bucket: config.bucket.clone(),
+            base_url,
+        })
+    }
+
+    /// 上传对象
+    pub async fn put(&self, key: &str, bytes: Vec<u8>, content_type: Option<&str>) -> Result<()> {
+        self.client
+            .put_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .body(ByteStream::from(bytes))
+            .set_content_type(content_type.map(str::to_string))
+            .send()
+            .await
+            .map_err(|e| ZzylError::FileOperation(format!("上传文件失败: {}", e)))?;
+        Ok(())
+    }
+
+    /// 删除对象
+    pub async fn delete(&self, key: &str) -> Result<()> {
+        self.client
+            .delete_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .send()
+            .await
+            .map_err(|e| ZzylError::FileOperation(format!("删除文件失败: {}", e)))?;
+        Ok(())
+    }
+
+    /// 获取对象访问地址
+    pub fn url(&self, key: &str) -> String {
+        format!("{}/{}", self.base_url, key)
+    }
+
+    /// 由访问地址解析存储键
+    pub fn key_of(&self, url: &str) -> Option<String> {
+        url.strip_prefix(&self.base_url)?.strip_prefix('/').map(str::to_string)
+    }
+}
+
+/// 对象存储
+pub enum OssStorage {
+    /// 本地磁盘
+    Local(LocalStorage),
+    /// S3兼容存储
+    S3(S3Storage),
+}
+
+impl OssStorage {
+    /// 根据配置创建存储
+    pub fn from_config(config: &ZzylConfig) -> Result<Self> {
+        match config.oss.storage_type {
+            OssStorageType::Local => Ok(Self::Local(LocalStorage::new(config.get_upload_path(), &config.oss.domain))),
+            OssStorageType::S3 => Ok(Self::S3(S3Storage::new(&config.oss)?)),
+        }
+    }
+
+    /// 写入文件
+    pub async fn put(&self, key: &str, bytes: Vec<u8>, content_type: Option<&str>) -> Result<()> {
+        match self {
+            Self::Local(storage) => storage.put(key, bytes).await,
+            Self::S3(storage) => storage.put(key, bytes, content_type).await,
+        }
+    }
+
+    /// 删除文件
+    pub async fn delete(&self, key: &str) -> Result<()> {
+        match self {
+            Self::Local(storage) => storage.delete(key).await,
+            Self::S3(storage) => storage.delete(key).await,
+        }
+    }
+
+    /// 获取文件访问地址
+    pub fn url(&self, key: &str) -> String {
+        match self {
+            Self::Local(storage) => storage.url(key),
+            Self::S3(storage) => storage.url(key),
+        }
+    }
+
+    /// 由访问地址解析存储键，非本存储的地址返回None
+    pub fn key_of(&self, url: &str) -> Option<String> {
+        match self {
+            Self::Local(storage) => storage.key_of(url),
+            Self::S3(storage) => storage.key_of(url),
+        }
+    }
+}
@@ -0,0 +1,403 @@
+pub mod login;
+pub mod captcha;
+pub mod config;
+pub mod dept;
+pub mod dict_data;
+pub mod dict_type;
+pub mod login_info;
+pub mod menu;
+pub mod online;
+pub mod profile;
+pub mod oper_log;
+pub mod post;
+pub mod role;
+pub mod user;
+
+use axum::{
+    extract::DefaultBodyLimit,
+    routing::{delete, get, post, put},
+    Router,
+};
+use zzyl_common::enums::BusinessType;
+use zzyl_framework::{oper_log, perm};
+use crate::service::{AVATAR_MAX_SIZE, IMPORT_MAX_SIZE};
+
+/// multipart请求中边界与字段头的预留字节数
+const MULTIPART_RESERVED_SIZE: usize = 64 * 1024;
+
+/// 系统管理路由
+pub fn routes<S>() -> Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    Router::new()
+        .route("/api/login", post(login::login))
+        .route("/api/logout", post(login::logout))
+        .route("/api/refresh", post(login::refresh))
+        .route("/getInfo", get(login::get_info))
+        .route("/getRouters", get(menu::get_routers))
+        .route("/captchaImage", get(captcha::captcha_image))
+        .merge(user_routes())
+        .merge(role_routes())
+        .merge(menu_routes())
+        .merge(dept_routes())
+        .merge(dict_routes())
+        .merge(config_routes())
+        .merge(post_routes())
+        .merge(monitor_routes())
+}
+
+/// 用户管理路由
+fn user_routes<S>() -> Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    Router::new()
+        .route("/system/user/list", get(user::list).route_layer(perm!("system:user:list")))
+        .route("/system/user/importTemplate", post(user::import_template))
+        .route(
+            "/system/user/importData",
+            post(user::import_data)
+                .route_layer(oper_log!("用户管理", BusinessType::Import))
+                .route_layer(perm!("system:user:import"))
+                .layer(DefaultBodyLimit::max(IMPORT_MAX_SIZE + MULTIPART_RESERVED_SIZE)),
+        )
+        .route(
+            "/system/user/profile",
+            get(profile::profile)
+                .merge(put(profile::update_profile).route_layer(oper_log!("个人信息", BusinessType::Update))),
+        )
+        .route(
+            "/system/user/profile/updatePwd",
+            put(profile::update_pwd).route_layer(oper_log!("个人信息", BusinessType::Update)),
+        )
+        .route(
+            "/system/user/profile/avatar",
+            post(profile::avatar)
+                .route_layer(oper_log!("用户头像", BusinessType::Update))
+                .layer(DefaultBodyLimit::max(AVATAR_MAX_SIZE + MULTIPART_RESERVED_SIZE)),
+        )
+        .route(
+            "/system/user",
+            post(user::add)
+                .route_layer(oper_log!("用户管理", BusinessType::Insert))
+                .route_layer(perm!("system:user:add"))
+                .merge(put(user::edit)
+                    .route_layer(oper_log!("用户管理", BusinessType::Update))
+                    .route_layer(perm!("system:user:edit"))),
+        )
+        .route(
+            "/system/user/:user_id",
+            get(user::get_info)
+                .route_layer(perm!("system:user:query"))
+                .merge(delete(user::remove)
+                    .route_layer(oper_log!("用户管理", BusinessType::Delete))
+                    .route_layer(perm!("system:user:remove"))),
+        )
+        .route(
+            "/system/user/resetPwd",
+            put(user::reset_pwd)
+                .route_layer(oper_log!("用户管理", BusinessType::Update))
+                .route_layer(perm!("system:user:resetPwd")),
+        )
+        .route(
+            "/system/user/changeStatus",
+            put(user::change_status)
+                .route_layer(oper_log!("用户管理", BusinessType::Update))
+                .route_layer(perm!("system:user:edit")),
+        )
+        .route(
+            "/system/user/authRole",
+            put(user::auth_role)
+                .route_layer(oper_log!("用户管理", BusinessType::Grant))
+                .route_layer(perm!("system:user:edit")),
+        )
+}
+
+/// 角色管理路由
+fn role_routes<S>() -> Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    Router::new()
+        .route("/system/role/list", get(role::list).route_layer(perm!("system:role:list")))
+        .route("/system/role/optionselect", get(role::option_select).route_layer(perm!("system:role:query")))
+        .route(
+            "/system/role",
+            post(role::add)
+                .route_layer(oper_log!("角色管理", BusinessType::Insert))
+                .route_layer(perm!("system:role:add"))
+                .merge(put(role::edit)
+                    .route_layer(oper_log!("角色管理", BusinessType::Update))
+                    .route_layer(perm!("system:role:edit"))),
+        )
+        .route(
+            "/system/role/:role_id",
+            get(role::get_info)
+                .route_layer(perm!("system:role:query"))
+                .merge(delete(role::remove)
+                    .route_layer(oper_log!("角色管理", BusinessType::Delete))
+                    .route_layer(perm!("system:role:remove"))),
+        )
+        .route(
+            "/system/role/dataScope",
+            put(role::data_scope)
+                .route_layer(oper_log!("角色管理", BusinessType::Update))
+                .route_layer(perm!("system:role:edit")),
+        )
+        .route(
+            "/system/role/changeStatus",
+            put(role::change_status)
+                .route_layer(oper_log!("角色管理", BusinessType::Update))
+                .route_layer(perm!("system:role:edit")),
+        )
+        .route(
+            "/system/role/authUser/allocatedList",
+            get(role::allocated_list).route_layer(perm!("system:role:list")),
+        )
+        .route(
+            "/system/role/authUser/unallocatedList",
+            get(role::unallocated_list).route_layer(perm!("system:role:list")),
+        )
+        .route(
+            "/system/role/authUser/selectAll",
+            put(role::select_all)
+                .route_layer(oper_log!("角色管理", BusinessType::Grant))
+                .route_layer(perm!("system:role:edit")),
+        )
+        .route(
+            "/system/role/authUser/cancelAll",
+            put(role::cancel_all)
+                .route_layer(oper_log!("角色管理", BusinessType::Grant))
+                .route_layer(perm!("system:role:edit")),
+        )
+}
+
+/// 菜单管理路由
+fn menu_routes<S>() -> Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    Router::new()
+        .route("/system/menu/treeselect", get(menu::treeselect).route_layer(perm!("system:menu:query")))
+        .route(
+            "/system/menu/roleMenuTreeselect/:role_id",
+            get(menu::role_menu_treeselect).route_layer(perm!("system:menu:query")),
+        )
+}
+
+/// 部门管理路由
+fn dept_routes<S>() -> Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    Router::new()
+        .route("/system/dept/list", get(dept::list).route_layer(perm!("system:dept:list")))
+        .route(
+            "/system/dept/list/exclude/:dept_id",
+            get(dept::exclude_child).route_layer(perm!("system:dept:list")),
+        )
+        .route("/system/dept/treeselect", get(dept::treeselect).route_layer(perm!("system:dept:query")))
+        .route(
+            "/system/dept/roleDeptTreeselect/:role_id",
+            get(dept::role_dept_treeselect).route_layer(perm!("system:role:query")),
+        )
+        .route(
+            "/system/dept",
+            post(dept::add)
+                .route_layer(oper_log!("部门管理", BusinessType::Insert))
+                .route_layer(perm!("system:dept:add"))
+                .merge(put(dept::edit)
+                    .route_layer(oper_log!("部门管理", BusinessType::Update))
+                    .route_layer(perm!("system:dept:edit"))),
+        )
+        .route(
+            "/system/dept/:dept_id",
+            get(dept::get_info)
+                .route_layer(perm!("system:dept:query"))
+                .merge(delete(dept::remove)
+                    .route_layer(oper_log!("部门管理", BusinessType::Delete))
+                    .route_layer(perm!("system:dept:remove"))),
+        )
+}
+
+/// 字典管理路由
+fn dict_routes<S>() -> Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    Router::new()
+        .route("/system/dict/type/list", get(dict_type::list).route_layer(perm!("system:dict:list")))
+        .route("/system/dict/type/optionselect", get(dict_type::option_select))
+        .route(
+            "/system/dict/type/refreshCache",
+            delete(dict_type::refresh_cache)
+                .route_layer(oper_log!("字典类型", BusinessType::Clean))
+                .route_layer(perm!("system:dict:remove")),
+        )
+        .route(
+            "/system/dict/type",
+            post(dict_type::add)
+                .route_layer(oper_log!("字典类型", BusinessType::Insert))
+                .route_layer(perm!("system:dict:add"))
+                .merge(put(dict_type::edit)
+                    .route_layer(oper_log!("字典类型", BusinessType::Update))
+                    .route_layer(perm!("system:dict:edit"))),
+        )
+        .route(
+            "/system/dict/type/:dict_id",
+            get(dict_type::get_info)
+                .route_layer(perm!("system:dict:query"))
+                .merge(delete(dict_type::remove)
+                    .route_layer(oper_log!("字典类型", BusinessType::Delete))
+                    .route_layer(perm!("system:dict:remove"))),
+        )
+        .route("/system/dict/data/list", get(dict_data::list).route_layer(perm!("system:dict:list")))
+        .route("/system/dict/data/type/:dict_type", get(dict_data::dict_type))
+        .route(
+            "/system/dict/data",
+            post(dict_data::add)
+                .route_layer(oper_log!("字典数据", BusinessType::Insert))
+                .route_layer(perm!("system:dict:add"))
+                .merge(put(dict_data::edit)
+                    .route_layer(oper_log!("字典数据", BusinessType::Update))
+                    .route_layer(perm!("system:dict:edit"))),
+        )
+        .route(
+            "/system/dict/data/:dict_code",
+            get(dict_data::get_info)
+                .route_layer(perm!("system:dict:query"))
+                .merge(delete(dict_data::remove)
+                    .route_layer(oper_log!("字典数据", BusinessType::Delete))
+                    .route_layer(perm!("system:dict:remove"))),
+        )
+}
+
+/// 参数配置路由
+fn config_routes<S>() -> Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    Router::new()
+        .route("/system/config/list", get(config::list).route_layer(perm!("system:config:list")))
+        .route("/system/config/configKey/:config_key", get(config::get_config_key))
+        .route(
+            "/system/config/refreshCache",
+            delete(config::refresh_cache)
+                .route_layer(oper_log!("参数管理", BusinessType::Clean))
+                .route_layer(perm!("system:config:remove")),
+        )
+        .route(
+            "/system/config",
+            post(config::add)
+                .route_layer(oper_log!("参数管理", BusinessType::Insert))
+                .route_layer(perm!("system:config:add"))
+                .merge(put(config::edit)
+                    .route_layer(oper_log!("参数管理", BusinessType::Update))
+                    .route_layer(perm!("system:config:edit"))),
+        )
+        .route(
+            "/system/config/:config_id",
+            get(config::get_info)
+                .route_layer(perm!("system:config:query"))
+                .merge(delete(config::remove)
+                    .route_layer(oper_log!("参数管理", BusinessType::Delete))
+                    .route_layer(perm!("system:config:remove"))),
+        )
+}
+
+/// 岗位管理路由
+fn post_routes<S>() -> Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    Router::new()
+        .route("/system/post/list", get(post::list).route_layer(perm!("system:post:list")))
+        .route("/system/post/optionselect", get(post::option_select).route_layer(perm!("system:post:query")))
+        .route(
+            "/system/post/export",
+            post(post::export)
+                .route_layer(oper_log!("岗位管理", BusinessType::Export))
+                .route_layer(perm!("system:post:export")),
+        )
+        .route(
+            "/system/post",
+            post(post::add)
+                .route_layer(oper_log!("岗位管理", BusinessType::Insert))
+                .route_layer(perm!("system:post:add"))
+                .merge(put(post::edit)
+                    .route_layer(oper_log!("岗位管理", BusinessType::Update))
+                    .route_layer(perm!("system:post:edit"))),
+        )
+        .route(
+            "/system/post/:post_id",
+            get(post::get_info)
+                .route_layer(perm!("system:post:query"))
+                .merge(delete(post::remove)
+                    .route_layer(oper_log!("岗位管理", BusinessType::Delete))
+                    .route_layer(perm!("system:post:remove"))),
+        )
+}
+
+/// 系统监控路由
+fn monitor_routes<S>() -> Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    Router::new()
+        .route("/monitor/online", get(online::list).route_layer(perm!("monitor:online:list")))
+        .route(
+            "/monitor/online/:token_id",
+            delete(online::force_logout)
+                .route_layer(oper_log!("在线用户", BusinessType::Force))
+                .route_layer(perm!("monitor:online:forceLogout")),
+        )
+        .route("/monitor/logininfor/list", get(login_info::list).route_layer(perm!("monitor:logininfor:list")))
+        .route(
+            "/monitor/logininfor/export",
+            post(login_info::export)
+                .route_layer(oper_log!("登录日志", BusinessType::Export))
+                .route_layer(perm!("monitor:logininfor:export")),
+        )
+        .route(
+            "/monitor/logininfor/clean",
+            delete(login_info::clean)
+                .route_layer(oper_log!("登录日志", BusinessType::Clean))
+                .route_layer(perm!("monitor:logininfor:remove")),
+        )
+        .route(
+            "/monitor/logininfor/unlock/:username",
+            get(login_info::unlock)
+                .route_layer(oper_log!("账户解锁", BusinessType::Other))
+                .route_layer(perm!("monitor:logininfor:unlock")),
+        )
+        .route(
+            "/monitor/logininfor/:info_id",
+            get(login_info::get_info)
+                .route_layer(perm!("monitor:logininfor:query"))
+                .merge(delete(login_info::remove)
+                    .route_layer(oper_log!("登录日志", BusinessType::Delete))
+                    .route_layer(perm!("monitor:logininfor:remove"))),
+        )
+        .route("/monitor/operlog/list", get(oper_log::list).route_layer(perm!("monitor:operlog:list")))
+        .route(
+            "/monitor/operlog/export",
+            post(oper_log::export)
+                .route_layer(oper_log!("操作日志", BusinessType::Export))
+                .route_layer(perm!("monitor:operlog:export")),
+        )
+        .route(
+            "/monitor/operlog/clean",
+            delete(oper_log::clean)
+                .route_layer(oper_log!("操作日志", BusinessType::Clean))
+                .route_layer(perm!("monitor:operlog:remove")),
+        )
+        .route(
+            "/monitor/operlog/:oper_id",
+            get(oper_log::get_info)
+                .route_layer(perm!("monitor:operlog:query"))
+                .merge(delete(oper_log::remove)
+                    .route_layer(oper_log!("操作日志", BusinessType::Delete))
+                    .route_layer(perm!("monitor:operlog:remove"))),
+        )
+}
@@ -0,0 +1,7 @@
+use zzyl_common::error::{AjaxResult, Result};
+use zzyl_framework::captcha::{CaptchaImage, CaptchaService};
+
+/// 生成验证码
+pub async fn captcha_image() -> Result<AjaxResult<CaptchaImage>> {
+    Ok(AjaxResult::success(CaptchaService::create_captcha().await?))
+}
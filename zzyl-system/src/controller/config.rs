@@ -0,0 +1,51 @@
+use axum::{extract::{Path, Query}, Json};
+use uuid::Uuid;
+use zzyl_common::error::{AjaxResult, Result, TableDataInfo};
+use zzyl_common::PageDomain;
+use zzyl_framework::security::CurrentUser;
+use crate::domain::{ConfigRequest, ConfigSearchCondition, SysConfig};
+use zzyl_framework::web::parse_ids;
+use crate::service::SysConfigService;
+
+/// 参数配置列表
+pub async fn list(
+    Query(condition): Query<ConfigSearchCondition>,
+    Query(page): Query<PageDomain>,
+) -> Result<TableDataInfo<SysConfig>> {
+    let page = SysConfigService::select_config_list(&condition, &page).await?;
+    Ok(TableDataInfo::success(page.list, page.total))
+}
+
+/// 参数配置详情
+pub async fn get_info(Path(config_id): Path<Uuid>) -> Result<AjaxResult<SysConfig>> {
+    Ok(AjaxResult::success(SysConfigService::select_config_by_id(&config_id).await?))
+}
+
+/// 根据参数键名查询参数值
+pub async fn get_config_key(Path(config_key): Path<String>) -> Result<AjaxResult<String>> {
+    Ok(AjaxResult::success(SysConfigService::select_config_by_key(&config_key).await?))
+}
+
+/// 新增参数配置
+pub async fn add(CurrentUser(login_user): CurrentUser, Json(request): Json<ConfigRequest>) -> Result<AjaxResult<Uuid>> {
+    Ok(AjaxResult::success(SysConfigService::insert_config(&login_user, &request).await?))
+}
+
+/// 修改参数配置
+pub async fn edit(CurrentUser(login_user): CurrentUser, Json(request): Json<ConfigRequest>) -> Result<AjaxResult<()>> {
+    SysConfigService::update_config(&login_user, &request).await?;
+    Ok(AjaxResult::success_msg("操作成功"))
+}
+
+/// 删除参数配置，多个ID以逗号分隔
+pub async fn remove(Path(config_ids): Path<String>) -> Result<AjaxResult<()>> {
+    let config_ids = parse_ids(&config_ids)?;
+    SysConfigService::delete_config_by_ids(&config_ids).await?;
+    Ok(AjaxResult::success_msg("操作成功"))
+}
+
+/// 刷新参数缓存
+pub async fn refresh_cache() -> Result<AjaxResult<()>> {
+    SysConfigService::reset_config_cache().await?;
+    Ok(AjaxResult::success_msg("操作成功"))
+}
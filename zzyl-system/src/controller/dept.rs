@@ -0,0 +1,60 @@
+use axum::{extract::{Path, Query}, Json};
+use uuid::Uuid;
+use zzyl_common::error::{AjaxResult, Result};
+use zzyl_framework::security::CurrentUser;
+use crate::domain::{DeptRequest, DeptSearchCondition, RoleDeptTreeResponse, SysDept, TreeSelect};
+use crate::service::SysDeptService;
+
+/// 部门列表
+pub async fn list(
+    CurrentUser(login_user): CurrentUser,
+    Query(condition): Query<DeptSearchCondition>,
+) -> Result<AjaxResult<Vec<SysDept>>> {
+    Ok(AjaxResult::success(SysDeptService::select_dept_list(&login_user, &condition).await?))
+}
+
+/// 部门列表（排除指定部门及其下级）
+pub async fn exclude_child(
+    CurrentUser(login_user): CurrentUser,
+    Path(dept_id): Path<Uuid>,
+) -> Result<AjaxResult<Vec<SysDept>>> {
+    Ok(AjaxResult::success(SysDeptService::select_dept_list_exclude_child(&login_user, &dept_id).await?))
+}
+
+/// 部门下拉树
+pub async fn treeselect(
+    CurrentUser(login_user): CurrentUser,
+    Query(condition): Query<DeptSearchCondition>,
+) -> Result<AjaxResult<Vec<TreeSelect>>> {
+    Ok(AjaxResult::success(SysDeptService::select_dept_tree(&login_user, &condition).await?))
+}
+
+/// 角色部门树及已选中的部门
+pub async fn role_dept_treeselect(
+    CurrentUser(login_user): CurrentUser,
+    Path(role_id): Path<Uuid>,
+) -> Result<AjaxResult<RoleDeptTreeResponse>> {
+    Ok(AjaxResult::success(SysDeptService::select_role_dept_tree(&login_user, &role_id).await?))
+}
+
+/// 部门详情
+pub async fn get_info(CurrentUser(login_user): CurrentUser, Path(dept_id): Path<Uuid>) -> Result<AjaxResult<SysDept>> {
+    Ok(AjaxResult::success(SysDeptService::select_dept_by_id(&login_user, &dept_id).await?))
+}
+
+/// 新增部门
+pub async fn add(CurrentUser(login_user): CurrentUser, Json(request): Json<DeptRequest>) -> Result<AjaxResult<Uuid>> {
+    Ok(AjaxResult::success(SysDeptService::insert_dept(&login_user, &request).await?))
+}
+
+/// 修改部门
+pub async fn edit(CurrentUser(login_user): CurrentUser, Json(request): Json<DeptRequest>) -> Result<AjaxResult<()>> {
+    SysDeptService::update_dept(&login_user, &request).await?;
+    Ok(AjaxResult::success_msg("操作成功"))
+}
+
+/// 删除部门
+pub async fn remove(CurrentUser(login_user): CurrentUser, Path(dept_id): Path<Uuid>) -> Result<AjaxResult<()>> {
+    SysDeptService::delete_dept_by_id(&login_user, &dept_id).await?;
+    Ok(AjaxResult::success_msg("操作成功"))
+}
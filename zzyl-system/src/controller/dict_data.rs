@@ -0,0 +1,45 @@
+use axum::{extract::{Path, Query}, Json};
+use uuid::Uuid;
+use zzyl_common::error::{AjaxResult, Result, TableDataInfo};
+use zzyl_common::{DictData, PageDomain};
+use zzyl_framework::security::CurrentUser;
+use crate::domain::{DictDataRequest, DictDataSearchCondition, SysDictData};
+use zzyl_framework::web::parse_ids;
+use crate::service::{SysDictDataService, SysDictTypeService};
+
+/// 字典数据列表
+pub async fn list(
+    Query(condition): Query<DictDataSearchCondition>,
+    Query(page): Query<PageDomain>,
+) -> Result<TableDataInfo<SysDictData>> {
+    let page = SysDictDataService::select_dict_data_list(&condition, &page).await?;
+    Ok(TableDataInfo::success(page.list, page.total))
+}
+
+/// 根据字典类型查询字典数据（读取缓存）
+pub async fn dict_type(Path(dict_type): Path<String>) -> Result<AjaxResult<Vec<DictData>>> {
+    Ok(AjaxResult::success(SysDictTypeService::select_dict_data_by_type(&dict_type).await?))
+}
+
+/// 字典数据详情
+pub async fn get_info(Path(dict_code): Path<Uuid>) -> Result<AjaxResult<SysDictData>> {
+    Ok(AjaxResult::success(SysDictDataService::select_dict_data_by_code(&dict_code).await?))
+}
+
+/// 新增字典数据
+pub async fn add(CurrentUser(login_user): CurrentUser, Json(request): Json<DictDataRequest>) -> Result<AjaxResult<Uuid>> {
+    Ok(AjaxResult::success(SysDictDataService::insert_dict_data(&login_user, &request).await?))
+}
+
+/// 修改字典数据
+pub async fn edit(CurrentUser(login_user): CurrentUser, Json(request): Json<DictDataRequest>) -> Result<AjaxResult<()>> {
+    SysDictDataService::update_dict_data(&login_user, &request).await?;
+    Ok(AjaxResult::success_msg("操作成功"))
+}
+
+/// 删除字典数据，多个编码以逗号分隔
+pub async fn remove(Path(dict_codes): Path<String>) -> Result<AjaxResult<()>> {
+    let dict_codes = parse_ids(&dict_codes)?;
+    SysDictDataService::delete_dict_data_by_codes(&dict_codes).await?;
+    Ok(AjaxResult::success_msg("操作成功"))
+}
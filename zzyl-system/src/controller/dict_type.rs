@@ -0,0 +1,51 @@
+use axum::{extract::{Path, Query}, Json};
+use uuid::Uuid;
+use zzyl_common::error::{AjaxResult, Result, TableDataInfo};
+use zzyl_common::PageDomain;
+use zzyl_framework::security::CurrentUser;
+use crate::domain::{DictTypeRequest, DictTypeSearchCondition, SysDictType};
+use zzyl_framework::web::parse_ids;
+use crate::service::SysDictTypeService;
+
+/// 字典类型列表
+pub async fn list(
+    Query(condition): Query<DictTypeSearchCondition>,
+    Query(page): Query<PageDomain>,
+) -> Result<TableDataInfo<SysDictType>> {
+    let page = SysDictTypeService::select_dict_type_list(&condition, &page).await?;
+    Ok(TableDataInfo::success(page.list, page.total))
+}
+
+/// 字典类型选择框列表
+pub async fn option_select() -> Result<AjaxResult<Vec<SysDictType>>> {
+    Ok(AjaxResult::success(SysDictTypeService::select_dict_type_all().await?))
+}
+
+/// 字典类型详情
+pub async fn get_info(Path(dict_id): Path<Uuid>) -> Result<AjaxResult<SysDictType>> {
+    Ok(AjaxResult::success(SysDictTypeService::select_dict_type_by_id(&dict_id).await?))
+}
+
+/// 新增字典类型
+pub async fn add(CurrentUser(login_user): CurrentUser, Json(request): Json<DictTypeRequest>) -> Result<AjaxResult<Uuid>> {
+    Ok(AjaxResult::success(SysDictTypeService::insert_dict_type(&login_user, &request).await?))
+}
+
+/// 修改字典类型
+pub async fn edit(CurrentUser(login_user): CurrentUser, Json(request): Json<DictTypeRequest>) -> Result<AjaxResult<()>> {
+    SysDictTypeService::update_dict_type(&login_user, &request).await?;
+    Ok(AjaxResult::success_msg("操作成功"))
+}
+
+/// 删除字典类型，多个ID以逗号分隔
+pub async fn remove(Path(dict_ids): Path<String>) -> Result<AjaxResult<()>> {
+    let dict_ids = parse_ids(&dict_ids)?;
+    SysDictTypeService::delete_dict_type_by_ids(&dict_ids).await?;
+    Ok(AjaxResult::success_msg("操作成功"))
+}
+
+/// 刷新字典缓存
+pub async fn refresh_cache() -> Result<AjaxResult<()>> {
+    SysDictTypeService::reset_dict_cache().await?;
+    Ok(AjaxResult::success_msg("操作成功"))
+}
@@ -0,0 +1,34 @@
+use axum::{http::HeaderMap, Json};
+use zzyl_common::constants::constants::LOGOUT;
+use zzyl_common::error::{AjaxResult, Result};
+use zzyl_framework::security::{CurrentUser, TokenService};
+use zzyl_framework::web::ClientInfo;
+use crate::domain::{LoginRequest, LoginResponse, UserInfoResponse};
+use crate::service::SysLoginService;
+
+/// 登录
+pub async fn login(client: ClientInfo, Json(request): Json<LoginRequest>) -> Result<AjaxResult<LoginResponse>> {
+    Ok(AjaxResult::success(SysLoginService::login(&request, &client).await?))
+}
+
+/// 退出登录
+pub async fn logout(client: ClientInfo, headers: HeaderMap) -> Result<AjaxResult<()>> {
+    let token = TokenService::get_token(&headers);
+    SysLoginService::logout(token.as_deref(), &client).await?;
+    Ok(AjaxResult::success_msg(LOGOUT))
+}
+
+/// 刷新令牌
+pub async fn refresh(
+    CurrentUser(login_user): CurrentUser,
+    client: ClientInfo,
+    headers: HeaderMap,
+) -> Result<AjaxResult<LoginResponse>> {
+    let token = TokenService::get_token(&headers);
+    Ok(AjaxResult::success(SysLoginService::refresh(&login_user, token.as_deref(), &client).await?))
+}
+
+/// 获取用户信息
+pub async fn get_info(CurrentUser(login_user): CurrentUser) -> Result<AjaxResult<UserInfoResponse>> {
+    Ok(AjaxResult::success(SysLoginService::get_info(&login_user).await?))
+}
@@ -0,0 +1,46 @@
+use axum::{extract::{Path, Query}, response::Response};
+use uuid::Uuid;
+use zzyl_common::error::{AjaxResult, Result, TableDataInfo};
+use zzyl_common::PageDomain;
+use crate::domain::{LoginInfoSearchCondition, SysLoginInfo};
+use zzyl_framework::web::{excel_response, parse_ids};
+use crate::service::SysLoginInfoService;
+
+/// 登录日志列表
+pub async fn list(
+    Query(condition): Query<LoginInfoSearchCondition>,
+    Query(page): Query<PageDomain>,
+) -> Result<TableDataInfo<SysLoginInfo>> {
+    let page = SysLoginInfoService::select_login_info_list(&condition, &page).await?;
+    Ok(TableDataInfo::success(page.list, page.total))
+}
+
+/// 登录日志详情
+pub async fn get_info(Path(info_id): Path<Uuid>) -> Result<AjaxResult<SysLoginInfo>> {
+    Ok(AjaxResult::success(SysLoginInfoService::select_login_info_by_id(&info_id).await?))
+}
+
+/// 删除登录日志，多个ID以逗号分隔
+pub async fn remove(Path(info_ids): Path<String>) -> Result<AjaxResult<()>> {
+    let info_ids = parse_ids(&info_ids)?;
+    SysLoginInfoService::delete_login_info_by_ids(&info_ids).await?;
+    Ok(AjaxResult::success_msg("操作成功"))
+}
+
+/// 清空登录日志
+pub async fn clean() -> Result<AjaxResult<()>> {
+    SysLoginInfoService::clean_login_info().await?;
+    Ok(AjaxResult::success_msg("操作成功"))
+}
+
+/// 解锁账户
+pub async fn unlock(Path(username): Path<String>) -> Result<AjaxResult<()>> {
+    SysLoginInfoService::unlock(&username).await?;
+    Ok(AjaxResult::success_msg("操作成功"))
+}
+
+/// 导出登录日志
+pub async fn export(Query(condition): Query<LoginInfoSearchCondition>) -> Result<Response> {
+    let rows = SysLoginInfoService::export_login_info(&condition).await?;
+    excel_response("logininfor", &rows)
+}
@@ -0,0 +1,24 @@
+use axum::extract::Path;
+use uuid::Uuid;
+use zzyl_common::error::{AjaxResult, Result};
+use zzyl_framework::security::CurrentUser;
+use crate::domain::{RoleMenuTreeResponse, RouterVo, TreeSelect};
+use crate::service::SysMenuService;
+
+/// 获取当前用户的前端路由
+pub async fn get_routers(CurrentUser(login_user): CurrentUser) -> Result<AjaxResult<Vec<RouterVo>>> {
+    Ok(AjaxResult::success(SysMenuService::select_routers(&login_user).await?))
+}
+
+/// 菜单下拉树
+pub async fn treeselect(CurrentUser(login_user): CurrentUser) -> Result<AjaxResult<Vec<TreeSelect>>> {
+    Ok(AjaxResult::success(SysMenuService::select_menu_tree_select(&login_user).await?))
+}
+
+/// 角色菜单树及已选中的菜单
+pub async fn role_menu_treeselect(
+    CurrentUser(login_user): CurrentUser,
+    Path(role_id): Path<Uuid>,
+) -> Result<AjaxResult<RoleMenuTreeResponse>> {
+    Ok(AjaxResult::success(SysMenuService::select_role_menu_tree(&login_user, &role_id).await?))
+}
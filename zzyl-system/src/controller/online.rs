@@ -0,0 +1,20 @@
+use axum::extract::{Path, Query};
+use zzyl_common::error::{AjaxResult, Result, TableDataInfo};
+use zzyl_common::PageDomain;
+use crate::domain::{OnlineSearchCondition, SysUserOnline};
+use crate::service::SysUserOnlineService;
+
+/// 在线用户列表
+pub async fn list(
+    Query(condition): Query<OnlineSearchCondition>,
+    Query(page): Query<PageDomain>,
+) -> Result<TableDataInfo<SysUserOnline>> {
+    let page = SysUserOnlineService::select_online_list(&condition, &page).await?;
+    Ok(TableDataInfo::success(page.list, page.total))
+}
+
+/// 强退用户
+pub async fn force_logout(Path(token_id): Path<String>) -> Result<AjaxResult<()>> {
+    SysUserOnlineService::force_logout(&token_id).await?;
+    Ok(AjaxResult::success_msg("操作成功"))
+}
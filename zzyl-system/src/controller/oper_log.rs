@@ -0,0 +1,40 @@
+use axum::{extract::{Path, Query}, response::Response};
+use uuid::Uuid;
+use zzyl_common::error::{AjaxResult, Result, TableDataInfo};
+use zzyl_common::PageDomain;
+use crate::domain::{OperLogSearchCondition, SysOperLog};
+use zzyl_framework::web::{excel_response, parse_ids};
+use crate::service::SysOperLogService;
+
+/// 操作日志列表
+pub async fn list(
+    Query(condition): Query<OperLogSearchCondition>,
+    Query(page): Query<PageDomain>,
+) -> Result<TableDataInfo<SysOperLog>> {
+    let page = SysOperLogService::select_oper_log_list(&condition, &page).await?;
+    Ok(TableDataInfo::success(page.list, page.total))
+}
+
+/// 操作日志详情
+pub async fn get_info(Path(oper_id): Path<Uuid>) -> Result<AjaxResult<SysOperLog>> {
+    Ok(AjaxResult::success(SysOperLogService::select_oper_log_by_id(&oper_id).await?))
+}
+
+/// 删除操作日志，多个ID以逗号分隔
+pub async fn remove(Path(oper_ids): Path<String>) -> Result<AjaxResult<()>> {
+    let oper_ids = parse_ids(&oper_ids)?;
+    SysOperLogService::delete_oper_log_by_ids(&oper_ids).await?;
+    Ok(AjaxResult::success_msg("操作成功"))
+}
+
+/// 清空操作日志
+pub async fn clean() -> Result<AjaxResult<()>> {
+    SysOperLogService::clean_oper_log().await?;
+    Ok(AjaxResult::success_msg("操作成功"))
+}
+
+/// 导出操作日志
+pub async fn export(Query(condition): Query<OperLogSearchCondition>) -> Result<Response> {
+    let rows = SysOperLogService::export_oper_log(&condition).await?;
+    excel_response("operlog", &rows)
+}
@@ -0,0 +1,51 @@
+use axum::{extract::{Path, Query}, response::Response, Json};
+use uuid::Uuid;
+use zzyl_common::error::{AjaxResult, Result, TableDataInfo};
+use zzyl_common::PageDomain;
+use zzyl_framework::security::CurrentUser;
+use crate::domain::{PostRequest, PostSearchCondition, SysPost};
+use zzyl_framework::web::{excel_response, parse_ids};
+use crate::service::SysPostService;
+
+/// 岗位列表
+pub async fn list(
+    Query(condition): Query<PostSearchCondition>,
+    Query(page): Query<PageDomain>,
+) -> Result<TableDataInfo<SysPost>> {
+    let page = SysPostService::select_post_list(&condition, &page).await?;
+    Ok(TableDataInfo::success(page.list, page.total))
+}
+
+/// 岗位下拉选项
+pub async fn option_select() -> Result<AjaxResult<Vec<SysPost>>> {
+    Ok(AjaxResult::success(SysPostService::select_post_all().await?))
+}
+
+/// 岗位详情
+pub async fn get_info(Path(post_id): Path<Uuid>) -> Result<AjaxResult<SysPost>> {
+    Ok(AjaxResult::success(SysPostService::select_post_by_id(&post_id).await?))
+}
+
+/// 新增岗位
+pub async fn add(CurrentUser(login_user): CurrentUser, Json(request): Json<PostRequest>) -> Result<AjaxResult<Uuid>> {
+    Ok(AjaxResult::success(SysPostService::insert_post(&login_user, &request).await?))
+}
+
+/// 修改岗位
+pub async fn edit(CurrentUser(login_user): CurrentUser, Json(request): Json<PostRequest>) -> Result<AjaxResult<()>> {
+    SysPostService::update_post(&login_user, &request).await?;
+    Ok(AjaxResult::success_msg("操作成功"))
+}
+
+/// 删除岗位，多个ID以逗号分隔
+pub async fn remove(Path(post_ids): Path<String>) -> Result<AjaxResult<()>> {
+    let post_ids = parse_ids(&post_ids)?;
+    SysPostService::delete_post_by_ids(&post_ids).await?;
+    Ok(AjaxResult::success_msg("操作成功"))
+}
+
+/// 导出岗位
+pub async fn export(Query(condition): Query<PostSearchCondition>) -> Result<Response> {
+    let rows = SysPostService::export_posts(&condition).await?;
+    excel_response("post", &rows)
+}
@@ -0,0 +1,47 @@
+use axum::{extract::Multipart, Json};
+use zzyl_common::error::{AjaxResult, Result, ZzylError};
+use zzyl_framework::security::CurrentUser;
+use crate::domain::{AvatarResponse, ChangePasswordRequest, ProfileRequest, ProfileResponse};
+use crate::service::SysUserService;
+
+/// 头像上传表单字段名
+const AVATAR_FIELD: &str = "avatarfile";
+
+/// 个人信息
+pub async fn profile(CurrentUser(login_user): CurrentUser) -> Result<AjaxResult<ProfileResponse>> {
+    Ok(AjaxResult::success(SysUserService::select_profile(&login_user).await?))
+}
+
+/// 修改个人信息
+pub async fn update_profile(
+    CurrentUser(login_user): CurrentUser,
+    Json(request): Json<ProfileRequest>,
+) -> Result<AjaxResult<()>> {
+    SysUserService::update_profile(&login_user, &request).await?;
+    Ok(AjaxResult::success_msg("操作成功"))
+}
+
+/// 修改个人密码
+pub async fn update_pwd(
+    CurrentUser(login_user): CurrentUser,
+    Json(request): Json<ChangePasswordRequest>,
+) -> Result<AjaxResult<()>> {
+    SysUserService::update_password(&login_user, &request).await?;
+    Ok(AjaxResult::success_msg("操作成功"))
+}
+
+/// 上传个人头像
+pub async fn avatar(CurrentUser(login_user): CurrentUser, mut multipart: Multipart) -> Result<AjaxResult<AvatarResponse>> {
+    while let Some(field) = multipart.next_field().await.map_err(|e| ZzylError::InvalidParameter(e.to_string()))? {
+        if field.name() != Some(AVATAR_FIELD) {
+            continue;
+        }
+        let file_name = field.file_name().unwrap_or_default().to_string();
+        let content_type = field.content_type().map(str::to_string);
+        let bytes = field.bytes().await.map_err(|e| ZzylError::InvalidParameter(e.to_string()))?;
+        let img_url =
+            SysUserService::update_avatar(&login_user, &file_name, content_type.as_deref(), bytes.to_vec()).await?;
+        return Ok(AjaxResult::success(AvatarResponse { img_url }));
+    }
+    Err(ZzylError::InvalidParameter("上传图片不能为空".to_string()))
+}
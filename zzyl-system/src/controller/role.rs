@@ -0,0 +1,106 @@
+use axum::{extract::{Path, Query}, Json};
+use uuid::Uuid;
+use zzyl_common::error::{AjaxResult, Result, TableDataInfo};
+use zzyl_common::PageDomain;
+use zzyl_framework::security::CurrentUser;
+use crate::domain::{
+    ChangeRoleStatusRequest, RoleDataScopeRequest, RoleDetailResponse, RoleRequest, RoleSearchCondition,
+    RoleUserQuery, RoleUsersRequest, SysRole, SysUser, UserSearchCondition,
+};
+use zzyl_framework::web::parse_ids;
+use crate::service::SysRoleService;
+
+/// 角色列表
+pub async fn list(
+    Query(condition): Query<RoleSearchCondition>,
+    Query(page): Query<PageDomain>,
+) -> Result<TableDataInfo<SysRole>> {
+    let page = SysRoleService::select_role_list(&condition, &page).await?;
+    Ok(TableDataInfo::success(page.list, page.total))
+}
+
+/// 角色选择框列表
+pub async fn option_select() -> Result<AjaxResult<Vec<SysRole>>> {
+    Ok(AjaxResult::success(SysRoleService::select_role_all().await?))
+}
+
+/// 角色详情
+pub async fn get_info(Path(role_id): Path<Uuid>) -> Result<AjaxResult<RoleDetailResponse>> {
+    Ok(AjaxResult::success(SysRoleService::select_role_detail(&role_id).await?))
+}
+
+/// 新增角色
+pub async fn add(CurrentUser(login_user): CurrentUser, Json(request): Json<RoleRequest>) -> Result<AjaxResult<Uuid>> {
+    Ok(AjaxResult::success(SysRoleService::insert_role(&login_user, &request).await?))
+}
+
+/// 修改角色
+pub async fn edit(CurrentUser(login_user): CurrentUser, Json(request): Json<RoleRequest>) -> Result<AjaxResult<()>> {
+    SysRoleService::update_role(&login_user, &request).await?;
+    Ok(AjaxResult::success_msg("操作成功"))
+}
+
+/// 修改数据权限
+pub async fn data_scope(
+    CurrentUser(login_user): CurrentUser,
+    Json(request): Json<RoleDataScopeRequest>,
+) -> Result<AjaxResult<()>> {
+    SysRoleService::auth_data_scope(&login_user, &request).await?;
+    Ok(AjaxResult::success_msg("操作成功"))
+}
+
+/// 修改角色状态
+pub async fn change_status(
+    CurrentUser(login_user): CurrentUser,
+    Json(request): Json<ChangeRoleStatusRequest>,
+) -> Result<AjaxResult<()>> {
+    SysRoleService::change_status(&login_user, &request).await?;
+    Ok(AjaxResult::success_msg("操作成功"))
+}
+
+/// 删除角色，多个ID以逗号分隔
+pub async fn remove(CurrentUser(login_user): CurrentUser, Path(role_ids): Path<String>) -> Result<AjaxResult<()>> {
+    let role_ids = parse_ids(&role_ids)?;
+    SysRoleService::delete_role_by_ids(&login_user, &role_ids).await?;
+    Ok(AjaxResult::success_msg("操作成功"))
+}
+
+/// 已分配该角色的用户列表
+pub async fn allocated_list(
+    CurrentUser(login_user): CurrentUser,
+    Query(query): Query<RoleUserQuery>,
+    Query(condition): Query<UserSearchCondition>,
+    Query(page): Query<PageDomain>,
+) -> Result<TableDataInfo<SysUser>> {
+    let page = SysRoleService::select_allocated_list(&login_user, &query.role_id, &condition, &page).await?;
+    Ok(TableDataInfo::success(page.list, page.total))
+}
+
+/// 未分配该角色的用户列表
+pub async fn unallocated_list(
+    CurrentUser(login_user): CurrentUser,
+    Query(query): Query<RoleUserQuery>,
+    Query(condition): Query<UserSearchCondition>,
+    Query(page): Query<PageDomain>,
+) -> Result<TableDataInfo<SysUser>> {
+    let page = SysRoleService::select_unallocated_list(&login_user, &query.role_id, &condition, &page).await?;
+    Ok(TableDataInfo::success(page.list, page.total))
+}
+
+/// 批量授权用户
+pub async fn select_all(
+    CurrentUser(login_user): CurrentUser,
+    Json(request): Json<RoleUsersRequest>,
+) -> Result<AjaxResult<()>> {
+    SysRoleService::grant_users(&login_user, &request).await?;
+    Ok(AjaxResult::success_msg("操作成功"))
+}
+
+/// 批量取消用户授权
+pub async fn cancel_all(
+    CurrentUser(login_user): CurrentUser,
+    Json(request): Json<RoleUsersRequest>,
+) -> Result<AjaxResult<()>> {
+    SysRoleService::revoke_users(&login_user, &request).await?;
+    Ok(AjaxResult::success_msg("操作成功"))
+}
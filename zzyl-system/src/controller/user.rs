@@ -0,0 +1,99 @@
+use axum::{extract::{Multipart, Path, Query}, response::Response, Json};
+use uuid::Uuid;
+use zzyl_common::error::{AjaxResult, Result, TableDataInfo, ZzylError};
+use zzyl_common::PageDomain;
+use zzyl_framework::security::CurrentUser;
+use crate::domain::{
+    AuthRoleRequest, ChangeUserStatusRequest, ResetPasswordRequest, SysUser, UserDetailResponse, UserImportQuery,
+    UserImportResult, UserRequest, UserSearchCondition,
+};
+use zzyl_framework::web::{excel_response, parse_ids};
+use crate::service::SysUserService;
+
+/// 用户导入文件表单字段名
+const IMPORT_FILE_FIELD: &str = "file";
+
+/// 用户列表
+pub async fn list(
+    CurrentUser(login_user): CurrentUser,
+    Query(condition): Query<UserSearchCondition>,
+    Query(page): Query<PageDomain>,
+) -> Result<TableDataInfo<SysUser>> {
+    let page = SysUserService::select_user_list(&login_user, &condition, &page).await?;
+    Ok(TableDataInfo::success(page.list, page.total))
+}
+
+/// 用户详情
+pub async fn get_info(
+    CurrentUser(login_user): CurrentUser,
+    Path(user_id): Path<Uuid>,
+) -> Result<AjaxResult<UserDetailResponse>> {
+    Ok(AjaxResult::success(SysUserService::select_user_detail(&login_user, &user_id).await?))
+}
+
+/// 新增用户
+pub async fn add(CurrentUser(login_user): CurrentUser, Json(request): Json<UserRequest>) -> Result<AjaxResult<Uuid>> {
+    Ok(AjaxResult::success(SysUserService::insert_user(&login_user, &request).await?))
+}
+
+/// 修改用户
+pub async fn edit(CurrentUser(login_user): CurrentUser, Json(request): Json<UserRequest>) -> Result<AjaxResult<()>> {
+    SysUserService::update_user(&login_user, &request).await?;
+    Ok(AjaxResult::success_msg("操作成功"))
+}
+
+/// 删除用户，多个ID以逗号分隔
+pub async fn remove(CurrentUser(login_user): CurrentUser, Path(user_ids): Path<String>) -> Result<AjaxResult<()>> {
+    let user_ids = parse_ids(&user_ids)?;
+    SysUserService::delete_user_by_ids(&login_user, &user_ids).await?;
+    Ok(AjaxResult::success_msg("操作成功"))
+}
+
+/// 重置密码
+pub async fn reset_pwd(
+    CurrentUser(login_user): CurrentUser,
+    Json(request): Json<ResetPasswordRequest>,
+) -> Result<AjaxResult<()>> {
+    SysUserService::reset_password(&login_user, &request).await?;
+    Ok(AjaxResult::success_msg("操作成功"))
+}
+
+/// 修改用户状态
+pub async fn change_status(
+    CurrentUser(login_user): CurrentUser,
+    Json(request): Json<ChangeUserStatusRequest>,
+) -> Result<AjaxResult<()>> {
+    SysUserService::change_status(&login_user, &request).await?;
+    Ok(AjaxResult::success_msg("操作成功"))
+}
+
+/// 用户授权角色
+pub async fn auth_role(
+    CurrentUser(login_user): CurrentUser,
+    Json(request): Json<AuthRoleRequest>,
+) -> Result<AjaxResult<()>> {
+    SysUserService::auth_role(&login_user, &request).await?;
+    Ok(AjaxResult::success_msg("操作成功"))
+}
+
+/// 下载用户导入模板
+pub async fn import_template() -> Result<Response> {
+    excel_response("user_template", &SysUserService::import_template())
+}
+
+/// 从Excel导入用户
+pub async fn import_data(
+    CurrentUser(login_user): CurrentUser,
+    Query(query): Query<UserImportQuery>,
+    mut multipart: Multipart,
+) -> Result<AjaxResult<UserImportResult>> {
+    while let Some(field) = multipart.next_field().await.map_err(|e| ZzylError::InvalidParameter(e.to_string()))? {
+        if field.name() != Some(IMPORT_FILE_FIELD) {
+            continue;
+        }
+        let bytes = field.bytes().await.map_err(|e| ZzylError::InvalidParameter(e.to_string()))?;
+        let result = SysUserService::import_users(&login_user, &bytes, query.update_support).await?;
+        return Ok(AjaxResult::success(result));
+    }
+    Err(ZzylError::InvalidParameter("导入文件不能为空".to_string()))
+}
@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use chrono::{DateTime, Utc};
-use zzyl_common::{BaseEntity, enums::{UserStatus, DelFlag}};
+use zzyl_common::{BaseEntity, Dept, DictData, Role, enums::{UserStatus, DelFlag}};
 
 /// 用户实体
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -20,9 +20,11 @@ pub struct SysUser {
     pub sex: Option<String>,
     /// 用户头像
     pub avatar: Option<String>,
-    /// 密码
+    /// 密码（不返回给前端）
+    #[serde(default, skip_serializing)]
     pub password: String,
     /// 盐加密
+    #[serde(default, skip_serializing)]
     pub salt: Option<String>,
     /// 帐号状态（0正常 1停用）
     pub status: UserStatus,
@@ -242,6 +244,8 @@ pub struct SysOperLog {
     pub error_msg: Option<String>,
     /// 操作时间
     pub oper_time: Option<DateTime<Utc>>,
+    /// 消耗时间（毫秒）
+    pub cost_time: Option<i64>,
 }
 
 /// 登录日志实体
@@ -331,9 +335,11 @@ pub struct LoginRequest {
     pub username: String,
     /// 密码
     pub password: String,
-    /// 验证码
+    /// 验证码（未开启验证码时可为空）
+    #[serde(default)]
     pub captcha: String,
     /// 验证码ID
+    #[serde(default)]
     pub uuid: String,
     /// 记住我
     pub remember_me: Option<bool>,
@@ -374,6 +380,410 @@ pub struct ChangePasswordRequest {
     pub new_password: String,
 }
 
+/// 修改个人信息请求
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProfileRequest {
+    /// 昵称
+    pub nick_name: Option<String>,
+    /// 手机号
+    pub phone: Option<String>,
+    /// 邮箱
+    pub email: Option<String>,
+    /// 性别
+    pub sex: Option<String>,
+}
+
+/// 个人信息响应
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProfileResponse {
+    /// 用户信息
+    pub user: SysUser,
+    /// 所属角色组
+    pub role_group: String,
+    /// 所属岗位组
+    pub post_group: String,
+}
+
+/// 头像上传响应
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AvatarResponse {
+    /// 头像地址
+    pub img_url: String,
+}
+
+/// 新增/修改用户请求
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserRequest {
+    /// 用户ID（修改时必填）
+    pub user_id: Option<Uuid>,
+    /// 部门ID
+    pub dept_id: Option<Uuid>,
+    /// 用户账号
+    pub username: String,
+    /// 用户昵称
+    pub nick_name: Option<String>,
+    /// 用户邮箱
+    pub email: Option<String>,
+    /// 手机号码
+    pub phone: Option<String>,
+    /// 用户性别
+    pub sex: Option<String>,
+    /// 密码（新增时必填）
+    pub password: Option<String>,
+    /// 帐号状态
+    pub status: Option<UserStatus>,
+    /// 备注
+    pub remark: Option<String>,
+    /// 角色ID列表
+    #[serde(default)]
+    pub role_ids: Vec<Uuid>,
+    /// 岗位ID列表
+    #[serde(default)]
+    pub post_ids: Vec<Uuid>,
+}
+
+/// 用户导入行，字段名与导入模板表头一致
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserImportRow {
+    /// 登录账号
+    #[serde(rename = "登录账号", default)]
+    pub username: String,
+    /// 用户昵称
+    #[serde(rename = "用户昵称", default)]
+    pub nick_name: String,
+    /// 部门名称
+    #[serde(rename = "部门名称", default)]
+    pub dept_name: String,
+    /// 手机号码
+    #[serde(rename = "手机号码", default)]
+    pub phone: String,
+    /// 用户邮箱
+    #[serde(rename = "用户邮箱", default)]
+    pub email: String,
+    /// 用户性别（字典标签）
+    #[serde(rename = "用户性别", default)]
+    pub sex: String,
+    /// 帐号状态（字典标签）
+    #[serde(rename = "帐号状态", default)]
+    pub status: String,
+}
+
+/// 用户导入参数
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserImportQuery {
+    /// 是否更新已存在的用户
+    #[serde(default)]
+    pub update_support: bool,
+}
+
+/// 用户导入单行结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserImportRowResult {
+    /// Excel行号（含表头）
+    pub row_num: usize,
+    /// 登录账号
+    pub username: String,
+    /// 是否成功
+    pub success: bool,
+    /// 结果说明
+    pub message: String,
+}
+
+/// 用户导入结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserImportResult {
+    /// 成功数量
+    pub success_count: usize,
+    /// 失败数量
+    pub failure_count: usize,
+    /// 逐行结果
+    pub rows: Vec<UserImportRowResult>,
+}
+
+/// 用户详情响应
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserDetailResponse {
+    /// 用户信息
+    pub user: SysUser,
+    /// 已分配的角色ID
+    pub role_ids: Vec<Uuid>,
+    /// 已分配的岗位ID
+    pub post_ids: Vec<Uuid>,
+}
+
+/// 重置密码请求
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResetPasswordRequest {
+    /// 用户ID
+    pub user_id: Uuid,
+    /// 新密码
+    pub password: String,
+}
+
+/// 修改用户状态请求
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChangeUserStatusRequest {
+    /// 用户ID
+    pub user_id: Uuid,
+    /// 帐号状态
+    pub status: UserStatus,
+}
+
+/// 用户授权角色请求
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuthRoleRequest {
+    /// 用户ID
+    pub user_id: Uuid,
+    /// 角色ID列表
+    #[serde(default)]
+    pub role_ids: Vec<Uuid>,
+}
+
+/// 新增/修改部门请求
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeptRequest {
+    /// 部门ID（修改时必填）
+    pub dept_id: Option<Uuid>,
+    /// 父部门ID，为空表示顶级部门
+    pub parent_id: Option<Uuid>,
+    /// 部门名称
+    pub dept_name: String,
+    /// 显示顺序
+    pub order_num: i32,
+    /// 负责人
+    pub leader: Option<String>,
+    /// 联系电话
+    pub phone: Option<String>,
+    /// 邮箱
+    pub email: Option<String>,
+    /// 部门状态（0正常 1停用）
+    pub status: Option<String>,
+    /// 备注
+    pub remark: Option<String>,
+}
+
+/// 新增/修改字典类型请求
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DictTypeRequest {
+    /// 字典主键（修改时必填）
+    pub dict_id: Option<Uuid>,
+    /// 字典名称
+    pub dict_name: String,
+    /// 字典类型
+    pub dict_type: String,
+    /// 状态（0正常 1停用）
+    pub status: Option<String>,
+    /// 备注
+    pub remark: Option<String>,
+}
+
+/// 新增/修改字典数据请求
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DictDataRequest {
+    /// 字典编码（修改时必填）
+    pub dict_code: Option<Uuid>,
+    /// 字典排序
+    #[serde(default)]
+    pub dict_sort: i32,
+    /// 字典标签
+    pub dict_label: String,
+    /// 字典键值
+    pub dict_value: String,
+    /// 字典类型
+    pub dict_type: String,
+    /// 样式属性
+    pub css_class: Option<String>,
+    /// 表格字典样式
+    pub list_class: Option<String>,
+    /// 是否默认（Y是 N否）
+    pub is_default: Option<String>,
+    /// 状态（0正常 1停用）
+    pub status: Option<String>,
+    /// 备注
+    pub remark: Option<String>,
+}
+
+/// 新增/修改参数配置请求
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfigRequest {
+    /// 参数主键（修改时必填）
+    pub config_id: Option<Uuid>,
+    /// 参数名称
+    pub config_name: String,
+    /// 参数键名
+    pub config_key: String,
+    /// 参数键值
+    pub config_value: String,
+    /// 系统内置（Y是 N否）
+    pub config_type: Option<String>,
+    /// 备注
+    pub remark: Option<String>,
+}
+
+/// 新增/修改岗位请求
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PostRequest {
+    /// 岗位ID（修改时必填）
+    pub post_id: Option<Uuid>,
+    /// 岗位编码
+    pub post_code: String,
+    /// 岗位名称
+    pub post_name: String,
+    /// 岗位排序
+    pub post_sort: i32,
+    /// 状态（0正常 1停用）
+    pub status: Option<String>,
+    /// 备注
+    pub remark: Option<String>,
+}
+
+/// 新增/修改角色请求
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoleRequest {
+    /// 角色ID（修改时必填）
+    pub role_id: Option<Uuid>,
+    /// 角色名称
+    pub role_name: String,
+    /// 角色权限
+    pub role_key: String,
+    /// 角色排序
+    pub role_sort: i32,
+    /// 菜单树选择项是否关联显示
+    #[serde(default = "default_true")]
+    pub menu_check_strictly: bool,
+    /// 部门树选择项是否关联显示
+    #[serde(default = "default_true")]
+    pub dept_check_strictly: bool,
+    /// 角色状态（0正常 1停用）
+    pub status: Option<String>,
+    /// 备注
+    pub remark: Option<String>,
+    /// 菜单ID列表
+    #[serde(default)]
+    pub menu_ids: Vec<Uuid>,
+}
+
+/// 角色数据权限请求
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoleDataScopeRequest {
+    /// 角色ID
+    pub role_id: Uuid,
+    /// 数据范围（1全部 2自定义 3本部门 4本部门及以下 5仅本人）
+    pub data_scope: String,
+    /// 部门ID列表（自定义数据权限时有效）
+    #[serde(default)]
+    pub dept_ids: Vec<Uuid>,
+}
+
+/// 修改角色状态请求
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChangeRoleStatusRequest {
+    /// 角色ID
+    pub role_id: Uuid,
+    /// 角色状态（0正常 1停用）
+    pub status: String,
+}
+
+/// 角色批量授权/取消授权用户请求
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoleUsersRequest {
+    /// 角色ID
+    pub role_id: Uuid,
+    /// 用户ID列表
+    pub user_ids: Vec<Uuid>,
+}
+
+/// 角色用户查询参数
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoleUserQuery {
+    /// 角色ID
+    pub role_id: Uuid,
+}
+
+/// 角色详情响应
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoleDetailResponse {
+    /// 角色信息
+    pub role: SysRole,
+    /// 已分配的菜单ID
+    pub menu_ids: Vec<Uuid>,
+    /// 已分配的部门ID
+    pub dept_ids: Vec<Uuid>,
+}
+
+/// 前端路由信息，字段命名与 vue-router 保持一致
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RouterVo {
+    /// 路由名字
+    pub name: String,
+    /// 路由地址
+    pub path: String,
+    /// 是否隐藏路由
+    pub hidden: bool,
+    /// 重定向地址
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redirect: Option<String>,
+    /// 组件地址
+    pub component: String,
+    /// 路由参数
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+    /// 子路由不止一个时自动变为嵌套模式
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub always_show: Option<bool>,
+    /// 路由元信息
+    pub meta: Option<MetaVo>,
+    /// 子路由
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<RouterVo>,
+}
+
+/// 路由元信息
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MetaVo {
+    /// 侧边栏和面包屑显示的名字
+    pub title: String,
+    /// 图标
+    pub icon: Option<String>,
+    /// 是否不缓存
+    pub no_cache: bool,
+    /// 外链地址
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub link: Option<String>,
+}
+
+/// 下拉树节点
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TreeSelect {
+    /// 节点ID
+    pub id: Uuid,
+    /// 节点名称
+    pub label: String,
+    /// 子节点
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<TreeSelect>,
+}
+
+/// 角色部门树响应
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoleDeptTreeResponse {
+    /// 选中的部门ID
+    pub checked_keys: Vec<Uuid>,
+    /// 部门树
+    pub depts: Vec<TreeSelect>,
+}
+
+/// 角色菜单树响应
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoleMenuTreeResponse {
+    /// 选中的菜单ID
+    pub checked_keys: Vec<Uuid>,
+    /// 菜单树
+    pub menus: Vec<TreeSelect>,
+}
+
 /// 用户查询条件
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UserSearchCondition {
@@ -428,3 +838,188 @@ pub struct DeptSearchCondition {
     pub parent_id: Option<Uuid>,
 }
 
+/// 在线用户查询条件
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OnlineSearchCondition {
+    /// 登录IP地址
+    pub ipaddr: Option<String>,
+    /// 用户名称
+    pub user_name: Option<String>,
+}
+
+/// 字典类型查询条件
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DictTypeSearchCondition {
+    /// 字典名称
+    pub dict_name: Option<String>,
+    /// 字典类型
+    pub dict_type: Option<String>,
+    /// 状态
+    pub status: Option<String>,
+}
+
+/// 字典数据查询条件
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DictDataSearchCondition {
+    /// 字典类型
+    pub dict_type: Option<String>,
+    /// 字典标签
+    pub dict_label: Option<String>,
+    /// 状态
+    pub status: Option<String>,
+}
+
+/// 参数配置查询条件
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfigSearchCondition {
+    /// 参数名称
+    pub config_name: Option<String>,
+    /// 参数键名
+    pub config_key: Option<String>,
+    /// 系统内置（Y是 N否）
+    pub config_type: Option<String>,
+}
+
+/// 岗位查询条件
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PostSearchCondition {
+    /// 岗位编码
+    pub post_code: Option<String>,
+    /// 岗位名称
+    pub post_name: Option<String>,
+    /// 状态
+    pub status: Option<String>,
+}
+
+/// 登录日志查询条件
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoginInfoSearchCondition {
+    /// 登录地址
+    pub ipaddr: Option<String>,
+    /// 用户账号
+    pub username: Option<String>,
+    /// 登录状态（0成功 1失败）
+    pub status: Option<String>,
+    /// 开始日期（yyyy-MM-dd）
+    pub begin_time: Option<String>,
+    /// 结束日期（yyyy-MM-dd）
+    pub end_time: Option<String>,
+}
+
+/// 操作日志查询条件
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OperLogSearchCondition {
+    /// 系统模块
+    pub title: Option<String>,
+    /// 操作人员
+    pub oper_name: Option<String>,
+    /// 操作地址
+    pub oper_ip: Option<String>,
+    /// 业务类型
+    pub business_type: Option<i32>,
+    /// 操作状态（0正常 1异常）
+    pub status: Option<i32>,
+    /// 开始日期（yyyy-MM-dd）
+    pub begin_time: Option<String>,
+    /// 结束日期（yyyy-MM-dd）
+    pub end_time: Option<String>,
+}
+
+/// 布尔字段默认值
+fn default_true() -> bool {
+    true
+}
+
+impl From<&SysRole> for Role {
+    fn from(role: &SysRole) -> Self {
+        Self {
+            role_id: role.role_id.unwrap_or_default(),
+            role_name: role.role_name.clone(),
+            role_key: role.role_key.clone(),
+            role_sort: role.role_sort,
+            data_scope: role.data_scope.clone(),
+            menu_check_strictly: role.menu_check_strictly,
+            dept_check_strictly: role.dept_check_strictly,
+            status: role.status.clone(),
+            del_flag: role.del_flag.clone(),
+            remark: role.remark.clone(),
+            flag: None,
+            permissions: Vec::new(),
+            role_menu_ids: Vec::new(),
+            role_dept_ids: Vec::new(),
+        }
+    }
+}
+
+impl From<&SysDept> for Dept {
+    fn from(dept: &SysDept) -> Self {
+        Self {
+            dept_id: dept.dept_id.unwrap_or_default(),
+            parent_id: dept.parent_id,
+            ancestors: dept.ancestors.clone(),
+            dept_name: dept.dept_name.clone(),
+            order_num: dept.order_num,
+            leader: dept.leader.clone(),
+            phone: dept.phone.clone(),
+            email: dept.email.clone(),
+            status: dept.status.clone(),
+            del_flag: dept.del_flag.clone(),
+            parent_name: None,
+            children: Vec::new(),
+        }
+    }
+}
+
+impl From<zzyl_common::SysOperLog> for SysOperLog {
+    fn from(oper_log: zzyl_common::SysOperLog) -> Self {
+        Self {
+            oper_id: Some(oper_log.oper_id),
+            title: oper_log.title,
+            method: oper_log.method,
+            request_method: oper_log.request_method,
+            business_type: oper_log.business_type,
+            oper_name: oper_log.oper_name,
+            dept_name: oper_log.dept_name,
+            oper_url: oper_log.oper_url,
+            oper_ip: oper_log.oper_ip,
+            oper_location: oper_log.oper_location,
+            oper_param: oper_log.oper_param,
+            json_result: oper_log.json_result,
+            status: oper_log.status,
+            error_msg: oper_log.error_msg,
+            oper_time: oper_log.oper_time,
+            cost_time: oper_log.cost_time,
+        }
+    }
+}
+
+impl From<zzyl_common::SysUserOnline> for SysUserOnline {
+    fn from(online: zzyl_common::SysUserOnline) -> Self {
+        Self {
+            token_id: online.token_id,
+            dept_name: online.dept_name,
+            user_name: online.user_name,
+            ipaddr: online.ipaddr,
+            login_location: online.login_location,
+            browser: online.browser,
+            os: online.os,
+            login_time: online.login_time,
+        }
+    }
+}
+
+impl From<&SysDictData> for DictData {
+    fn from(data: &SysDictData) -> Self {
+        Self {
+            dict_code: data.dict_code.unwrap_or_default(),
+            dict_sort: data.dict_sort,
+            dict_label: data.dict_label.clone(),
+            dict_value: data.dict_value.clone(),
+            dict_type: data.dict_type.clone(),
+            css_class: data.css_class.clone(),
+            list_class: data.list_class.clone(),
+            is_default: data.is_default.clone(),
+            status: data.status.clone(),
+        }
+    }
+}
@@ -1,8 +1,8 @@
 pub mod domain;
 pub mod service;
 pub mod repository;
+pub mod controller;
 
 pub use domain::*;
 pub use service::*;
 pub use repository::*;
-
@@ -0,0 +1,29 @@
+mod user;
+mod role;
+mod menu;
+mod dept;
+mod login_info;
+mod oper_log;
+mod user_role;
+mod user_post;
+mod role_menu;
+mod role_dept;
+mod dict_type;
+mod dict_data;
+mod config;
+mod post;
+
+pub use user::*;
+pub use role::*;
+pub use menu::*;
+pub use dept::*;
+pub use login_info::*;
+pub use oper_log::*;
+pub use user_role::*;
+pub use user_post::*;
+pub use role_menu::*;
+pub use role_dept::*;
+pub use dict_type::*;
+pub use dict_data::*;
+pub use config::*;
+pub use post::*;
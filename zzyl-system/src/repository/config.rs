@@ -0,0 +1,161 @@
+use sqlx::{mysql::MySqlRow, Row};
+use uuid::Uuid;
+use zzyl_common::error::Result;
+use zzyl_common::PageDomain;
+use zzyl_framework::database::{require_database_pool, DatabaseUtils};
+use crate::domain::{ConfigSearchCondition, SysConfig};
+
+/// 参数配置表查询列
+pub const CONFIG_COLUMNS: &str = "c.config_id, c.config_name, c.config_key, c.config_value, c.config_type, \
+    c.create_by, c.create_time, c.update_by, c.update_time, c.remark";
+
+/// 参数配置数据访问
+pub struct SysConfigRepository;
+
+impl SysConfigRepository {
+    /// 将查询结果映射为参数配置实体
+    pub fn map_row(row: &MySqlRow) -> Result<SysConfig> {
+        Ok(SysConfig {
+            config_id: DatabaseUtils::get_uuid(row, "config_id")?,
+            config_name: row.try_get("config_name")?,
+            config_key: row.try_get("config_key")?,
+            config_value: row.try_get("config_value")?,
+            config_type: row.try_get("config_type")?,
+            base: DatabaseUtils::get_base_entity(row)?,
+        })
+    }
+
+    /// 根据条件分页查询参数配置，返回当前页数据与总数
+    pub async fn select_config_list(
+        condition: &ConfigSearchCondition,
+        page: &PageDomain,
+    ) -> Result<(Vec<SysConfig>, i64)> {
+        let mut conditions = Vec::new();
+        let mut params = Vec::new();
+        if let Some(config_name) = condition.config_name.as_deref().filter(|value| !value.trim().is_empty()) {
+            conditions.push("c.config_name LIKE ?");
+            params.push(format!("%{}%", config_name.trim()));
+        }
+        if let Some(config_key) = condition.config_key.as_deref().filter(|value| !value.trim().is_empty()) {
+            conditions.push("c.config_key LIKE ?");
+            params.push(format!("%{}%", config_key.trim()));
+        }
+        if let Some(config_type) = condition.config_type.as_deref().filter(|value| !value.trim().is_empty()) {
+            conditions.push("c.config_type = ?");
+            params.push(config_type.trim().to_string());
+        }
+        let where_sql = if conditions.is_empty() {
+            String::new()
+        } else {
+            format!("WHERE {}", conditions.join(" AND "))
+        };
+        let pool = require_database_pool()?;
+
+        let count_sql = format!("SELECT COUNT(*) FROM sys_config c {}", where_sql);
+        let mut count_query = sqlx::query_scalar::<_, i64>(&count_sql);
+        for param in &params {
+            count_query = count_query.bind(param);
+        }
+        let total = count_query.fetch_one(pool).await?;
+
+        let list_sql = format!(
+            "SELECT {} FROM sys_config c {} ORDER BY c.create_time DESC LIMIT ? OFFSET ?",
+            CONFIG_COLUMNS, where_sql
+        );
+        let mut list_query = sqlx::query(&list_sql);
+        for param in &params {
+            list_query = list_query.bind(param);
+        }
+        let rows = list_query
+            .bind(page.page_size() as i64)
+            .bind(page.offset())
+            .fetch_all(pool)
+            .await?;
+        let list = rows.iter().map(Self::map_row).collect::<Result<Vec<_>>>()?;
+
+        Ok((list, total))
+    }
+
+    /// 查询全部参数配置
+    pub async fn select_config_all() -> Result<Vec<SysConfig>> {
+        let sql = format!("SELECT {} FROM sys_config c", CONFIG_COLUMNS);
+        let rows = sqlx::query(&sql)
+            .fetch_all(require_database_pool()?)
+            .await?;
+        rows.iter().map(Self::map_row).collect()
+    }
+
+    /// 通过主键查询参数配置
+    pub async fn select_config_by_id(config_id: &Uuid) -> Result<Option<SysConfig>> {
+        let sql = format!("SELECT {} FROM sys_config c WHERE c.config_id = ?", CONFIG_COLUMNS);
+        let row = sqlx::query(&sql)
+            .bind(config_id.to_string())
+            .fetch_optional(require_database_pool()?)
+            .await?;
+        row.as_ref().map(Self::map_row).transpose()
+    }
+
+    /// 通过参数键名查询主键（用于唯一性校验）
+    pub async fn select_config_id_by_key(config_key: &str) -> Result<Option<Uuid>> {
+        let row = sqlx::query("SELECT config_id FROM sys_config WHERE config_key = ? LIMIT 1")
+            .bind(config_key)
+            .fetch_optional(require_database_pool()?)
+            .await?;
+        match row {
+            Some(row) => DatabaseUtils::get_uuid(&row, "config_id"),
+            None => Ok(None),
+        }
+    }
+
+    /// 新增参数配置
+    pub async fn insert_config(config: &SysConfig) -> Result<u64> {
+        let result = sqlx::query(
+            "INSERT INTO sys_config (config_id, config_name, config_key, config_value, config_type, \
+             create_by, create_time, remark) VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+            .bind(config.config_id.map(|id| id.to_string()))
+            .bind(&config.config_name)
+            .bind(&config.config_key)
+            .bind(&config.config_value)
+            .bind(&config.config_type)
+            .bind(&config.base.create_by)
+            .bind(config.base.create_time)
+            .bind(&config.base.remark)
+            .execute(require_database_pool()?)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// 修改参数配置
+    pub async fn update_config(config: &SysConfig) -> Result<u64> {
+        let result = sqlx::query(
+            "UPDATE sys_config SET config_name = ?, config_key = ?, config_value = ?, config_type = ?, \
+             update_by = ?, update_time = ?, remark = ? WHERE config_id = ?",
+        )
+            .bind(&config.config_name)
+            .bind(&config.config_key)
+            .bind(&config.config_value)
+            .bind(&config.config_type)
+            .bind(&config.base.update_by)
+            .bind(config.base.update_time)
+            .bind(&config.base.remark)
+            .bind(config.config_id.map(|id| id.to_string()))
+            .execute(require_database_pool()?)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// 批量删除参数配置
+    pub async fn delete_config_by_ids(config_ids: &[Uuid]) -> Result<u64> {
+        if config_ids.is_empty() {
+            return Ok(0);
+        }
+        let placeholders = vec!["?"; config_ids.len()].join(", ");
+        let sql = format!("DELETE FROM sys_config WHERE config_id IN ({})", placeholders);
+        let mut query = sqlx::query(&sql);
+        for config_id in config_ids {
+            query = query.bind(config_id.to_string());
+        }
+        Ok(query.execute(require_database_pool()?).await?.rows_affected())
+    }
+}
@@ -0,0 +1,273 @@
+use chrono::Utc;
+use sqlx::{mysql::MySqlRow, MySqlConnection, Row};
+use uuid::Uuid;
+use zzyl_common::enums::DelFlag;
+use zzyl_common::error::Result;
+use zzyl_framework::database::{require_database_pool, DatabaseUtils};
+use zzyl_framework::security::DataScopeSql;
+use crate::domain::{DeptSearchCondition, SysDept};
+
+/// 部门表查询列
+pub const DEPT_COLUMNS: &str = "d.dept_id, d.parent_id, d.ancestors, d.dept_name, d.order_num, d.leader, \
+    d.phone, d.email, d.status, d.del_flag, \
+    d.create_by, d.create_time, d.update_by, d.update_time, d.remark";
+
+/// 部门数据访问
+pub struct SysDeptRepository;
+
+impl SysDeptRepository {
+    /// 将查询结果映射为部门实体
+    pub fn map_row(row: &MySqlRow) -> Result<SysDept> {
+        Ok(SysDept {
+            dept_id: DatabaseUtils::get_uuid(row, "dept_id")?,
+            parent_id: DatabaseUtils::get_uuid(row, "parent_id")?,
+            ancestors: row.try_get("ancestors")?,
+            dept_name: row.try_get("dept_name")?,
+            order_num: row.try_get("order_num")?,
+            leader: row.try_get("leader")?,
+            phone: row.try_get("phone")?,
+            email: row.try_get("email")?,
+            status: row.try_get("status")?,
+            del_flag: row.try_get("del_flag")?,
+            base: DatabaseUtils::get_base_entity(row)?,
+        })
+    }
+
+    /// 通过部门ID查询部门
+    pub async fn select_dept_by_id(dept_id: &Uuid) -> Result<Option<SysDept>> {
+        let sql = format!("SELECT {} FROM sys_dept d WHERE d.dept_id = ? AND d.del_flag = '0'", DEPT_COLUMNS);
+        let row = sqlx::query(&sql)
+            .bind(dept_id.to_string())
+            .fetch_optional(require_database_pool()?)
+            .await?;
+        row.as_ref().map(Self::map_row).transpose()
+    }
+
+    /// 根据条件查询数据权限范围内的部门列表
+    pub async fn select_dept_list(condition: &DeptSearchCondition, data_scope: &DataScopeSql) -> Result<Vec<SysDept>> {
+        let mut conditions = vec!["d.del_flag = '0'".to_string()];
+        let mut params = Vec::new();
+        if let Some(dept_name) = condition.dept_name.as_deref().filter(|value| !value.trim().is_empty()) {
+            conditions.push("d.dept_name LIKE ?".to_string());
+            params.push(format!("%{}%", dept_name.trim()));
+        }
+        if let Some(status) = condition.status.as_deref().filter(|value| !value.trim().is_empty()) {
+            conditions.push("d.status = ?".to_string());
+            params.push(status.trim().to_string());
+        }
+        if let Some(parent_id) = condition.parent_id {
+            conditions.push("d.parent_id = ?".to_string());
+            params.push(parent_id.to_string());
+        }
+        let sql = format!(
+            "SELECT {} FROM sys_dept d WHERE {}{} ORDER BY d.parent_id, d.order_num",
+            DEPT_COLUMNS,
+            conditions.join(" AND "),
+            data_scope.and_clause()
+        );
+        let mut query = sqlx::query(&sql);
+        for param in params.iter().chain(&data_scope.params) {
+            query = query.bind(param);
+        }
+        let rows = query.fetch_all(require_database_pool()?).await?;
+        rows.iter().map(Self::map_row).collect()
+    }
+
+    /// 统计数据权限范围内指定ID的部门数量
+    pub async fn count_dept_in_scope(dept_id: &Uuid, data_scope: &DataScopeSql) -> Result<i64> {
+        let sql = format!(
+            "SELECT COUNT(*) FROM sys_dept d WHERE d.dept_id = ? AND d.del_flag = '0'{}",
+            data_scope.and_clause()
+        );
+        let mut query = sqlx::query_scalar::<_, i64>(&sql).bind(dept_id.to_string());
+        for param in &data_scope.params {
+            query = query.bind(param);
+        }
+        Ok(query.fetch_one(require_database_pool()?).await?)
+    }
+
+    /// 查询全部下级部门（不限层级）
+    pub async fn select_children_by_id(dept_id: &Uuid) -> Result<Vec<SysDept>> {
+        let sql = format!(
+            "SELECT {} FROM sys_dept d WHERE FIND_IN_SET(?, d.ancestors) AND d.del_flag = '0'",
+            DEPT_COLUMNS
+        );
+        let rows = sqlx::query(&sql)
+            .bind(dept_id.to_string())
+            .fetch_all(require_database_pool()?)
+            .await?;
+        rows.iter().map(Self::map_row).collect()
+    }
+
+    /// 统计正常状态的下级部门数量（不限层级）
+    pub async fn count_normal_children_by_id(dept_id: &Uuid) -> Result<i64> {
+        let count = sqlx::query_scalar::<_, i64>(
+            "SELECT COUNT(*) FROM sys_dept WHERE status = '0' AND del_flag = '0' AND FIND_IN_SET(?, ancestors)",
+        )
+            .bind(dept_id.to_string())
+            .fetch_one(require_database_pool()?)
+            .await?;
+        Ok(count)
+    }
+
+    /// 统计直接下级部门数量
+    pub async fn count_children_by_parent_id(dept_id: &Uuid) -> Result<i64> {
+        let count = sqlx::query_scalar::<_, i64>(
+            "SELECT COUNT(*) FROM sys_dept WHERE parent_id = ? AND del_flag = '0'",
+        )
+            .bind(dept_id.to_string())
+            .fetch_one(require_database_pool()?)
+            .await?;
+        Ok(count)
+    }
+
+    /// 统计部门下的用户数量
+    pub async fn count_users_by_dept_id(dept_id: &Uuid) -> Result<i64> {
+        let count = sqlx::query_scalar::<_, i64>(
+            "SELECT COUNT(*) FROM sys_user WHERE dept_id = ? AND del_flag = ?",
+        )
+            .bind(dept_id.to_string())
+            .bind(i32::from(DelFlag::Exists))
+            .fetch_one(require_database_pool()?)
+            .await?;
+        Ok(count)
+    }
+
+    /// 查询同一父部门下指定名称的部门ID（用于唯一性校验）
+    pub async fn select_dept_id_by_name(parent_id: Option<&Uuid>, dept_name: &str) -> Result<Option<Uuid>> {
+        let row = sqlx::query(
+            "SELECT dept_id FROM sys_dept WHERE dept_name = ? AND parent_id <=> ? AND del_flag = '0' LIMIT 1",
+        )
+            .bind(dept_name)
+            .bind(parent_id.map(|id| id.to_string()))
+            .fetch_optional(require_database_pool()?)
+            .await?;
+        match row {
+            Some(row) => DatabaseUtils::get_uuid(&row, "dept_id"),
+            None => Ok(None),
+        }
+    }
+
+    /// 按部门名称查询部门ID（用于导入时解析部门）
+    pub async fn select_dept_ids_by_name(dept_name: &str) -> Result<Vec<Uuid>> {
+        let rows = sqlx::query("SELECT dept_id FROM sys_dept WHERE dept_name = ? AND del_flag = '0'")
+            .bind(dept_name)
+            .fetch_all(require_database_pool()?)
+            .await?;
+        DatabaseUtils::get_uuids(&rows, "dept_id")
+    }
+
+    /// 查询角色选中的部门ID
+    ///
+    /// 部门树选择项关联显示时，排除已有下级部门被选中的父部门。
+    pub async fn select_checked_dept_ids(role_id: &Uuid, dept_check_strictly: bool) -> Result<Vec<Uuid>> {
+        let mut sql = "SELECT d.dept_id FROM sys_dept d \
+             INNER JOIN sys_role_dept rd ON rd.dept_id = d.dept_id \
+             WHERE rd.role_id = ? AND d.del_flag = '0'".to_string();
+        if dept_check_strictly {
+            sql.push_str(
+                " AND d.dept_id NOT IN (SELECT c.parent_id FROM sys_dept c \
+                 INNER JOIN sys_role_dept crd ON crd.dept_id = c.dept_id \
+                 WHERE crd.role_id = ? AND c.parent_id IS NOT NULL)",
+            );
+        }
+        sql.push_str(" ORDER BY d.parent_id, d.order_num");
+
+        let mut query = sqlx::query(&sql).bind(role_id.to_string());
+        if dept_check_strictly {
+            query = query.bind(role_id.to_string());
+        }
+        let rows = query.fetch_all(require_database_pool()?).await?;
+        DatabaseUtils::get_uuids(&rows, "dept_id")
+    }
+
+    /// 新增部门
+    pub async fn insert_dept(conn: &mut MySqlConnection, dept: &SysDept) -> Result<u64> {
+        let result = sqlx::query(
+            "INSERT INTO sys_dept (dept_id, parent_id, ancestors, dept_name, order_num, leader, phone, email, \
+             status, del_flag, create_by, create_time, remark) \
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, '0', ?, ?, ?)",
+        )
+            .bind(dept.dept_id.map(|id| id.to_string()))
+            .bind(dept.parent_id.map(|id| id.to_string()))
+            .bind(&dept.ancestors)
+            .bind(&dept.dept_name)
+            .bind(dept.order_num)
+            .bind(&dept.leader)
+            .bind(&dept.phone)
+            .bind(&dept.email)
+            .bind(&dept.status)
+            .bind(&dept.base.create_by)
+            .bind(dept.base.create_time)
+            .bind(&dept.base.remark)
+            .execute(conn)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// 修改部门
+    pub async fn update_dept(conn: &mut MySqlConnection, dept: &SysDept) -> Result<u64> {
+        let result = sqlx::query(
+            "UPDATE sys_dept SET parent_id = ?, ancestors = ?, dept_name = ?, order_num = ?, leader = ?, \
+             phone = ?, email = ?, status = ?, update_by = ?, update_time = ?, remark = ? \
+             WHERE dept_id = ? AND del_flag = '0'",
+        )
+            .bind(dept.parent_id.map(|id| id.to_string()))
+            .bind(&dept.ancestors)
+            .bind(&dept.dept_name)
+            .bind(dept.order_num)
+            .bind(&dept.leader)
+            .bind(&dept.phone)
+            .bind(&dept.email)
+            .bind(&dept.status)
+            .bind(&dept.base.update_by)
+            .bind(dept.base.update_time)
+            .bind(&dept.base.remark)
+            .bind(dept.dept_id.map(|id| id.to_string()))
+            .execute(conn)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// 修改部门的祖级列表
+    pub async fn update_ancestors(conn: &mut MySqlConnection, dept_id: &Uuid, ancestors: &str) -> Result<u64> {
+        let result = sqlx::query("UPDATE sys_dept SET ancestors = ? WHERE dept_id = ?")
+            .bind(ancestors)
+            .bind(dept_id.to_string())
+            .execute(conn)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// 将指定部门全部启用
+    pub async fn update_status_normal(conn: &mut MySqlConnection, dept_ids: &[Uuid], update_by: &str) -> Result<u64> {
+        if dept_ids.is_empty() {
+            return Ok(0);
+        }
+        let placeholders = vec!["?"; dept_ids.len()].join(", ");
+        let sql = format!(
+            "UPDATE sys_dept SET status = '0', update_by = ?, update_time = ? WHERE dept_id IN ({})",
+            placeholders
+        );
+        let mut query = sqlx::query(&sql)
+            .bind(update_by)
+            .bind(Utc::now());
+        for dept_id in dept_ids {
+            query = query.bind(dept_id.to_string());
+        }
+        Ok(query.execute(conn).await?.rows_affected())
+    }
+
+    /// 逻辑删除部门
+    pub async fn delete_dept_by_id(dept_id: &Uuid, update_by: &str) -> Result<u64> {
+        let result = sqlx::query(
+            "UPDATE sys_dept SET del_flag = '2', update_by = ?, update_time = ? WHERE dept_id = ?",
+        )
+            .bind(update_by)
+            .bind(Utc::now())
+            .bind(dept_id.to_string())
+            .execute(require_database_pool()?)
+            .await?;
+        Ok(result.rows_affected())
+    }
+}
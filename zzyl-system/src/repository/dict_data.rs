@@ -0,0 +1,226 @@
+use sqlx::{mysql::MySqlRow, MySqlConnection, Row};
+use uuid::Uuid;
+use zzyl_common::error::Result;
+use zzyl_common::PageDomain;
+use zzyl_framework::database::{require_database_pool, DatabaseUtils};
+use crate::domain::{DictDataSearchCondition, SysDictData};
+
+/// 字典数据表查询列
+pub const DICT_DATA_COLUMNS: &str = "d.dict_code, d.dict_sort, d.dict_label, d.dict_value, d.dict_type, \
+    d.css_class, d.list_class, d.is_default, d.status, \
+    d.create_by, d.create_time, d.update_by, d.update_time, d.remark";
+
+/// 字典数据数据访问
+pub struct SysDictDataRepository;
+
+impl SysDictDataRepository {
+    /// 将查询结果映射为字典数据实体
+    pub fn map_row(row: &MySqlRow) -> Result<SysDictData> {
+        Ok(SysDictData {
+            dict_code: DatabaseUtils::get_uuid(row, "dict_code")?,
+            dict_sort: row.try_get("dict_sort")?,
+            dict_label: row.try_get("dict_label")?,
+            dict_value: row.try_get("dict_value")?,
+            dict_type: row.try_get("dict_type")?,
+            css_class: row.try_get("css_class")?,
+            list_class: row.try_get("list_class")?,
+            is_default: row.try_get("is_default")?,
+            status: row.try_get("status")?,
+            base: DatabaseUtils::get_base_entity(row)?,
+        })
+    }
+
+    /// 根据条件分页查询字典数据，返回当前页数据与总数
+    pub async fn select_dict_data_list(
+        condition: &DictDataSearchCondition,
+        page: &PageDomain,
+    ) -> Result<(Vec<SysDictData>, i64)> {
+        let mut conditions = Vec::new();
+        let mut params = Vec::new();
+        if let Some(dict_type) = condition.dict_type.as_deref().filter(|value| !value.trim().is_empty()) {
+            conditions.push("d.dict_type = ?");
+            params.push(dict_type.trim().to_string());
+        }
+        if let Some(dict_label) = condition.dict_label.as_deref().filter(|value| !value.trim().is_empty()) {
+            conditions.push("d.dict_label LIKE ?");
+            params.push(format!("%{}%", dict_label.trim()));
+        }
+        if let Some(status) = condition.status.as_deref().filter(|value| !value.trim().is_empty()) {
+            conditions.push("d.status = ?");
+            params.push(status.trim().to_string());
+        }
+        let where_sql = if conditions.is_empty() {
+            String::new()
+        } else {
+            format!("WHERE {}", conditions.join(" AND "))
+        };
+        let pool = require_database_pool()?;
+
+        let count_sql = format!("SELECT COUNT(*) FROM sys_dict_data d {}", where_sql);
+        let mut count_query = sqlx::query_scalar::<_, i64>(&count_sql);
+        for param in &params {
+            count_query = count_query.bind(param);
+        }
+        let total = count_query.fetch_one(pool).await?;
+
+        let list_sql = format!(
+            "SELECT {} FROM sys_dict_data d {} ORDER BY d.dict_type, d.dict_sort LIMIT ? OFFSET ?",
+            DICT_DATA_COLUMNS, where_sql
+        );
+        let mut list_query = sqlx::query(&list_sql);
+        for param in &params {
+            list_query = list_query.bind(param);
+        }
+        let rows = list_query
+            .bind(page.page_size() as i64)
+            .bind(page.offset())
+            .fetch_all(pool)
+            .await?;
+        let list = rows.iter().map(Self::map_row).collect::<Result<Vec<_>>>()?;
+
+        Ok((list, total))
+    }
+
+    /// 查询指定类型的正常字典数据
+    pub async fn select_dict_data_by_type(dict_type: &str) -> Result<Vec<SysDictData>> {
+        let sql = format!(
+            "SELECT {} FROM sys_dict_data d WHERE d.dict_type = ? AND d.status = '0' ORDER BY d.dict_sort",
+            DICT_DATA_COLUMNS
+        );
+        let rows = sqlx::query(&sql)
+            .bind(dict_type)
+            .fetch_all(require_database_pool()?)
+            .await?;
+        rows.iter().map(Self::map_row).collect()
+    }
+
+    /// 查询全部正常字典数据
+    pub async fn select_dict_data_all() -> Result<Vec<SysDictData>> {
+        let sql = format!(
+            "SELECT {} FROM sys_dict_data d WHERE d.status = '0' ORDER BY d.dict_type, d.dict_sort",
+            DICT_DATA_COLUMNS
+        );
+        let rows = sqlx::query(&sql)
+            .fetch_all(require_database_pool()?)
+            .await?;
+        rows.iter().map(Self::map_row).collect()
+    }
+
+    /// 通过字典编码查询字典数据
+    pub async fn select_dict_data_by_code(dict_code: &Uuid) -> Result<Option<SysDictData>> {
+        let sql = format!("SELECT {} FROM sys_dict_data d WHERE d.dict_code = ?", DICT_DATA_COLUMNS);
+        let row = sqlx::query(&sql)
+            .bind(dict_code.to_string())
+            .fetch_optional(require_database_pool()?)
+            .await?;
+        row.as_ref().map(Self::map_row).transpose()
+    }
+
+    /// 查询指定字典编码所属的字典类型
+    pub async fn select_dict_types_by_codes(dict_codes: &[Uuid]) -> Result<Vec<String>> {
+        if dict_codes.is_empty() {
+            return Ok(Vec::new());
+        }
+        let placeholders = vec!["?"; dict_codes.len()].join(", ");
+        let sql = format!("SELECT DISTINCT dict_type FROM sys_dict_data WHERE dict_code IN ({})", placeholders);
+        let mut query = sqlx::query_scalar::<_, String>(&sql);
+        for dict_code in dict_codes {
+            query = query.bind(dict_code.to_string());
+        }
+        Ok(query.fetch_all(require_database_pool()?).await?)
+    }
+
+    /// 通过字典类型与键值查询字典编码（用于唯一性校验）
+    pub async fn select_dict_code_by_value(dict_type: &str, dict_value: &str) -> Result<Option<Uuid>> {
+        let row = sqlx::query("SELECT dict_code FROM sys_dict_data WHERE dict_type = ? AND dict_value = ? LIMIT 1")
+            .bind(dict_type)
+            .bind(dict_value)
+            .fetch_optional(require_database_pool()?)
+            .await?;
+        match row {
+            Some(row) => DatabaseUtils::get_uuid(&row, "dict_code"),
+            None => Ok(None),
+        }
+    }
+
+    /// 统计字典类型下的数据数量
+    pub async fn count_by_dict_type(dict_type: &str) -> Result<i64> {
+        let count = sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM sys_dict_data WHERE dict_type = ?")
+            .bind(dict_type)
+            .fetch_one(require_database_pool()?)
+            .await?;
+        Ok(count)
+    }
+
+    /// 新增字典数据
+    pub async fn insert_dict_data(dict_data: &SysDictData) -> Result<u64> {
+        let result = sqlx::query(
+            "INSERT INTO sys_dict_data (dict_code, dict_sort, dict_label, dict_value, dict_type, css_class, \
+             list_class, is_default, status, create_by, create_time, remark) \
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+            .bind(dict_data.dict_code.map(|id| id.to_string()))
+            .bind(dict_data.dict_sort)
+            .bind(&dict_data.dict_label)
+            .bind(&dict_data.dict_value)
+            .bind(&dict_data.dict_type)
+            .bind(&dict_data.css_class)
+            .bind(&dict_data.list_class)
+            .bind(&dict_data.is_default)
+            .bind(&dict_data.status)
+            .bind(&dict_data.base.create_by)
+            .bind(dict_data.base.create_time)
+            .bind(&dict_data.base.remark)
+            .execute(require_database_pool()?)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// 修改字典数据
+    pub async fn update_dict_data(dict_data: &SysDictData) -> Result<u64> {
+        let result = sqlx::query(
+            "UPDATE sys_dict_data SET dict_sort = ?, dict_label = ?, dict_value = ?, dict_type = ?, css_class = ?, \
+             list_class = ?, is_default = ?, status = ?, update_by = ?, update_time = ?, remark = ? \
+             WHERE dict_code = ?",
+        )
+            .bind(dict_data.dict_sort)
+            .bind(&dict_data.dict_label)
+            .bind(&dict_data.dict_value)
+            .bind(&dict_data.dict_type)
+            .bind(&dict_data.css_class)
+            .bind(&dict_data.list_class)
+            .bind(&dict_data.is_default)
+            .bind(&dict_data.status)
+            .bind(&dict_data.base.update_by)
+            .bind(dict_data.base.update_time)
+            .bind(&dict_data.base.remark)
+            .bind(dict_data.dict_code.map(|id| id.to_string()))
+            .execute(require_database_pool()?)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// 同步修改字典数据的类型
+    pub async fn update_dict_type(conn: &mut MySqlConnection, old_dict_type: &str, new_dict_type: &str) -> Result<u64> {
+        let result = sqlx::query("UPDATE sys_dict_data SET dict_type = ? WHERE dict_type = ?")
+            .bind(new_dict_type)
+            .bind(old_dict_type)
+            .execute(conn)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// 批量删除字典数据
+    pub async fn delete_dict_data_by_codes(dict_codes: &[Uuid]) -> Result<u64> {
+        if dict_codes.is_empty() {
+            return Ok(0);
+        }
+        let placeholders = vec!["?"; dict_codes.len()].join(", ");
+        let sql = format!("DELETE FROM sys_dict_data WHERE dict_code IN ({})", placeholders);
+        let mut query = sqlx::query(&sql);
+        for dict_code in dict_codes {
+            query = query.bind(dict_code.to_string());
+        }
+        Ok(query.execute(require_database_pool()?).await?.rows_affected())
+    }
+}
@@ -0,0 +1,158 @@
+use sqlx::{mysql::MySqlRow, MySqlConnection, Row};
+use uuid::Uuid;
+use zzyl_common::error::Result;
+use zzyl_common::PageDomain;
+use zzyl_framework::database::{require_database_pool, DatabaseUtils};
+use crate::domain::{DictTypeSearchCondition, SysDictType};
+
+/// 字典类型表查询列
+pub const DICT_TYPE_COLUMNS: &str = "t.dict_id, t.dict_name, t.dict_type, t.status, \
+    t.create_by, t.create_time, t.update_by, t.update_time, t.remark";
+
+/// 字典类型数据访问
+pub struct SysDictTypeRepository;
+
+impl SysDictTypeRepository {
+    /// 将查询结果映射为字典类型实体
+    pub fn map_row(row: &MySqlRow) -> Result<SysDictType> {
+        Ok(SysDictType {
+            dict_id: DatabaseUtils::get_uuid(row, "dict_id")?,
+            dict_name: row.try_get("dict_name")?,
+            dict_type: row.try_get("dict_type")?,
+            status: row.try_get("status")?,
+            base: DatabaseUtils::get_base_entity(row)?,
+        })
+    }
+
+    /// 根据条件分页查询字典类型，返回当前页数据与总数
+    pub async fn select_dict_type_list(
+        condition: &DictTypeSearchCondition,
+        page: &PageDomain,
+    ) -> Result<(Vec<SysDictType>, i64)> {
+        let mut conditions = Vec::new();
+        let mut params = Vec::new();
+        if let Some(dict_name) = condition.dict_name.as_deref().filter(|value| !value.trim().is_empty()) {
+            conditions.push("t.dict_name LIKE ?");
+            params.push(format!("%{}%", dict_name.trim()));
+        }
+        if let Some(dict_type) = condition.dict_type.as_deref().filter(|value| !value.trim().is_empty()) {
+            conditions.push("t.dict_type LIKE ?");
+            params.push(format!("%{}%", dict_type.trim()));
+        }
+        if let Some(status) = condition.status.as_deref().filter(|value| !value.trim().is_empty()) {
+            conditions.push("t.status = ?");
+            params.push(status.trim().to_string());
+        }
+        let where_sql = if conditions.is_empty() {
+            String::new()
+        } else {
+            format!("WHERE {}", conditions.join(" AND "))
+        };
+        let pool = require_database_pool()?;
+
+        let count_sql = format!("SELECT COUNT(*) FROM sys_dict_type t {}", where_sql);
+        let mut count_query = sqlx::query_scalar::<_, i64>(&count_sql);
+        for param in &params {
+            count_query = count_query.bind(param);
+        }
+        let total = count_query.fetch_one(pool).await?;
+
+        let list_sql = format!(
+            "SELECT {} FROM sys_dict_type t {} ORDER BY t.create_time DESC LIMIT ? OFFSET ?",
+            DICT_TYPE_COLUMNS, where_sql
+        );
+        let mut list_query = sqlx::query(&list_sql);
+        for param in &params {
+            list_query = list_query.bind(param);
+        }
+        let rows = list_query
+            .bind(page.page_size() as i64)
+            .bind(page.offset())
+            .fetch_all(pool)
+            .await?;
+        let list = rows.iter().map(Self::map_row).collect::<Result<Vec<_>>>()?;
+
+        Ok((list, total))
+    }
+
+    /// 查询全部字典类型
+    pub async fn select_dict_type_all() -> Result<Vec<SysDictType>> {
+        let sql = format!("SELECT {} FROM sys_dict_type t ORDER BY t.create_time", DICT_TYPE_COLUMNS);
+        let rows = sqlx::query(&sql)
+            .fetch_all(require_database_pool()?)
+            .await?;
+        rows.iter().map(Self::map_row).collect()
+    }
+
+    /// 通过主键查询字典类型
+    pub async fn select_dict_type_by_id(dict_id: &Uuid) -> Result<Option<SysDictType>> {
+        let sql = format!("SELECT {} FROM sys_dict_type t WHERE t.dict_id = ?", DICT_TYPE_COLUMNS);
+        let row = sqlx::query(&sql)
+            .bind(dict_id.to_string())
+            .fetch_optional(require_database_pool()?)
+            .await?;
+        row.as_ref().map(Self::map_row).transpose()
+    }
+
+    /// 通过字典类型查询主键（用于唯一性校验）
+    pub async fn select_dict_id_by_type(dict_type: &str) -> Result<Option<Uuid>> {
+        let row = sqlx::query("SELECT dict_id FROM sys_dict_type WHERE dict_type = ? LIMIT 1")
+            .bind(dict_type)
+            .fetch_optional(require_database_pool()?)
+            .await?;
+        match row {
+            Some(row) => DatabaseUtils::get_uuid(&row, "dict_id"),
+            None => Ok(None),
+        }
+    }
+
+    /// 新增字典类型
+    pub async fn insert_dict_type(dict_type: &SysDictType) -> Result<u64> {
+        let result = sqlx::query(
+            "INSERT INTO sys_dict_type (dict_id, dict_name, dict_type, status, create_by, create_time, remark) \
+             VALUES (?, ?, ?, ?, ?, ?, ?)",
+        )
+            .bind(dict_type.dict_id.map(|id| id.to_string()))
+            .bind(&dict_type.dict_name)
+            .bind(&dict_type.dict_type)
+            .bind(&dict_type.status)
+            .bind(&dict_type.base.create_by)
+            .bind(dict_type.base.create_time)
+            .bind(&dict_type.base.remark)
+            .execute(require_database_pool()?)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// 修改字典类型
+    pub async fn update_dict_type(conn: &mut MySqlConnection, dict_type: &SysDictType) -> Result<u64> {
+        let result = sqlx::query(
+            "UPDATE sys_dict_type SET dict_name = ?, dict_type = ?, status = ?, update_by = ?, update_time = ?, \
+             remark = ? WHERE dict_id = ?",
+        )
+            .bind(&dict_type.dict_name)
+            .bind(&dict_type.dict_type)
+            .bind(&dict_type.status)
+            .bind(&dict_type.base.update_by)
+            .bind(dict_type.base.update_time)
+            .bind(&dict_type.base.remark)
+            .bind(dict_type.dict_id.map(|id| id.to_string()))
+            .execute(conn)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// 批量删除字典类型
+    pub async fn delete_dict_type_by_ids(dict_ids: &[Uuid]) -> Result<u64> {
+        if dict_ids.is_empty() {
+            return Ok(0);
+        }
+        let placeholders = vec!["?"; dict_ids.len()].join(", ");
+        let sql = format!("DELETE FROM sys_dict_type WHERE dict_id IN ({})", placeholders);
+        let mut query = sqlx::query(&sql);
+        for dict_id in dict_ids {
+            query = query.bind(dict_id.to_string());
+        }
+        Ok(query.execute(require_database_pool()?).await?.rows_affected())
+    }
+}
@@ -0,0 +1,159 @@
+use sqlx::{mysql::MySqlRow, Row};
+use uuid::Uuid;
+use zzyl_common::error::Result;
+use zzyl_common::PageDomain;
+use zzyl_framework::database::{require_database_pool, DatabaseUtils};
+use crate::domain::{LoginInfoSearchCondition, SysLoginInfo};
+
+/// 登录日志表查询列
+pub const LOGIN_INFO_COLUMNS: &str = "l.info_id, l.username, l.ipaddr, l.login_location, l.browser, l.os, \
+    l.status, l.msg, l.login_time";
+
+/// 登录日志数据访问
+pub struct SysLoginInfoRepository;
+
+impl SysLoginInfoRepository {
+    /// 将查询结果映射为登录日志实体
+    pub fn map_row(row: &MySqlRow) -> Result<SysLoginInfo> {
+        Ok(SysLoginInfo {
+            info_id: DatabaseUtils::get_uuid(row, "info_id")?,
+            username: row.try_get("username")?,
+            ipaddr: row.try_get("ipaddr")?,
+            login_location: row.try_get("login_location")?,
+            browser: row.try_get("browser")?,
+            os: row.try_get("os")?,
+            status: row.try_get("status")?,
+            msg: row.try_get("msg")?,
+            login_time: row.try_get("login_time")?,
+        })
+    }
+
+    /// 新增登录日志
+    pub async fn insert_login_info(login_info: &SysLoginInfo) -> Result<u64> {
+        let info_id = login_info.info_id.unwrap_or_else(Uuid::new_v4);
+        let result = sqlx::query(
+            "INSERT INTO sys_logininfor (info_id, username, ipaddr, login_location, browser, os, status, msg, login_time) \
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+            .bind(info_id.to_string())
+            .bind(&login_info.username)
+            .bind(&login_info.ipaddr)
+            .bind(&login_info.login_location)
+            .bind(&login_info.browser)
+            .bind(&login_info.os)
+            .bind(&login_info.status)
+            .bind(&login_info.msg)
+            .bind(login_info.login_time)
+            .execute(require_database_pool()?)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// 根据条件分页查询登录日志，返回当前页数据与总数
+    pub async fn select_login_info_list(
+        condition: &LoginInfoSearchCondition,
+        page: &PageDomain,
+    ) -> Result<(Vec<SysLoginInfo>, i64)> {
+        let (where_sql, params) = Self::build_where(condition);
+        let pool = require_database_pool()?;
+
+        let count_sql = format!("SELECT COUNT(*) FROM sys_logininfor l {}", where_sql);
+        let mut count_query = sqlx::query_scalar::<_, i64>(&count_sql);
+        for param in &params {
+            count_query = count_query.bind(param);
+        }
+        let total = count_query.fetch_one(pool).await?;
+
+        let list_sql = format!(
+            "SELECT {} FROM sys_logininfor l {} ORDER BY l.login_time DESC LIMIT ? OFFSET ?",
+            LOGIN_INFO_COLUMNS, where_sql
+        );
+        let mut list_query = sqlx::query(&list_sql);
+        for param in &params {
+            list_query = list_query.bind(param);
+        }
+        let rows = list_query
+            .bind(page.page_size() as i64)
+            .bind(page.offset())
+            .fetch_all(pool)
+            .await?;
+        let list = rows.iter().map(Self::map_row).collect::<Result<Vec<_>>>()?;
+
+        Ok((list, total))
+    }
+
+    /// 根据条件查询全部登录日志（用于导出）
+    pub async fn select_login_info_export(condition: &LoginInfoSearchCondition) -> Result<Vec<SysLoginInfo>> {
+        let (where_sql, params) = Self::build_where(condition);
+        let sql = format!("SELECT {} FROM sys_logininfor l {} ORDER BY l.login_time DESC", LOGIN_INFO_COLUMNS, where_sql);
+        let mut query = sqlx::query(&sql);
+        for param in &params {
+            query = query.bind(param);
+        }
+        let rows = query.fetch_all(require_database_pool()?).await?;
+        rows.iter().map(Self::map_row).collect()
+    }
+
+    /// 通过访问ID查询登录日志
+    pub async fn select_login_info_by_id(info_id: &Uuid) -> Result<Option<SysLoginInfo>> {
+        let sql = format!("SELECT {} FROM sys_logininfor l WHERE l.info_id = ?", LOGIN_INFO_COLUMNS);
+        let row = sqlx::query(&sql)
+            .bind(info_id.to_string())
+            .fetch_optional(require_database_pool()?)
+            .await?;
+        row.as_ref().map(Self::map_row).transpose()
+    }
+
+    /// 批量删除登录日志
+    pub async fn delete_login_info_by_ids(info_ids: &[Uuid]) -> Result<u64> {
+        if info_ids.is_empty() {
+            return Ok(0);
+        }
+        let placeholders = vec!["?"; info_ids.len()].join(", ");
+        let sql = format!("DELETE FROM sys_logininfor WHERE info_id IN ({})", placeholders);
+        let mut query = sqlx::query(&sql);
+        for info_id in info_ids {
+            query = query.bind(info_id.to_string());
+        }
+        Ok(query.execute(require_database_pool()?).await?.rows_affected())
+    }
+
+    /// 清空登录日志
+    pub async fn clean_login_info() -> Result<()> {
+        sqlx::query("TRUNCATE TABLE sys_logininfor")
+            .execute(require_database_pool()?)
+            .await?;
+        Ok(())
+    }
+
+    /// 构建查询条件
+    fn build_where(condition: &LoginInfoSearchCondition) -> (String, Vec<String>) {
+        let mut conditions = Vec::new();
+        let mut params = Vec::new();
+        if let Some(ipaddr) = condition.ipaddr.as_deref().filter(|value| !value.trim().is_empty()) {
+            conditions.push("l.ipaddr LIKE ?");
+            params.push(format!("%{}%", ipaddr.trim()));
+        }
+        if let Some(username) = condition.username.as_deref().filter(|value| !value.trim().is_empty()) {
+            conditions.push("l.username LIKE ?");
+            params.push(format!("%{}%", username.trim()));
+        }
+        if let Some(status) = condition.status.as_deref().filter(|value| !value.trim().is_empty()) {
+            conditions.push("l.status = ?");
+            params.push(status.trim().to_string());
+        }
+        if let Some(begin_time) = condition.begin_time.as_deref().filter(|value| !value.trim().is_empty()) {
+            conditions.push("DATE(l.login_time) >= DATE(?)");
+            params.push(begin_time.trim().to_string());
+        }
+        if let Some(end_time) = condition.end_time.as_deref().filter(|value| !value.trim().is_empty()) {
+            conditions.push("DATE(l.login_time) <= DATE(?)");
+            params.push(end_time.trim().to_string());
+        }
+        if conditions.is_empty() {
+            (String::new(), params)
+        } else {
+            (format!("WHERE {}", conditions.join(" AND ")), params)
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use sqlx::{mysql::MySqlRow, Row};
+use uuid::Uuid;
+use zzyl_common::error::Result;
+use zzyl_framework::database::{require_database_pool, DatabaseUtils};
+use crate::domain::SysMenu;
+
+/// 菜单表查询列
+pub const MENU_COLUMNS: &str = "m.menu_id, m.menu_name, m.parent_id, m.order_num, m.path, m.component, m.query, \
+    m.is_frame, m.is_cache, m.menu_type, m.visible, m.status, m.perms, m.icon, \
+    m.create_by, m.create_time, m.update_by, m.update_time, m.remark";
+
+/// 菜单数据访问
+pub struct SysMenuRepository;
+
+impl SysMenuRepository {
+    /// 将查询结果映射为菜单实体
+    pub fn map_row(row: &MySqlRow) -> Result<SysMenu> {
+        Ok(SysMenu {
+            menu_id: DatabaseUtils::get_uuid(row, "menu_id")?,
+            menu_name: row.try_get("menu_name")?,
+            parent_id: DatabaseUtils::get_uuid(row, "parent_id")?,
+            order_num: row.try_get("order_num")?,
+            path: row.try_get("path")?,
+            component: row.try_get("component")?,
+            query: row.try_get("query")?,
+            is_frame: row.try_get("is_frame")?,
+            is_cache: row.try_get("is_cache")?,
+            menu_type: row.try_get("menu_type")?,
+            visible: row.try_get("visible")?,
+            status: row.try_get("status")?,
+            perms: row.try_get("perms")?,
+            icon: row.try_get("icon")?,
+            base: DatabaseUtils::get_base_entity(row)?,
+        })
+    }
+
+    /// 查询用户拥有的权限标识
+    pub async fn select_perms_by_user_id(user_id: &Uuid) -> Result<Vec<String>> {
+        let rows = sqlx::query(
+            "SELECT DISTINCT m.perms FROM sys_menu m \
+             INNER JOIN sys_role_menu rm ON rm.menu_id = m.menu_id \
+             INNER JOIN sys_user_role ur ON ur.role_id = rm.role_id \
+             INNER JOIN sys_role r ON r.role_id = ur.role_id \
+             WHERE ur.user_id = ? AND m.status = '0' AND r.status = '0' AND r.del_flag = '0'",
+        )
+            .bind(user_id.to_string())
+            .fetch_all(require_database_pool()?)
+            .await?;
+
+        let mut perms = Vec::new();
+        for row in &rows {
+            let value: Option<String> = row.try_get("perms")?;
+            if let Some(value) = value {
+                perms.extend(value.split(',').map(|p| p.trim()).filter(|p| !p.is_empty()).map(|p| p.to_string()));
+            }
+        }
+        Ok(perms)
+    }
+
+    /// 查询用户可见的目录和菜单（不含按钮）
+    pub async fn select_menus_by_user_id(user_id: &Uuid) -> Result<Vec<SysMenu>> {
+        let sql = format!(
+            "SELECT DISTINCT {} FROM sys_menu m \
+             INNER JOIN sys_role_menu rm ON rm.menu_id = m.menu_id \
+             INNER JOIN sys_user_role ur ON ur.role_id = rm.role_id \
+             INNER JOIN sys_role r ON r.role_id = ur.role_id \
+             WHERE ur.user_id = ? AND m.menu_type IN ('M', 'C') AND m.status = '0' \
+             AND r.status = '0' AND r.del_flag = '0' \
+             ORDER BY m.parent_id, m.order_num",
+            MENU_COLUMNS
+        );
+        let rows = sqlx::query(&sql)
+            .bind(user_id.to_string())
+            .fetch_all(require_database_pool()?)
+            .await?;
+        rows.iter().map(Self::map_row).collect()
+    }
+
+    /// 查询全部正常的目录和菜单（不含按钮）
+    pub async fn select_all_menus() -> Result<Vec<SysMenu>> {
+        let sql = format!(
+            "SELECT {} FROM sys_menu m WHERE m.menu_type IN ('M', 'C') AND m.status = '0' \
+             ORDER BY m.parent_id, m.order_num",
+            MENU_COLUMNS
+        );
+        let rows = sqlx::query(&sql)
+            .fetch_all(require_database_pool()?)
+            .await?;
+        rows.iter().map(Self::map_row).collect()
+    }
+
+    /// 查询全部正常菜单（含按钮）
+    pub async fn select_menu_list() -> Result<Vec<SysMenu>> {
+        let sql = format!(
+            "SELECT {} FROM sys_menu m WHERE m.status = '0' ORDER BY m.parent_id, m.order_num",
+            MENU_COLUMNS
+        );
+        let rows = sqlx::query(&sql)
+            .fetch_all(require_database_pool()?)
+            .await?;
+        rows.iter().map(Self::map_row).collect()
+    }
+
+    /// 查询用户拥有的全部正常菜单（含按钮）
+    pub async fn select_menu_list_by_user_id(user_id: &Uuid) -> Result<Vec<SysMenu>> {
+        let sql = format!(
+            "SELECT DISTINCT {} FROM sys_menu m \
+             INNER JOIN sys_role_menu rm ON rm.menu_id = m.menu_id \
+             INNER JOIN sys_user_role ur ON ur.role_id = rm.role_id \
+             INNER JOIN sys_role r ON r.role_id = ur.role_id \
+             WHERE ur.user_id = ? AND m.status = '0' AND r.status = '0' AND r.del_flag = '0' \
+             ORDER BY m.parent_id, m.order_num",
+            MENU_COLUMNS
+        );
+        let rows = sqlx::query(&sql)
+            .bind(user_id.to_string())
+            .fetch_all(require_database_pool()?)
+            .await?;
+        rows.iter().map(Self::map_row).collect()
+    }
+
+    /// 查询角色选中的菜单ID
+    ///
+    /// 菜单树选择项关联显示时，排除已有子菜单被选中的父菜单，避免前端级联勾选全部子节点。
+    pub async fn select_checked_menu_ids(role_id: &Uuid, menu_check_strictly: bool) -> Result<Vec<Uuid>> {
+        let mut sql = "SELECT m.menu_id FROM sys_menu m \
+             INNER JOIN sys_role_menu rm ON rm.menu_id = m.menu_id \
+             WHERE rm.role_id = ?".to_string();
+        if menu_check_strictly {
+            sql.push_str(
+                " AND m.menu_id NOT IN (SELECT c.parent_id FROM sys_menu c \
+                 INNER JOIN sys_role_menu crm ON crm.menu_id = c.menu_id \
+                 WHERE crm.role_id = ? AND c.parent_id IS NOT NULL)",
+            );
+        }
+        sql.push_str(" ORDER BY m.parent_id, m.order_num");
+
+        let mut query = sqlx::query(&sql).bind(role_id.to_string());
+        if menu_check_strictly {
+            query = query.bind(role_id.to_string());
+        }
+        let rows = query.fetch_all(require_database_pool()?).await?;
+        DatabaseUtils::get_uuids(&rows, "menu_id")
+    }
+}
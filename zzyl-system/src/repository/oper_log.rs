@@ -0,0 +1,183 @@
+use sqlx::{mysql::MySqlRow, Row};
+use uuid::Uuid;
+use zzyl_common::error::Result;
+use zzyl_common::PageDomain;
+use zzyl_framework::database::{require_database_pool, DatabaseUtils};
+use crate::domain::{OperLogSearchCondition, SysOperLog};
+
+/// 操作日志表查询列
+pub const OPER_LOG_COLUMNS: &str = "o.oper_id, o.title, o.business_type, o.method, o.request_method, o.oper_name, \
+    o.dept_name, o.oper_url, o.oper_ip, o.oper_location, o.oper_param, o.json_result, o.status, o.error_msg, \
+    o.oper_time, o.cost_time";
+
+/// 操作日志数据访问
+pub struct SysOperLogRepository;
+
+impl SysOperLogRepository {
+    /// 将查询结果映射为操作日志实体
+    pub fn map_row(row: &MySqlRow) -> Result<SysOperLog> {
+        Ok(SysOperLog {
+            oper_id: DatabaseUtils::get_uuid(row, "oper_id")?,
+            title: row.try_get("title")?,
+            method: row.try_get("method")?,
+            request_method: row.try_get("request_method")?,
+            business_type: row.try_get("business_type")?,
+            oper_name: row.try_get("oper_name")?,
+            dept_name: row.try_get("dept_name")?,
+            oper_url: row.try_get("oper_url")?,
+            oper_ip: row.try_get("oper_ip")?,
+            oper_location: row.try_get("oper_location")?,
+            oper_param: row.try_get("oper_param")?,
+            json_result: row.try_get("json_result")?,
+            status: row.try_get("status")?,
+            error_msg: row.try_get("error_msg")?,
+            oper_time: row.try_get("oper_time")?,
+            cost_time: row.try_get("cost_time")?,
+        })
+    }
+
+    /// 新增操作日志
+    pub async fn insert_oper_log(oper_log: &SysOperLog) -> Result<u64> {
+        let oper_id = oper_log.oper_id.unwrap_or_else(Uuid::new_v4);
+        let result = sqlx::query(
+            "INSERT INTO sys_oper_log (oper_id, title, business_type, method, request_method, oper_name, dept_name, \
+             oper_url, oper_ip, oper_location, oper_param, json_result, status, error_msg, oper_time, cost_time) \
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+            .bind(oper_id.to_string())
+            .bind(&oper_log.title)
+            .bind(oper_log.business_type)
+            .bind(&oper_log.method)
+            .bind(&oper_log.request_method)
+            .bind(&oper_log.oper_name)
+            .bind(&oper_log.dept_name)
+            .bind(&oper_log.oper_url)
+            .bind(&oper_log.oper_ip)
+            .bind(&oper_log.oper_location)
+            .bind(&oper_log.oper_param)
+            .bind(&oper_log.json_result)
+            .bind(oper_log.status)
+            .bind(&oper_log.error_msg)
+            .bind(oper_log.oper_time)
+            .bind(oper_log.cost_time)
+            .execute(require_database_pool()?)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// 根据条件分页查询操作日志，返回当前页数据与总数
+    pub async fn select_oper_log_list(
+        condition: &OperLogSearchCondition,
+        page: &PageDomain,
+    ) -> Result<(Vec<SysOperLog>, i64)> {
+        let (where_sql, params) = Self::build_where(condition);
+        let pool = require_database_pool()?;
+
+        let count_sql = format!("SELECT COUNT(*) FROM sys_oper_log o {}", where_sql);
+        let mut count_query = sqlx::query_scalar::<_, i64>(&count_sql);
+        for param in &params {
+            count_query = count_query.bind(param);
+        }
+        let total = count_query.fetch_one(pool).await?;
+
+        let list_sql = format!(
+            "SELECT {} FROM sys_oper_log o {} ORDER BY o.oper_time DESC LIMIT ? OFFSET ?",
+            OPER_LOG_COLUMNS, where_sql
+        );
+        let mut list_query = sqlx::query(&list_sql);
+        for param in &params {
+            list_query = list_query.bind(param);
+        }
+        let rows = list_query
+            .bind(page.page_size() as i64)
+            .bind(page.offset())
+            .fetch_all(pool)
+            .await?;
+        let list = rows.iter().map(Self::map_row).collect::<Result<Vec<_>>>()?;
+
+        Ok((list, total))
+    }
+
+    /// 根据条件查询全部操作日志（用于导出）
+    pub async fn select_oper_log_export(condition: &OperLogSearchCondition) -> Result<Vec<SysOperLog>> {
+        let (where_sql, params) = Self::build_where(condition);
+        let sql = format!("SELECT {} FROM sys_oper_log o {} ORDER BY o.oper_time DESC", OPER_LOG_COLUMNS, where_sql);
+        let mut query = sqlx::query(&sql);
+        for param in &params {
+            query = query.bind(param);
+        }
+        let rows = query.fetch_all(require_database_pool()?).await?;
+        rows.iter().map(Self::map_row).collect()
+    }
+
+    /// 通过日志主键查询操作日志
+    pub async fn select_oper_log_by_id(oper_id: &Uuid) -> Result<Option<SysOperLog>> {
+        let sql = format!("SELECT {} FROM sys_oper_log o WHERE o.oper_id = ?", OPER_LOG_COLUMNS);
+        let row = sqlx::query(&sql)
+            .bind(oper_id.to_string())
+            .fetch_optional(require_database_pool()?)
+            .await?;
+        row.as_ref().map(Self::map_row).transpose()
+    }
+
+    /// 批量删除操作日志
+    pub async fn delete_oper_log_by_ids(oper_ids: &[Uuid]) -> Result<u64> {
+        if oper_ids.is_empty() {
+            return Ok(0);
+        }
+        let placeholders = vec!["?"; oper_ids.len()].join(", ");
+        let sql = format!("DELETE FROM sys_oper_log WHERE oper_id IN ({})", placeholders);
+        let mut query = sqlx::query(&sql);
+        for oper_id in oper_ids {
+            query = query.bind(oper_id.to_string());
+        }
+        Ok(query.execute(require_database_pool()?).await?.rows_affected())
+    }
+
+    /// 清空操作日志
+    pub async fn clean_oper_log() -> Result<()> {
+        sqlx::query("TRUNCATE TABLE sys_oper_log")
+            .execute(require_database_pool()?)
+            .await?;
+        Ok(())
+    }
+
+    /// 构建查询条件
+    fn build_where(condition: &OperLogSearchCondition) -> (String, Vec<String>) {
+        let mut conditions = Vec::new();
+        let mut params = Vec::new();
+        if let Some(title) = condition.title.as_deref().filter(|value| !value.trim().is_empty()) {
+            conditions.push("o.title LIKE ?");
+            params.push(format!("%{}%", title.trim()));
+        }
+        if let Some(oper_name) = condition.oper_name.as_deref().filter(|value| !value.trim().is_empty()) {
+            conditions.push("o.oper_name LIKE ?");
+            params.push(format!("%{}%", oper_name.trim()));
+        }
+        if let Some(oper_ip) = condition.oper_ip.as_deref().filter(|value| !value.trim().is_empty()) {
+            conditions.push("o.oper_ip LIKE ?");
+            params.push(format!("%{}%", oper_ip.trim()));
+        }
+        if let Some(business_type) = condition.business_type {
+            conditions.push("o.business_type = ?");
+            params.push(business_type.to_string());
+        }
+        if let Some(status) = condition.status {
+            conditions.push("o.status = ?");
+            params.push(status.to_string());
+        }
+        if let Some(begin_time) = condition.begin_time.as_deref().filter(|value| !value.trim().is_empty()) {
+            conditions.push("DATE(o.oper_time) >= DATE(?)");
+            params.push(begin_time.trim().to_string());
+        }
+        if let Some(end_time) = condition.end_time.as_deref().filter(|value| !value.trim().is_empty()) {
+            conditions.push("DATE(o.oper_time) <= DATE(?)");
+            params.push(end_time.trim().to_string());
+        }
+        if conditions.is_empty() {
+            (String::new(), params)
+        } else {
+            (format!("WHERE {}", conditions.join(" AND ")), params)
+        }
+    }
+}
@@ -0,0 +1,204 @@
+use sqlx::{mysql::MySqlRow, Row};
+use uuid::Uuid;
+use zzyl_common::error::Result;
+use zzyl_common::PageDomain;
+use zzyl_framework::database::{require_database_pool, DatabaseUtils};
+use crate::domain::{PostSearchCondition, SysPost};
+
+/// 岗位表查询列
+pub const POST_COLUMNS: &str = "p.post_id, p.post_code, p.post_name, p.post_sort, p.status, \
+    p.create_by, p.create_time, p.update_by, p.update_time, p.remark";
+
+/// 岗位数据访问
+pub struct SysPostRepository;
+
+impl SysPostRepository {
+    /// 将查询结果映射为岗位实体
+    pub fn map_row(row: &MySqlRow) -> Result<SysPost> {
+        Ok(SysPost {
+            post_id: DatabaseUtils::get_uuid(row, "post_id")?,
+            post_code: row.try_get("post_code")?,
+            post_name: row.try_get("post_name")?,
+            post_sort: row.try_get("post_sort")?,
+            status: row.try_get("status")?,
+            base: DatabaseUtils::get_base_entity(row)?,
+        })
+    }
+
+    /// 根据条件分页查询岗位，返回当前页数据与总数
+    pub async fn select_post_list(condition: &PostSearchCondition, page: &PageDomain) -> Result<(Vec<SysPost>, i64)> {
+        let (where_sql, params) = Self::build_where(condition);
+        let pool = require_database_pool()?;
+
+        let count_sql = format!("SELECT COUNT(*) FROM sys_post p {}", where_sql);
+        let mut count_query = sqlx::query_scalar::<_, i64>(&count_sql);
+        for param in &params {
+            count_query = count_query.bind(param);
+        }
+        let total = count_query.fetch_one(pool).await?;
+
+        let list_sql = format!("SELECT {} FROM sys_post p {} ORDER BY p.post_sort LIMIT ? OFFSET ?", POST_COLUMNS, where_sql);
+        let mut list_query = sqlx::query(&list_sql);
+        for param in &params {
+            list_query = list_query.bind(param);
+        }
+        let rows = list_query
+            .bind(page.page_size() as i64)
+            .bind(page.offset())
+            .fetch_all(pool)
+            .await?;
+        let list = rows.iter().map(Self::map_row).collect::<Result<Vec<_>>>()?;
+
+        Ok((list, total))
+    }
+
+    /// 查询全部岗位
+    pub async fn select_post_all() -> Result<Vec<SysPost>> {
+        let sql = format!("SELECT {} FROM sys_post p ORDER BY p.post_sort", POST_COLUMNS);
+        let rows = sqlx::query(&sql).fetch_all(require_database_pool()?).await?;
+        rows.iter().map(Self::map_row).collect()
+    }
+
+    /// 根据条件查询全部岗位（用于导出）
+    pub async fn select_post_export(condition: &PostSearchCondition) -> Result<Vec<SysPost>> {
+        let (where_sql, params) = Self::build_where(condition);
+        let sql = format!("SELECT {} FROM sys_post p {} ORDER BY p.post_sort", POST_COLUMNS, where_sql);
+        let mut query = sqlx::query(&sql);
+        for param in &params {
+            query = query.bind(param);
+        }
+        let rows = query.fetch_all(require_database_pool()?).await?;
+        rows.iter().map(Self::map_row).collect()
+    }
+
+    /// 通过岗位ID查询岗位
+    pub async fn select_post_by_id(post_id: &Uuid) -> Result<Option<SysPost>> {
+        let sql = format!("SELECT {} FROM sys_post p WHERE p.post_id = ?", POST_COLUMNS);
+        let row = sqlx::query(&sql)
+            .bind(post_id.to_string())
+            .fetch_optional(require_database_pool()?)
+            .await?;
+        row.as_ref().map(Self::map_row).transpose()
+    }
+
+    /// 查询用户已分配的岗位
+    pub async fn select_posts_by_user_id(user_id: &Uuid) -> Result<Vec<SysPost>> {
+        let sql = format!(
+            "SELECT {} FROM sys_post p INNER JOIN sys_user_post up ON up.post_id = p.post_id \
+             WHERE up.user_id = ? ORDER BY p.post_sort",
+            POST_COLUMNS
+        );
+        let rows = sqlx::query(&sql)
+            .bind(user_id.to_string())
+            .fetch_all(require_database_pool()?)
+            .await?;
+        rows.iter().map(Self::map_row).collect()
+    }
+
+    /// 通过岗位编码查询岗位ID（用于唯一性校验）
+    pub async fn select_post_id_by_code(post_code: &str) -> Result<Option<Uuid>> {
+        Self::select_post_id_by("post_code", post_code).await
+    }
+
+    /// 通过岗位名称查询岗位ID（用于唯一性校验）
+    pub async fn select_post_id_by_name(post_name: &str) -> Result<Option<Uuid>> {
+        Self::select_post_id_by("post_name", post_name).await
+    }
+
+    /// 统计岗位已分配的用户数量
+    pub async fn count_user_by_post_id(post_id: &Uuid) -> Result<i64> {
+        let count = sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM sys_user_post WHERE post_id = ?")
+            .bind(post_id.to_string())
+            .fetch_one(require_database_pool()?)
+            .await?;
+        Ok(count)
+    }
+
+    /// 新增岗位
+    pub async fn insert_post(post: &SysPost) -> Result<u64> {
+        let result = sqlx::query(
+            "INSERT INTO sys_post (post_id, post_code, post_name, post_sort, status, create_by, create_time, remark) \
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+            .bind(post.post_id.map(|id| id.to_string()))
+            .bind(&post.post_code)
+            .bind(&post.post_name)
+            .bind(post.post_sort)
+            .bind(&post.status)
+            .bind(&post.base.create_by)
+            .bind(post.base.create_time)
+            .bind(&post.base.remark)
+            .execute(require_database_pool()?)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// 修改岗位
+    pub async fn update_post(post: &SysPost) -> Result<u64> {
+        let result = sqlx::query(
+            "UPDATE sys_post SET post_code = ?, post_name = ?, post_sort = ?, status = ?, update_by = ?, \
+             update_time = ?, remark = ? WHERE post_id = ?",
+        )
+            .bind(&post.post_code)
+            .bind(&post.post_name)
+            .bind(post.post_sort)
+            .bind(&post.status)
+            .bind(&post.base.update_by)
+            .bind(post.base.update_time)
+            .bind(&post.base.remark)
+            .bind(post.post_id.map(|id| id.to_string()))
+            .execute(require_database_pool()?)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// 批量删除岗位
+    pub async fn delete_post_by_ids(post_ids: &[Uuid]) -> Result<u64> {
+        if post_ids.is_empty() {
+            return Ok(0);
+        }
+        let placeholders = vec!["?"; post_ids.len()].join(", ");
+        let sql = format!("DELETE FROM sys_post WHERE post_id IN ({})", placeholders);
+        let mut query = sqlx::query(&sql);
+        for post_id in post_ids {
+            query = query.bind(post_id.to_string());
+        }
+        Ok(query.execute(require_database_pool()?).await?.rows_affected())
+    }
+
+    /// 按指定列查询岗位ID
+    async fn select_post_id_by(column: &'static str, value: &str) -> Result<Option<Uuid>> {
+        let sql = format!("SELECT p.post_id FROM sys_post p WHERE p.{} = ? LIMIT 1", column);
+        let row = sqlx::query(&sql)
+            .bind(value)
+            .fetch_optional(require_database_pool()?)
+            .await?;
+        match row {
+            Some(row) => DatabaseUtils::get_uuid(&row, "post_id"),
+            None => Ok(None),
+        }
+    }
+
+    /// 构建查询条件
+    fn build_where(condition: &PostSearchCondition) -> (String, Vec<String>) {
+        let mut conditions = Vec::new();
+        let mut params = Vec::new();
+        if let Some(post_code) = condition.post_code.as_deref().filter(|value| !value.trim().is_empty()) {
+            conditions.push("p.post_code LIKE ?");
+            params.push(format!("%{}%", post_code.trim()));
+        }
+        if let Some(post_name) = condition.post_name.as_deref().filter(|value| !value.trim().is_empty()) {
+            conditions.push("p.post_name LIKE ?");
+            params.push(format!("%{}%", post_name.trim()));
+        }
+        if let Some(status) = condition.status.as_deref().filter(|value| !value.trim().is_empty()) {
+            conditions.push("p.status = ?");
+            params.push(status.trim().to_string());
+        }
+        if conditions.is_empty() {
+            (String::new(), params)
+        } else {
+            (format!("WHERE {}", conditions.join(" AND ")), params)
+        }
+    }
+}
@@ -0,0 +1,251 @@
+use chrono::Utc;
+use sqlx::{mysql::MySqlRow, MySqlConnection, Row};
+use uuid::Uuid;
+use zzyl_common::error::Result;
+use zzyl_common::PageDomain;
+use zzyl_framework::database::{require_database_pool, DatabaseUtils};
+use zzyl_framework::security::DataScopeSql;
+use crate::domain::{RoleSearchCondition, SysRole};
+
+/// 角色表查询列
+pub const ROLE_COLUMNS: &str = "r.role_id, r.role_name, r.role_key, r.role_sort, r.data_scope, \
+    r.menu_check_strictly, r.dept_check_strictly, r.status, r.del_flag, \
+    r.create_by, r.create_time, r.update_by, r.update_time, r.remark";
+
+/// 角色数据访问
+pub struct SysRoleRepository;
+
+impl SysRoleRepository {
+    /// 将查询结果映射为角色实体
+    pub fn map_row(row: &MySqlRow) -> Result<SysRole> {
+        Ok(SysRole {
+            role_id: DatabaseUtils::get_uuid(row, "role_id")?,
+            role_name: row.try_get("role_name")?,
+            role_key: row.try_get("role_key")?,
+            role_sort: row.try_get("role_sort")?,
+            data_scope: row.try_get("data_scope")?,
+            menu_check_strictly: row.try_get("menu_check_strictly")?,
+            dept_check_strictly: row.try_get("dept_check_strictly")?,
+            status: row.try_get("status")?,
+            del_flag: row.try_get("del_flag")?,
+            remark: row.try_get("remark")?,
+            base: DatabaseUtils::get_base_entity(row)?,
+        })
+    }
+
+    /// 查询用户拥有的正常角色
+    pub async fn select_roles_by_user_id(user_id: &Uuid) -> Result<Vec<SysRole>> {
+        let sql = format!(
+            "SELECT {} FROM sys_role r \
+             INNER JOIN sys_user_role ur ON ur.role_id = r.role_id \
+             WHERE ur.user_id = ? AND r.del_flag = '0' AND r.status = '0' \
+             ORDER BY r.role_sort",
+            ROLE_COLUMNS
+        );
+        let rows = sqlx::query(&sql)
+            .bind(user_id.to_string())
+            .fetch_all(require_database_pool()?)
+            .await?;
+        rows.iter().map(Self::map_row).collect()
+    }
+
+    /// 根据条件分页查询角色列表，返回当前页数据与总数
+    pub async fn select_role_list(condition: &RoleSearchCondition, page: &PageDomain) -> Result<(Vec<SysRole>, i64)> {
+        let mut conditions = vec!["r.del_flag = '0'".to_string()];
+        let mut params = Vec::new();
+        if let Some(role_name) = condition.role_name.as_deref().filter(|value| !value.trim().is_empty()) {
+            conditions.push("r.role_name LIKE ?".to_string());
+            params.push(format!("%{}%", role_name.trim()));
+        }
+        if let Some(role_key) = condition.role_key.as_deref().filter(|value| !value.trim().is_empty()) {
+            conditions.push("r.role_key LIKE ?".to_string());
+            params.push(format!("%{}%", role_key.trim()));
+        }
+        if let Some(status) = condition.status.as_deref().filter(|value| !value.trim().is_empty()) {
+            conditions.push("r.status = ?".to_string());
+            params.push(status.trim().to_string());
+        }
+        let where_sql = format!("WHERE {}", conditions.join(" AND "));
+        let pool = require_database_pool()?;
+
+        let count_sql = format!("SELECT COUNT(*) FROM sys_role r {}", where_sql);
+        let mut count_query = sqlx::query_scalar::<_, i64>(&count_sql);
+        for param in &params {
+            count_query = count_query.bind(param);
+        }
+        let total = count_query.fetch_one(pool).await?;
+
+        let list_sql = format!(
+            "SELECT {} FROM sys_role r {} ORDER BY r.role_sort LIMIT ? OFFSET ?",
+            ROLE_COLUMNS, where_sql
+        );
+        let mut list_query = sqlx::query(&list_sql);
+        for param in &params {
+            list_query = list_query.bind(param);
+        }
+        let rows = list_query
+            .bind(page.page_size() as i64)
+            .bind(page.offset())
+            .fetch_all(pool)
+            .await?;
+        let list = rows.iter().map(Self::map_row).collect::<Result<Vec<_>>>()?;
+
+        Ok((list, total))
+    }
+
+    /// 查询全部未删除的角色
+    pub async fn select_role_all() -> Result<Vec<SysRole>> {
+        let sql = format!("SELECT {} FROM sys_role r WHERE r.del_flag = '0' ORDER BY r.role_sort", ROLE_COLUMNS);
+        let rows = sqlx::query(&sql)
+            .fetch_all(require_database_pool()?)
+            .await?;
+        rows.iter().map(Self::map_row).collect()
+    }
+
+    /// 通过角色ID查询角色
+    pub async fn select_role_by_id(role_id: &Uuid) -> Result<Option<SysRole>> {
+        let sql = format!("SELECT {} FROM sys_role r WHERE r.role_id = ? AND r.del_flag = '0'", ROLE_COLUMNS);
+        let row = sqlx::query(&sql)
+            .bind(role_id.to_string())
+            .fetch_optional(require_database_pool()?)
+            .await?;
+        row.as_ref().map(Self::map_row).transpose()
+    }
+
+    /// 统计数据权限范围内指定ID的角色数量（按角色下用户所属部门过滤）
+    pub async fn count_role_in_scope(role_id: &Uuid, data_scope: &DataScopeSql) -> Result<i64> {
+        let sql = format!(
+            "SELECT COUNT(DISTINCT r.role_id) FROM sys_role r \
+             LEFT JOIN sys_user_role ur ON ur.role_id = r.role_id \
+             LEFT JOIN sys_user u ON u.user_id = ur.user_id \
+             LEFT JOIN sys_dept d ON u.dept_id = d.dept_id \
+             WHERE r.role_id = ? AND r.del_flag = '0'{}",
+            data_scope.and_clause()
+        );
+        let mut query = sqlx::query_scalar::<_, i64>(&sql).bind(role_id.to_string());
+        for param in &data_scope.params {
+            query = query.bind(param);
+        }
+        Ok(query.fetch_one(require_database_pool()?).await?)
+    }
+
+    /// 通过角色名称查询角色ID（用于唯一性校验）
+    pub async fn select_role_id_by_name(role_name: &str) -> Result<Option<Uuid>> {
+        Self::select_role_id_by("role_name", role_name).await
+    }
+
+    /// 通过权限字符查询角色ID（用于唯一性校验）
+    pub async fn select_role_id_by_key(role_key: &str) -> Result<Option<Uuid>> {
+        Self::select_role_id_by("role_key", role_key).await
+    }
+
+    /// 新增角色
+    pub async fn insert_role(conn: &mut MySqlConnection, role: &SysRole) -> Result<u64> {
+        let result = sqlx::query(
+            "INSERT INTO sys_role (role_id, role_name, role_key, role_sort, data_scope, menu_check_strictly, \
+             dept_check_strictly, status, del_flag, create_by, create_time, remark) \
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, '0', ?, ?, ?)",
+        )
+            .bind(role.role_id.map(|id| id.to_string()))
+            .bind(&role.role_name)
+            .bind(&role.role_key)
+            .bind(role.role_sort)
+            .bind(&role.data_scope)
+            .bind(role.menu_check_strictly)
+            .bind(role.dept_check_strictly)
+            .bind(&role.status)
+            .bind(&role.base.create_by)
+            .bind(role.base.create_time)
+            .bind(&role.remark)
+            .execute(conn)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// 修改角色基本信息
+    pub async fn update_role(conn: &mut MySqlConnection, role: &SysRole) -> Result<u64> {
+        let result = sqlx::query(
+            "UPDATE sys_role SET role_name = ?, role_key = ?, role_sort = ?, menu_check_strictly = ?, \
+             dept_check_strictly = ?, status = ?, update_by = ?, update_time = ?, remark = ? \
+             WHERE role_id = ? AND del_flag = '0'",
+        )
+            .bind(&role.role_name)
+            .bind(&role.role_key)
+            .bind(role.role_sort)
+            .bind(role.menu_check_strictly)
+            .bind(role.dept_check_strictly)
+            .bind(&role.status)
+            .bind(&role.base.update_by)
+            .bind(role.base.update_time)
+            .bind(&role.remark)
+            .bind(role.role_id.map(|id| id.to_string()))
+            .execute(conn)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// 修改角色数据范围
+    pub async fn update_data_scope(
+        conn: &mut MySqlConnection,
+        role_id: &Uuid,
+        data_scope: &str,
+        update_by: &str,
+    ) -> Result<u64> {
+        let result = sqlx::query(
+            "UPDATE sys_role SET data_scope = ?, update_by = ?, update_time = ? WHERE role_id = ? AND del_flag = '0'",
+        )
+            .bind(data_scope)
+            .bind(update_by)
+            .bind(Utc::now())
+            .bind(role_id.to_string())
+            .execute(conn)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// 修改角色状态
+    pub async fn update_status(role_id: &Uuid, status: &str, update_by: &str) -> Result<u64> {
+        let result = sqlx::query(
+            "UPDATE sys_role SET status = ?, update_by = ?, update_time = ? WHERE role_id = ? AND del_flag = '0'",
+        )
+            .bind(status)
+            .bind(update_by)
+            .bind(Utc::now())
+            .bind(role_id.to_string())
+            .execute(require_database_pool()?)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// 批量逻辑删除角色
+    pub async fn delete_role_by_ids(conn: &mut MySqlConnection, role_ids: &[Uuid], update_by: &str) -> Result<u64> {
+        if role_ids.is_empty() {
+            return Ok(0);
+        }
+        let placeholders = vec!["?"; role_ids.len()].join(", ");
+        let sql = format!(
+            "UPDATE sys_role SET del_flag = '2', update_by = ?, update_time = ? WHERE role_id IN ({})",
+            placeholders
+        );
+        let mut query = sqlx::query(&sql)
+            .bind(update_by)
+            .bind(Utc::now());
+        for role_id in role_ids {
+            query = query.bind(role_id.to_string());
+        }
+        Ok(query.execute(conn).await?.rows_affected())
+    }
+
+    /// 按指定列查询未删除角色的ID
+    async fn select_role_id_by(column: &'static str, value: &str) -> Result<Option<Uuid>> {
+        let sql = format!("SELECT r.role_id FROM sys_role r WHERE r.{} = ? AND r.del_flag = '0' LIMIT 1", column);
+        let row = sqlx::query(&sql)
+            .bind(value)
+            .fetch_optional(require_database_pool()?)
+            .await?;
+        match row {
+            Some(row) => DatabaseUtils::get_uuid(&row, "role_id"),
+            None => Ok(None),
+        }
+    }
+}
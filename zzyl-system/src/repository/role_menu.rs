@@ -0,0 +1,46 @@
+use sqlx::MySqlConnection;
+use uuid::Uuid;
+use zzyl_common::error::Result;
+use zzyl_framework::database::{require_database_pool, DatabaseUtils};
+
+/// 角色菜单关联数据访问
+pub struct SysRoleMenuRepository;
+
+impl SysRoleMenuRepository {
+    /// 查询角色关联的菜单ID
+    pub async fn select_menu_ids_by_role_id(role_id: &Uuid) -> Result<Vec<Uuid>> {
+        let rows = sqlx::query("SELECT menu_id FROM sys_role_menu WHERE role_id = ?")
+            .bind(role_id.to_string())
+            .fetch_all(require_database_pool()?)
+            .await?;
+        DatabaseUtils::get_uuids(&rows, "menu_id")
+    }
+
+    /// 删除角色的全部菜单关联
+    pub async fn delete_by_role_ids(conn: &mut MySqlConnection, role_ids: &[Uuid]) -> Result<u64> {
+        if role_ids.is_empty() {
+            return Ok(0);
+        }
+        let placeholders = vec!["?"; role_ids.len()].join(", ");
+        let sql = format!("DELETE FROM sys_role_menu WHERE role_id IN ({})", placeholders);
+        let mut query = sqlx::query(&sql);
+        for role_id in role_ids {
+            query = query.bind(role_id.to_string());
+        }
+        Ok(query.execute(conn).await?.rows_affected())
+    }
+
+    /// 批量新增角色菜单关联
+    pub async fn insert_role_menus(conn: &mut MySqlConnection, role_id: &Uuid, menu_ids: &[Uuid]) -> Result<u64> {
+        if menu_ids.is_empty() {
+            return Ok(0);
+        }
+        let placeholders = vec!["(?, ?)"; menu_ids.len()].join(", ");
+        let sql = format!("INSERT INTO sys_role_menu (role_id, menu_id) VALUES {}", placeholders);
+        let mut query = sqlx::query(&sql);
+        for menu_id in menu_ids {
+            query = query.bind(role_id.to_string()).bind(menu_id.to_string());
+        }
+        Ok(query.execute(conn).await?.rows_affected())
+    }
+}
@@ -0,0 +1,371 @@
+use chrono::{DateTime, Utc};
+use sqlx::{mysql::MySqlRow, MySqlConnection, Row};
+use uuid::Uuid;
+use zzyl_common::enums::{DelFlag, UserStatus};
+use zzyl_common::error::Result;
+use zzyl_common::PageDomain;
+use zzyl_framework::database::{require_database_pool, DatabaseUtils};
+use zzyl_framework::security::DataScopeSql;
+use crate::domain::{SysUser, UserSearchCondition};
+
+/// 用户表查询列
+pub const USER_COLUMNS: &str = "u.user_id, u.dept_id, u.username, u.nick_name, u.email, u.phone, u.sex, u.avatar, \
+    u.password, u.salt, u.status, u.del_flag, u.login_ip, u.login_date, \
+    u.create_by, u.create_time, u.update_by, u.update_time, u.remark";
+
+/// 用户数据访问
+pub struct SysUserRepository;
+
+impl SysUserRepository {
+    /// 将查询结果映射为用户实体
+    pub fn map_row(row: &MySqlRow) -> Result<SysUser> {
+        Ok(SysUser {
+            user_id: DatabaseUtils::get_uuid(row, "user_id")?,
+            username: row.try_get("username")?,
+            nick_name: row.try_get("nick_name")?,
+            email: row.try_get("email")?,
+            phone: row.try_get("phone")?,
+            sex: row.try_get("sex")?,
+            avatar: row.try_get("avatar")?,
+            password: row.try_get("password")?,
+            salt: row.try_get("salt")?,
+            status: row.try_get::<i32, _>("status")?.into(),
+            del_flag: row.try_get::<i32, _>("del_flag")?.into(),
+            login_ip: row.try_get("login_ip")?,
+            login_date: row.try_get::<Option<DateTime<Utc>>, _>("login_date")?,
+            dept_id: DatabaseUtils::get_uuid(row, "dept_id")?,
+            base: DatabaseUtils::get_base_entity(row)?,
+        })
+    }
+
+    /// 通过用户名查询用户
+    pub async fn select_user_by_username(username: &str) -> Result<Option<SysUser>> {
+        let sql = format!("SELECT {} FROM sys_user u WHERE u.username = ? AND u.del_flag = ?", USER_COLUMNS);
+        let row = sqlx::query(&sql)
+            .bind(username)
+            .bind(i32::from(DelFlag::Exists))
+            .fetch_optional(require_database_pool()?)
+            .await?;
+        row.as_ref().map(Self::map_row).transpose()
+    }
+
+    /// 通过用户ID查询用户
+    pub async fn select_user_by_id(user_id: &Uuid) -> Result<Option<SysUser>> {
+        let sql = format!("SELECT {} FROM sys_user u WHERE u.user_id = ? AND u.del_flag = ?", USER_COLUMNS);
+        let row = sqlx::query(&sql)
+            .bind(user_id.to_string())
+            .bind(i32::from(DelFlag::Exists))
+            .fetch_optional(require_database_pool()?)
+            .await?;
+        row.as_ref().map(Self::map_row).transpose()
+    }
+
+    /// 根据条件分页查询用户列表，返回当前页数据与总数
+    pub async fn select_user_list(
+        condition: &UserSearchCondition,
+        data_scope: &DataScopeSql,
+        page: &PageDomain,
+    ) -> Result<(Vec<SysUser>, i64)> {
+        Self::select_page(condition, data_scope, None, page).await
+    }
+
+    /// 分页查询已分配指定角色的用户
+    pub async fn select_allocated_list(
+        role_id: &Uuid,
+        condition: &UserSearchCondition,
+        data_scope: &DataScopeSql,
+        page: &PageDomain,
+    ) -> Result<(Vec<SysUser>, i64)> {
+        let role_condition = "u.user_id IN (SELECT ur.user_id FROM sys_user_role ur WHERE ur.role_id = ?)";
+        Self::select_page(condition, data_scope, Some((role_condition, role_id.to_string())), page).await
+    }
+
+    /// 分页查询未分配指定角色的用户
+    pub async fn select_unallocated_list(
+        role_id: &Uuid,
+        condition: &UserSearchCondition,
+        data_scope: &DataScopeSql,
+        page: &PageDomain,
+    ) -> Result<(Vec<SysUser>, i64)> {
+        let role_condition = "u.user_id NOT IN (SELECT ur.user_id FROM sys_user_role ur WHERE ur.role_id = ?)";
+        Self::select_page(condition, data_scope, Some((role_condition, role_id.to_string())), page).await
+    }
+
+    /// 统计数据权限范围内指定ID的用户数量
+    pub async fn count_user_in_scope(user_id: &Uuid, data_scope: &DataScopeSql) -> Result<i64> {
+        let sql = format!(
+            "SELECT COUNT(*) FROM sys_user u LEFT JOIN sys_dept d ON u.dept_id = d.dept_id \
+             WHERE u.user_id = ? AND u.del_flag = ?{}",
+            data_scope.and_clause()
+        );
+        let mut query = sqlx::query_scalar::<_, i64>(&sql)
+            .bind(user_id.to_string())
+            .bind(i32::from(DelFlag::Exists));
+        for param in &data_scope.params {
+            query = query.bind(param);
+        }
+        Ok(query.fetch_one(require_database_pool()?).await?)
+    }
+
+    /// 通过用户名查询用户ID（用于唯一性校验）
+    pub async fn select_user_id_by_username(username: &str) -> Result<Option<Uuid>> {
+        Self::select_user_id_by("username", username).await
+    }
+
+    /// 通过手机号码查询用户ID（用于唯一性校验）
+    pub async fn select_user_id_by_phone(phone: &str) -> Result<Option<Uuid>> {
+        Self::select_user_id_by("phone", phone).await
+    }
+
+    /// 通过邮箱查询用户ID（用于唯一性校验）
+    pub async fn select_user_id_by_email(email: &str) -> Result<Option<Uuid>> {
+        Self::select_user_id_by("email", email).await
+    }
+
+    /// 新增用户
+    pub async fn insert_user(conn: &mut MySqlConnection, user: &SysUser) -> Result<u64> {
+        let result = sqlx::query(
+            "INSERT INTO sys_user (user_id, dept_id, username, nick_name, email, phone, sex, avatar, password, salt, \
+             status, del_flag, create_by, create_time, remark) \
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+            .bind(user.user_id.map(|id| id.to_string()))
+            .bind(user.dept_id.map(|id| id.to_string()))
+            .bind(&user.username)
+            .bind(&user.nick_name)
+            .bind(&user.email)
+            .bind(&user.phone)
+            .bind(&user.sex)
+            .bind(&user.avatar)
+            .bind(&user.password)
+            .bind(&user.salt)
+            .bind(i32::from(user.status))
+            .bind(i32::from(user.del_flag))
+            .bind(&user.base.create_by)
+            .bind(user.base.create_time)
+            .bind(&user.base.remark)
+            .execute(conn)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// 修改用户基本信息（不含用户名与密码）
+    pub async fn update_user(conn: &mut MySqlConnection, user: &SysUser) -> Result<u64> {
+        let result = sqlx::query(
+            "UPDATE sys_user SET dept_id = ?, nick_name = ?, email = ?, phone = ?, sex = ?, avatar = ?, \
+             status = ?, update_by = ?, update_time = ?, remark = ? WHERE user_id = ? AND del_flag = ?",
+        )
+            .bind(user.dept_id.map(|id| id.to_string()))
+            .bind(&user.nick_name)
+            .bind(&user.email)
+            .bind(&user.phone)
+            .bind(&user.sex)
+            .bind(&user.avatar)
+            .bind(i32::from(user.status))
+            .bind(&user.base.update_by)
+            .bind(user.base.update_time)
+            .bind(&user.base.remark)
+            .bind(user.user_id.map(|id| id.to_string()))
+            .bind(i32::from(DelFlag::Exists))
+            .execute(conn)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// 修改个人信息
+    pub async fn update_profile(user: &SysUser) -> Result<u64> {
+        let result = sqlx::query(
+            "UPDATE sys_user SET nick_name = ?, email = ?, phone = ?, sex = ?, update_by = ?, update_time = ? \
+             WHERE user_id = ? AND del_flag = ?",
+        )
+            .bind(&user.nick_name)
+            .bind(&user.email)
+            .bind(&user.phone)
+            .bind(&user.sex)
+            .bind(&user.base.update_by)
+            .bind(user.base.update_time)
+            .bind(user.user_id.map(|id| id.to_string()))
+            .bind(i32::from(DelFlag::Exists))
+            .execute(require_database_pool()?)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// 修改用户头像
+    pub async fn update_avatar(user_id: &Uuid, avatar: &str, update_by: &str) -> Result<u64> {
+        let result = sqlx::query(
+            "UPDATE sys_user SET avatar = ?, update_by = ?, update_time = ? WHERE user_id = ? AND del_flag = ?",
+        )
+            .bind(avatar)
+            .bind(update_by)
+            .bind(Utc::now())
+            .bind(user_id.to_string())
+            .bind(i32::from(DelFlag::Exists))
+            .execute(require_database_pool()?)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// 修改用户状态
+    pub async fn update_status(user_id: &Uuid, status: UserStatus, update_by: &str) -> Result<u64> {
+        let result = sqlx::query(
+            "UPDATE sys_user SET status = ?, update_by = ?, update_time = ? WHERE user_id = ? AND del_flag = ?",
+        )
+            .bind(i32::from(status))
+            .bind(update_by)
+            .bind(Utc::now())
+            .bind(user_id.to_string())
+            .bind(i32::from(DelFlag::Exists))
+            .execute(require_database_pool()?)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// 重置用户密码
+    pub async fn reset_password(user_id: &Uuid, password: &str, update_by: &str) -> Result<u64> {
+        let result = sqlx::query(
+            "UPDATE sys_user SET password = ?, update_by = ?, update_time = ? WHERE user_id = ? AND del_flag = ?",
+        )
+            .bind(password)
+            .bind(update_by)
+            .bind(Utc::now())
+            .bind(user_id.to_string())
+            .bind(i32::from(DelFlag::Exists))
+            .execute(require_database_pool()?)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// 批量逻辑删除用户
+    pub async fn delete_user_by_ids(conn: &mut MySqlConnection, user_ids: &[Uuid], update_by: &str) -> Result<u64> {
+        if user_ids.is_empty() {
+            return Ok(0);
+        }
+        let placeholders = vec!["?"; user_ids.len()].join(", ");
+        let sql = format!(
+            "UPDATE sys_user SET del_flag = ?, update_by = ?, update_time = ? WHERE user_id IN ({})",
+            placeholders
+        );
+        let mut query = sqlx::query(&sql)
+            .bind(i32::from(DelFlag::Deleted))
+            .bind(update_by)
+            .bind(Utc::now());
+        for user_id in user_ids {
+            query = query.bind(user_id.to_string());
+        }
+        Ok(query.execute(conn).await?.rows_affected())
+    }
+
+    /// 更新用户登录信息
+    pub async fn update_login_info(user_id: &Uuid, login_ip: &str, login_date: DateTime<Utc>) -> Result<u64> {
+        let result = sqlx::query("UPDATE sys_user SET login_ip = ?, login_date = ? WHERE user_id = ?")
+            .bind(login_ip)
+            .bind(login_date)
+            .bind(user_id.to_string())
+            .execute(require_database_pool()?)
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// 分页查询用户，可附加一个带单个参数的额外条件
+    async fn select_page(
+        condition: &UserSearchCondition,
+        data_scope: &DataScopeSql,
+        extra: Option<(&str, String)>,
+        page: &PageDomain,
+    ) -> Result<(Vec<SysUser>, i64)> {
+        let (mut where_sql, mut params) = Self::build_where(condition, data_scope);
+        if let Some((extra_sql, extra_param)) = extra {
+            where_sql.push_str(&format!(" AND {}", extra_sql));
+            params.push(extra_param);
+        }
+        let pool = require_database_pool()?;
+
+        let count_sql = format!(
+            "SELECT COUNT(*) FROM sys_user u LEFT JOIN sys_dept d ON u.dept_id = d.dept_id {}",
+            where_sql
+        );
+        let mut count_query = sqlx::query_scalar::<_, i64>(&count_sql);
+        for param in &params {
+            count_query = count_query.bind(param);
+        }
+        let total = count_query.fetch_one(pool).await?;
+
+        let list_sql = format!(
+            "SELECT {} FROM sys_user u LEFT JOIN sys_dept d ON u.dept_id = d.dept_id {} \
+             ORDER BY u.create_time DESC LIMIT ? OFFSET ?",
+            USER_COLUMNS, where_sql
+        );
+        let mut list_query = sqlx::query(&list_sql);
+        for param in &params {
+            list_query = list_query.bind(param);
+        }
+        let rows = list_query
+            .bind(page.page_size() as i64)
+            .bind(page.offset())
+            .fetch_all(pool)
+            .await?;
+        let list = rows.iter().map(Self::map_row).collect::<Result<Vec<_>>>()?;
+
+        Ok((list, total))
+    }
+
+    /// 按指定列查询未删除用户的ID
+    async fn select_user_id_by(column: &'static str, value: &str) -> Result<Option<Uuid>> {
+        let sql = format!("SELECT u.user_id FROM sys_user u WHERE u.{} = ? AND u.del_flag = ? LIMIT 1", column);
+        let row = sqlx::query(&sql)
+            .bind(value)
+            .bind(i32::from(DelFlag::Exists))
+            .fetch_optional(require_database_pool()?)
+            .await?;
+        match row {
+            Some(row) => DatabaseUtils::get_uuid(&row, "user_id"),
+            None => Ok(None),
+        }
+    }
+
+    /// 构建用户列表查询条件
+    fn build_where(condition: &UserSearchCondition, data_scope: &DataScopeSql) -> (String, Vec<String>) {
+        let mut conditions = vec!["u.del_flag = ?".to_string()];
+        let mut params = vec![i32::from(DelFlag::Exists).to_string()];
+
+        if let Some(username) = condition.username.as_deref().filter(|value| !value.trim().is_empty()) {
+            conditions.push("u.username LIKE ?".to_string());
+            params.push(format!("%{}%", username.trim()));
+        }
+        if let Some(nick_name) = condition.nick_name.as_deref().filter(|value| !value.trim().is_empty()) {
+            conditions.push("u.nick_name LIKE ?".to_string());
+            params.push(format!("%{}%", nick_name.trim()));
+        }
+        if let Some(phone) = condition.phone.as_deref().filter(|value| !value.trim().is_empty()) {
+            conditions.push("u.phone LIKE ?".to_string());
+            params.push(format!("%{}%", phone.trim()));
+        }
+        if let Some(email) = condition.email.as_deref().filter(|value| !value.trim().is_empty()) {
+            conditions.push("u.email LIKE ?".to_string());
+            params.push(format!("%{}%", email.trim()));
+        }
+        if let Some(sex) = condition.sex.as_deref().filter(|value| !value.trim().is_empty()) {
+            conditions.push("u.sex = ?".to_string());
+            params.push(sex.trim().to_string());
+        }
+        if let Some(status) = condition.status {
+            conditions.push("u.status = ?".to_string());
+            params.push(i32::from(status).to_string());
+        }
+        if let Some(dept_id) = condition.dept_id {
+            conditions.push(
+                "(u.dept_id = ? OR u.dept_id IN (SELECT t.dept_id FROM sys_dept t WHERE FIND_IN_SET(?, t.ancestors)))"
+                    .to_string(),
+            );
+            params.push(dept_id.to_string());
+            params.push(dept_id.to_string());
+        }
+        if !data_scope.is_empty() {
+            conditions.push(format!("({})", data_scope.sql));
+            params.extend(data_scope.params.iter().cloned());
+        }
+
+        (format!("WHERE {}", conditions.join(" AND ")), params)
+    }
+}
@@ -0,0 +1,46 @@
+use sqlx::MySqlConnection;
+use uuid::Uuid;
+use zzyl_common::error::Result;
+use zzyl_framework::database::{require_database_pool, DatabaseUtils};
+
+/// 用户岗位关联数据访问
+pub struct SysUserPostRepository;
+
+impl SysUserPostRepository {
+    /// 查询用户关联的岗位ID
+    pub async fn select_post_ids_by_user_id(user_id: &Uuid) -> Result<Vec<Uuid>> {
+        let rows = sqlx::query("SELECT post_id FROM sys_user_post WHERE user_id = ?")
+            .bind(user_id.to_string())
+            .fetch_all(require_database_pool()?)
+            .await?;
+        DatabaseUtils::get_uuids(&rows, "post_id")
+    }
+
+    /// 删除用户的全部岗位关联
+    pub async fn delete_by_user_ids(conn: &mut MySqlConnection, user_ids: &[Uuid]) -> Result<u64> {
+        if user_ids.is_empty() {
+            return Ok(0);
+        }
+        let placeholders = vec!["?"; user_ids.len()].join(", ");
+        let sql = format!("DELETE FROM sys_user_post WHERE user_id IN ({})", placeholders);
+        let mut query = sqlx::query(&sql);
+        for user_id in user_ids {
+            query = query.bind(user_id.to_string());
+        }
+        Ok(query.execute(conn).await?.rows_affected())
+    }
+
+    /// 批量新增用户岗位关联
+    pub async fn insert_user_posts(conn: &mut MySqlConnection, user_id: &Uuid, post_ids: &[Uuid]) -> Result<u64> {
+        if post_ids.is_empty() {
+            return Ok(0);
+        }
+        let placeholders = vec!["(?, ?)"; post_ids.len()].join(", ");
+        let sql = format!("INSERT INTO sys_user_post (user_id, post_id) VALUES {}", placeholders);
+        let mut query = sqlx::query(&sql);
+        for post_id in post_ids {
+            query = query.bind(user_id.to_string()).bind(post_id.to_string());
+        }
+        Ok(query.execute(conn).await?.rows_affected())
+    }
+}
@@ -0,0 +1,89 @@
+use sqlx::MySqlConnection;
+use uuid::Uuid;
+use zzyl_common::error::Result;
+use zzyl_framework::database::{require_database_pool, DatabaseUtils};
+
+/// 用户角色关联数据访问
+pub struct SysUserRoleRepository;
+
+impl SysUserRoleRepository {
+    /// 查询用户关联的角色ID
+    pub async fn select_role_ids_by_user_id(user_id: &Uuid) -> Result<Vec<Uuid>> {
+        let rows = sqlx::query("SELECT role_id FROM sys_user_role WHERE user_id = ?")
+            .bind(user_id.to_string())
+            .fetch_all(require_database_pool()?)
+            .await?;
+        DatabaseUtils::get_uuids(&rows, "role_id")
+    }
+
+    /// 删除用户的全部角色关联
+    pub async fn delete_by_user_ids(conn: &mut MySqlConnection, user_ids: &[Uuid]) -> Result<u64> {
+        if user_ids.is_empty() {
+            return Ok(0);
+        }
+        let placeholders = vec!["?"; user_ids.len()].join(", ");
+        let sql = format!("DELETE FROM sys_user_role WHERE user_id IN ({})", placeholders);
+        let mut query = sqlx::query(&sql);
+        for user_id in user_ids {
+            query = query.bind(user_id.to_string());
+        }
+        Ok(query.execute(conn).await?.rows_affected())
+    }
+
+    /// 批量新增用户角色关联
+    pub async fn insert_user_roles(conn: &mut MySqlConnection, user_id: &Uuid, role_ids: &[Uuid]) -> Result<u64> {
+        if role_ids.is_empty() {
+            return Ok(0);
+        }
+        let placeholders = vec!["(?, ?)"; role_ids.len()].join(", ");
+        let sql = format!("INSERT INTO sys_user_role (user_id, role_id) VALUES {}", placeholders);
+        let mut query = sqlx::query(&sql);
+        for role_id in role_ids {
+            query = query.bind(user_id.to_string()).bind(role_id.to_string());
+        }
+        Ok(query.execute(conn).await?.rows_affected())
+    }
+
+    /// 查询拥有指定角色的用户ID
+    pub async fn select_user_ids_by_role_ids(role_ids: &[Uuid]) -> Result<Vec<Uuid>> {
+        if role_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let placeholders = vec!["?"; role_ids.len()].join(", ");
+        let sql = format!("SELECT DISTINCT user_id FROM sys_user_role WHERE role_id IN ({})", placeholders);
+        let mut query = sqlx::query(&sql);
+        for role_id in role_ids {
+            query = query.bind(role_id.to_string());
+        }
+        let rows = query.fetch_all(require_database_pool()?).await?;
+        DatabaseUtils::get_uuids(&rows, "user_id")
+    }
+
+    /// 批量授权用户角色，已授权的用户忽略
+    pub async fn insert_role_users(role_id: &Uuid, user_ids: &[Uuid]) -> Result<u64> {
+        if user_ids.is_empty() {
+            return Ok(0);
+        }
+        let placeholders = vec!["(?, ?)"; user_ids.len()].join(", ");
+        let sql = format!("INSERT IGNORE INTO sys_user_role (user_id, role_id) VALUES {}", placeholders);
+        let mut query = sqlx::query(&sql);
+        for user_id in user_ids {
+            query = query.bind(user_id.to_string()).bind(role_id.to_string());
+        }
+        Ok(query.execute(require_database_pool()?).await?.rows_affected())
+    }
+
+    /// 批量取消用户角色授权
+    pub async fn delete_role_users(role_id: &Uuid, user_ids: &[Uuid]) -> Result<u64> {
+        if user_ids.is_empty() {
+            return Ok(0);
+        }
+        let placeholders = vec!["?"; user_ids.len()].join(", ");
+        let sql = format!("DELETE FROM sys_user_role WHERE role_id = ? AND user_id IN ({})", placeholders);
+        let mut query = sqlx::query(&sql).bind(role_id.to_string());
+        for user_id in user_ids {
+            query = query.bind(user_id.to_string());
+        }
+        Ok(query.execute(require_database_pool()?).await?.rows_affected())
+    }
+}
@@ -0,0 +1,25 @@
+mod config;
+mod dept;
+mod dict_data;
+mod dict_type;
+mod login;
+mod login_info;
+mod menu;
+mod oper_log;
+mod online;
+mod post;
+mod role;
+mod user;
+
+pub use config::*;
+pub use dept::*;
+pub use dict_data::*;
+pub use dict_type::*;
+pub use login::*;
+pub use login_info::*;
+pub use menu::*;
+pub use oper_log::*;
+pub use online::*;
+pub use post::*;
+pub use role::*;
+pub use user::*;
@@ -0,0 +1,144 @@
+use chrono::Utc;
+use uuid::Uuid;
+use zzyl_common::constants::constants::YES;
+use zzyl_common::error::{Result, ZzylError};
+use zzyl_common::{BaseEntity, LoginUser, PageDomain, PageResult};
+use zzyl_framework::cache::ConfigCache;
+use crate::domain::{ConfigRequest, ConfigSearchCondition, SysConfig};
+use crate::repository::SysConfigRepository;
+
+/// 非内置参数标识
+const NOT_BUILT_IN: &str = "N";
+
+/// 参数配置服务
+pub struct SysConfigService;
+
+impl SysConfigService {
+    /// 根据条件分页查询参数配置
+    pub async fn select_config_list(
+        condition: &ConfigSearchCondition,
+        page: &PageDomain,
+    ) -> Result<PageResult<SysConfig>> {
+        let (list, total) = SysConfigRepository::select_config_list(condition, page).await?;
+        Ok(PageResult::new(page.page_num(), page.page_size(), total, list))
+    }
+
+    /// 查询参数配置详情
+    pub async fn select_config_by_id(config_id: &Uuid) -> Result<SysConfig> {
+        SysConfigRepository::select_config_by_id(config_id)
+            .await?
+            .ok_or_else(|| ZzylError::NotFound("参数配置不存在".to_string()))
+    }
+
+    /// 根据参数键名查询参数值（读取缓存）
+    pub async fn select_config_by_key(config_key: &str) -> Result<String> {
+        Ok(ConfigCache::get(config_key).await?.unwrap_or_default())
+    }
+
+    /// 新增参数配置
+    pub async fn insert_config(login_user: &LoginUser, request: &ConfigRequest) -> Result<Uuid> {
+        let config_key = request.config_key.trim();
+        Self::check_config_key_unique(config_key, None).await?;
+
+        let config_id = Uuid::new_v4();
+        let config = SysConfig {
+            config_id: Some(config_id),
+            config_name: request.config_name.trim().to_string(),
+            config_key: config_key.to_string(),
+            config_value: request.config_value.clone(),
+            config_type: request.config_type.clone().unwrap_or_else(|| NOT_BUILT_IN.to_string()),
+            base: BaseEntity {
+                create_by: Some(login_user.username.clone()),
+                create_time: Some(Utc::now()),
+                update_by: None,
+                update_time: None,
+                remark: request.remark.clone(),
+            },
+        };
+        SysConfigRepository::insert_config(&config).await?;
+        ConfigCache::set(&config.config_key, &config.config_value).await;
+        Ok(config_id)
+    }
+
+    /// 修改参数配置，内置参数不允许修改键名或取消内置
+    pub async fn update_config(login_user: &LoginUser, request: &ConfigRequest) -> Result<()> {
+        let config_id = request.config_id
+            .ok_or_else(|| ZzylError::InvalidParameter("参数主键不能为空".to_string()))?;
+        let existing = Self::select_config_by_id(&config_id).await?;
+        let config_key = request.config_key.trim();
+        Self::check_config_key_unique(config_key, Some(&config_id)).await?;
+
+        let config_type = request.config_type.clone().unwrap_or_else(|| existing.config_type.clone());
+        if existing.config_type == YES && (config_key != existing.config_key || config_type != YES) {
+            return Err(ZzylError::Forbidden(format!("内置参数【{}】不能修改键名或类型", existing.config_key)));
+        }
+
+        let old_config_key = existing.config_key.clone();
+        let config = SysConfig {
+            config_name: request.config_name.trim().to_string(),
+            config_key: config_key.to_string(),
+            config_value: request.config_value.clone(),
+            config_type,
+            base: BaseEntity {
+                update_by: Some(login_user.username.clone()),
+                update_time: Some(Utc::now()),
+                remark: request.remark.clone(),
+                ..existing.base
+            },
+            ..existing
+        };
+        SysConfigRepository::update_config(&config).await?;
+
+        if old_config_key != config.config_key {
+            ConfigCache::remove(&old_config_key).await;
+        }
+        ConfigCache::set(&config.config_key, &config.config_value).await;
+        Ok(())
+    }
+
+    /// 批量删除参数配置，内置参数不允许删除
+    pub async fn delete_config_by_ids(config_ids: &[Uuid]) -> Result<()> {
+        if config_ids.is_empty() {
+            return Err(ZzylError::InvalidParameter("请选择要删除的参数".to_string()));
+        }
+        let mut config_keys = Vec::with_capacity(config_ids.len());
+        for config_id in config_ids {
+            let config = Self::select_config_by_id(config_id).await?;
+            if config.config_type == YES {
+                return Err(ZzylError::Forbidden(format!("内置参数【{}】不能删除", config.config_key)));
+            }
+            config_keys.push(config.config_key);
+        }
+        SysConfigRepository::delete_config_by_ids(config_ids).await?;
+        for config_key in &config_keys {
+            ConfigCache::remove(config_key).await;
+        }
+        Ok(())
+    }
+
+    /// 加载全部参数到缓存
+    pub async fn load_config_cache() -> Result<()> {
+        for config in SysConfigRepository::select_config_all().await? {
+            ConfigCache::set(&config.config_key, &config.config_value).await;
+        }
+        Ok(())
+    }
+
+    /// 重置参数缓存
+    pub async fn reset_config_cache() -> Result<()> {
+        ConfigCache::clear().await?;
+        Self::load_config_cache().await
+    }
+
+    /// 校验参数键名唯一
+    async fn check_config_key_unique(config_key: &str, config_id: Option<&Uuid>) -> Result<()> {
+        if config_key.is_empty() {
+            return Err(ZzylError::InvalidParameter("参数键名不能为空".to_string()));
+        }
+        let found = SysConfigRepository::select_config_id_by_key(config_key).await?;
+        if found.is_some_and(|found| Some(&found) != config_id) {
+            return Err(ZzylError::AlreadyExists(format!("参数键名'{}'已存在", config_key)));
+        }
+        Ok(())
+    }
+}
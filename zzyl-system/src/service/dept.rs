@@ -0,0 +1,268 @@
+use std::collections::HashMap;
+use chrono::Utc;
+use uuid::Uuid;
+use zzyl_common::annotation::DataScope;
+use zzyl_common::constants::constants::{DEPT_DISABLE, DEPT_NORMAL};
+use zzyl_common::error::{Result, ZzylError};
+use zzyl_common::{BaseEntity, LoginUser};
+use zzyl_framework::database::require_database_pool;
+use zzyl_framework::security::{DataScopeFilter, DataScopeSql, PermissionService};
+use crate::domain::{DeptRequest, DeptSearchCondition, RoleDeptTreeResponse, SysDept, TreeSelect};
+use crate::repository::{SysDeptRepository, SysRoleRepository};
+
+/// 顶级部门的祖级列表
+const ROOT_ANCESTORS: &str = "0";
+
+/// 部门管理服务
+pub struct SysDeptService;
+
+impl SysDeptService {
+    /// 查询数据权限范围内的部门列表
+    pub async fn select_dept_list(login_user: &LoginUser, condition: &DeptSearchCondition) -> Result<Vec<SysDept>> {
+        SysDeptRepository::select_dept_list(condition, &Self::data_scope(login_user)).await
+    }
+
+    /// 查询部门下拉树
+    pub async fn select_dept_tree(login_user: &LoginUser, condition: &DeptSearchCondition) -> Result<Vec<TreeSelect>> {
+        let depts = Self::select_dept_list(login_user, condition).await?;
+        Ok(Self::build_tree_select(&depts))
+    }
+
+    /// 查询部门列表（排除指定部门及其下级，用于选择上级部门）
+    pub async fn select_dept_list_exclude_child(login_user: &LoginUser, dept_id: &Uuid) -> Result<Vec<SysDept>> {
+        let condition = DeptSearchCondition { dept_name: None, status: None, parent_id: None };
+        let depts = Self::select_dept_list(login_user, &condition).await?;
+        Ok(depts.into_iter()
+            .filter(|dept| dept.dept_id != Some(*dept_id) && !Self::is_descendant_of(dept, dept_id))
+            .collect())
+    }
+
+    /// 查询角色部门树及已选中的部门
+    pub async fn select_role_dept_tree(login_user: &LoginUser, role_id: &Uuid) -> Result<RoleDeptTreeResponse> {
+        let role = SysRoleRepository::select_role_by_id(role_id)
+            .await?
+            .ok_or_else(|| ZzylError::NotFound("角色不存在".to_string()))?;
+        let condition = DeptSearchCondition { dept_name: None, status: None, parent_id: None };
+        Ok(RoleDeptTreeResponse {
+            checked_keys: SysDeptRepository::select_checked_dept_ids(role_id, role.dept_check_strictly).await?,
+            depts: Self::select_dept_tree(login_user, &condition).await?,
+        })
+    }
+
+    /// 查询部门详情
+    pub async fn select_dept_by_id(login_user: &LoginUser, dept_id: &Uuid) -> Result<SysDept> {
+        Self::check_dept_data_scope(login_user, dept_id).await?;
+        Self::require_dept(dept_id).await
+    }
+
+    /// 新增部门
+    pub async fn insert_dept(login_user: &LoginUser, request: &DeptRequest) -> Result<Uuid> {
+        let dept_name = request.dept_name.trim();
+        Self::check_dept_name_unique(request.parent_id.as_ref(), dept_name, None).await?;
+        let ancestors = match &request.parent_id {
+            Some(parent_id) => {
+                Self::check_dept_data_scope(login_user, parent_id).await?;
+                let parent = Self::require_dept(parent_id).await?;
+                if parent.status != DEPT_NORMAL {
+                    return Err(ZzylError::Business("部门停用，不允许新增".to_string()));
+                }
+                Self::child_ancestors(&parent)
+            }
+            None => ROOT_ANCESTORS.to_string(),
+        };
+
+        let dept_id = Uuid::new_v4();
+        let dept = SysDept {
+            dept_id: Some(dept_id),
+            parent_id: request.parent_id,
+            ancestors: Some(ancestors),
+            dept_name: dept_name.to_string(),
+            order_num: request.order_num,
+            leader: request.leader.clone(),
+            phone: request.phone.clone(),
+            email: request.email.clone(),
+            status: request.status.clone().unwrap_or_else(|| DEPT_NORMAL.to_string()),
+            del_flag: "0".to_string(),
+            base: BaseEntity {
+                create_by: Some(login_user.username.clone()),
+                create_time: Some(Utc::now()),
+                update_by: None,
+                update_time: None,
+                remark: request.remark.clone(),
+            },
+        };
+
+        let mut tx = require_database_pool()?.begin().await?;
+        SysDeptRepository::insert_dept(&mut tx, &dept).await?;
+        tx.commit().await?;
+        Ok(dept_id)
+    }
+
+    /// 修改部门，上级部门变化时同步更新所有下级部门的祖级列表
+    pub async fn update_dept(login_user: &LoginUser, request: &DeptRequest) -> Result<()> {
+        let dept_id = request.dept_id
+            .ok_or_else(|| ZzylError::InvalidParameter("部门ID不能为空".to_string()))?;
+        Self::check_dept_data_scope(login_user, &dept_id).await?;
+        let existing = Self::require_dept(&dept_id).await?;
+        let dept_name = request.dept_name.trim();
+        Self::check_dept_name_unique(request.parent_id.as_ref(), dept_name, Some(&dept_id)).await?;
+
+        let ancestors = match &request.parent_id {
+            Some(parent_id) => {
+                let parent = Self::require_dept(parent_id).await?;
+                if *parent_id == dept_id || Self::is_descendant_of(&parent, &dept_id) {
+                    return Err(ZzylError::Business(format!(
+                        "修改部门'{}'失败，上级部门不能是自己或其下级部门", dept_name
+                    )));
+                }
+                Self::child_ancestors(&parent)
+            }
+            None => ROOT_ANCESTORS.to_string(),
+        };
+
+        let status = request.status.clone().unwrap_or_else(|| existing.status.clone());
+        if status == DEPT_DISABLE && SysDeptRepository::count_normal_children_by_id(&dept_id).await? > 0 {
+            return Err(ZzylError::Business("该部门包含未停用的子部门！".to_string()));
+        }
+
+        let old_ancestors = existing.ancestors.clone().unwrap_or_else(|| ROOT_ANCESTORS.to_string());
+        let children = if old_ancestors != ancestors {
+            SysDeptRepository::select_children_by_id(&dept_id).await?
+        } else {
+            Vec::new()
+        };
+
+        let dept = SysDept {
+            parent_id: request.parent_id,
+            ancestors: Some(ancestors.clone()),
+            dept_name: dept_name.to_string(),
+            order_num: request.order_num,
+            leader: request.leader.clone(),
+            phone: request.phone.clone(),
+            email: request.email.clone(),
+            status,
+            base: BaseEntity {
+                update_by: Some(login_user.username.clone()),
+                update_time: Some(Utc::now()),
+                remark: request.remark.clone(),
+                ..existing.base.clone()
+            },
+            ..existing
+        };
+
+        let mut tx = require_database_pool()?.begin().await?;
+        SysDeptRepository::update_dept(&mut tx, &dept).await?;
+        for child in &children {
+            let (Some(child_id), Some(child_ancestors)) = (child.dept_id, child.ancestors.as_deref()) else {
+                continue;
+            };
+            if let Some(rest) = child_ancestors.strip_prefix(old_ancestors.as_str()) {
+                SysDeptRepository::update_ancestors(&mut tx, &child_id, &format!("{}{}", ancestors, rest)).await?;
+            }
+        }
+        if dept.status == DEPT_NORMAL {
+            // 启用部门时同时启用其全部上级部门
+            let parent_ids = Self::parse_ancestors(&ancestors);
+            SysDeptRepository::update_status_normal(&mut tx, &parent_ids, &login_user.username).await?;
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// 删除部门（逻辑删除），存在下级部门或用户时不允许删除
+    pub async fn delete_dept_by_id(login_user: &LoginUser, dept_id: &Uuid) -> Result<()> {
+        Self::check_dept_data_scope(login_user, dept_id).await?;
+        Self::require_dept(dept_id).await?;
+        if SysDeptRepository::count_children_by_parent_id(dept_id).await? > 0 {
+            return Err(ZzylError::Business("存在下级部门,不允许删除".to_string()));
+        }
+        if SysDeptRepository::count_users_by_dept_id(dept_id).await? > 0 {
+            return Err(ZzylError::Business("部门存在用户,不允许删除".to_string()));
+        }
+        SysDeptRepository::delete_dept_by_id(dept_id, &login_user.username).await?;
+        Ok(())
+    }
+
+    /// 校验当前用户是否有权访问目标部门的数据
+    pub async fn check_dept_data_scope(login_user: &LoginUser, dept_id: &Uuid) -> Result<()> {
+        if PermissionService::is_admin(login_user) {
+            return Ok(());
+        }
+        if SysDeptRepository::count_dept_in_scope(dept_id, &Self::data_scope(login_user)).await? == 0 {
+            return Err(ZzylError::Permission("没有权限访问部门数据！".to_string()));
+        }
+        Ok(())
+    }
+
+    /// 构建部门下拉树
+    pub fn build_tree_select(depts: &[SysDept]) -> Vec<TreeSelect> {
+        let mut children: HashMap<Uuid, Vec<&SysDept>> = HashMap::new();
+        for dept in depts {
+            if let Some(parent_id) = dept.parent_id {
+                children.entry(parent_id).or_default().push(dept);
+            }
+        }
+        depts.iter()
+            .filter(|dept| match dept.parent_id {
+                Some(parent_id) => !depts.iter().any(|d| d.dept_id == Some(parent_id)),
+                None => true,
+            })
+            .filter_map(|dept| Self::build_tree_node(dept, &children))
+            .collect()
+    }
+
+    /// 构建单个下拉树节点
+    fn build_tree_node(dept: &SysDept, children: &HashMap<Uuid, Vec<&SysDept>>) -> Option<TreeSelect> {
+        let dept_id = dept.dept_id?;
+        Some(TreeSelect {
+            id: dept_id,
+            label: dept.dept_name.clone(),
+            children: children.get(&dept_id)
+                .map(|subs| subs.iter().filter_map(|sub| Self::build_tree_node(sub, children)).collect())
+                .unwrap_or_default(),
+        })
+    }
+
+    /// 部门数据权限过滤条件
+    fn data_scope(login_user: &LoginUser) -> DataScopeSql {
+        DataScopeFilter::build(login_user, &DataScope { user_alias: None, dept_alias: Some("d".to_string()) })
+    }
+
+    /// 查询部门，不存在时返回错误
+    async fn require_dept(dept_id: &Uuid) -> Result<SysDept> {
+        SysDeptRepository::select_dept_by_id(dept_id)
+            .await?
+            .ok_or_else(|| ZzylError::NotFound("部门不存在".to_string()))
+    }
+
+    /// 校验同一上级部门下名称唯一
+    async fn check_dept_name_unique(parent_id: Option<&Uuid>, dept_name: &str, dept_id: Option<&Uuid>) -> Result<()> {
+        if dept_name.is_empty() {
+            return Err(ZzylError::InvalidParameter("部门名称不能为空".to_string()));
+        }
+        let found = SysDeptRepository::select_dept_id_by_name(parent_id, dept_name).await?;
+        if found.is_some_and(|found| Some(&found) != dept_id) {
+            return Err(ZzylError::AlreadyExists(format!("部门'{}'保存失败，部门名称已存在", dept_name)));
+        }
+        Ok(())
+    }
+
+    /// 下级部门的祖级列表
+    fn child_ancestors(parent: &SysDept) -> String {
+        let parent_ancestors = parent.ancestors.as_deref().filter(|value| !value.is_empty()).unwrap_or(ROOT_ANCESTORS);
+        match parent.dept_id {
+            Some(parent_id) => format!("{},{}", parent_ancestors, parent_id),
+            None => parent_ancestors.to_string(),
+        }
+    }
+
+    /// 是否为指定部门的下级部门
+    fn is_descendant_of(dept: &SysDept, dept_id: &Uuid) -> bool {
+        dept.ancestors.as_deref().is_some_and(|ancestors| Self::parse_ancestors(ancestors).contains(dept_id))
+    }
+
+    /// 解析祖级列表中的部门ID
+    fn parse_ancestors(ancestors: &str) -> Vec<Uuid> {
+        ancestors.split(',').filter_map(|id| Uuid::parse_str(id.trim()).ok()).collect()
+    }
+}
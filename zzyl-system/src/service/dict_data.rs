@@ -0,0 +1,119 @@
+use chrono::Utc;
+use uuid::Uuid;
+use zzyl_common::constants::constants::DICT_NORMAL;
+use zzyl_common::error::{Result, ZzylError};
+use zzyl_common::{BaseEntity, LoginUser, PageDomain, PageResult};
+use crate::domain::{DictDataRequest, DictDataSearchCondition, SysDictData};
+use crate::repository::{SysDictDataRepository, SysDictTypeRepository};
+use crate::service::SysDictTypeService;
+
+/// 字典数据服务
+pub struct SysDictDataService;
+
+impl SysDictDataService {
+    /// 根据条件分页查询字典数据
+    pub async fn select_dict_data_list(
+        condition: &DictDataSearchCondition,
+        page: &PageDomain,
+    ) -> Result<PageResult<SysDictData>> {
+        let (list, total) = SysDictDataRepository::select_dict_data_list(condition, page).await?;
+        Ok(PageResult::new(page.page_num(), page.page_size(), total, list))
+    }
+
+    /// 查询字典数据详情
+    pub async fn select_dict_data_by_code(dict_code: &Uuid) -> Result<SysDictData> {
+        SysDictDataRepository::select_dict_data_by_code(dict_code)
+            .await?
+            .ok_or_else(|| ZzylError::NotFound("字典数据不存在".to_string()))
+    }
+
+    /// 新增字典数据
+    pub async fn insert_dict_data(login_user: &LoginUser, request: &DictDataRequest) -> Result<Uuid> {
+        let dict_type = request.dict_type.trim();
+        Self::check_dict_data(dict_type, request, None).await?;
+
+        let dict_code = Uuid::new_v4();
+        SysDictDataRepository::insert_dict_data(&SysDictData {
+            dict_code: Some(dict_code),
+            dict_sort: request.dict_sort,
+            dict_label: request.dict_label.trim().to_string(),
+            dict_value: request.dict_value.trim().to_string(),
+            dict_type: dict_type.to_string(),
+            css_class: request.css_class.clone(),
+            list_class: request.list_class.clone(),
+            is_default: request.is_default.clone().unwrap_or_else(|| "N".to_string()),
+            status: request.status.clone().unwrap_or_else(|| DICT_NORMAL.to_string()),
+            base: BaseEntity {
+                create_by: Some(login_user.username.clone()),
+                create_time: Some(Utc::now()),
+                update_by: None,
+                update_time: None,
+                remark: request.remark.clone(),
+            },
+        }).await?;
+
+        SysDictTypeService::refresh_dict_cache(dict_type).await?;
+        Ok(dict_code)
+    }
+
+    /// 修改字典数据
+    pub async fn update_dict_data(login_user: &LoginUser, request: &DictDataRequest) -> Result<()> {
+        let dict_code = request.dict_code
+            .ok_or_else(|| ZzylError::InvalidParameter("字典编码不能为空".to_string()))?;
+        let existing = Self::select_dict_data_by_code(&dict_code).await?;
+        let dict_type = request.dict_type.trim();
+        Self::check_dict_data(dict_type, request, Some(&dict_code)).await?;
+
+        let old_dict_type = existing.dict_type.clone();
+        SysDictDataRepository::update_dict_data(&SysDictData {
+            dict_sort: request.dict_sort,
+            dict_label: request.dict_label.trim().to_string(),
+            dict_value: request.dict_value.trim().to_string(),
+            dict_type: dict_type.to_string(),
+            css_class: request.css_class.clone(),
+            list_class: request.list_class.clone(),
+            is_default: request.is_default.clone().unwrap_or_else(|| existing.is_default.clone()),
+            status: request.status.clone().unwrap_or_else(|| existing.status.clone()),
+            base: BaseEntity {
+                update_by: Some(login_user.username.clone()),
+                update_time: Some(Utc::now()),
+                remark: request.remark.clone(),
+                ..existing.base
+            },
+            ..existing
+        }).await?;
+
+        if old_dict_type != dict_type {
+            SysDictTypeService::refresh_dict_cache(&old_dict_type).await?;
+        }
+        SysDictTypeService::refresh_dict_cache(dict_type).await
+    }
+
+    /// 批量删除字典数据
+    pub async fn delete_dict_data_by_codes(dict_codes: &[Uuid]) -> Result<()> {
+        if dict_codes.is_empty() {
+            return Err(ZzylError::InvalidParameter("请选择要删除的字典数据".to_string()));
+        }
+        let dict_types = SysDictDataRepository::select_dict_types_by_codes(dict_codes).await?;
+        SysDictDataRepository::delete_dict_data_by_codes(dict_codes).await?;
+        for dict_type in &dict_types {
+            SysDictTypeService::refresh_dict_cache(dict_type).await?;
+        }
+        Ok(())
+    }
+
+    /// 校验字典类型存在且同类型下键值唯一
+    async fn check_dict_data(dict_type: &str, request: &DictDataRequest, dict_code: Option<&Uuid>) -> Result<()> {
+        if request.dict_label.trim().is_empty() || request.dict_value.trim().is_empty() {
+            return Err(ZzylError::InvalidParameter("字典标签和字典键值不能为空".to_string()));
+        }
+        if SysDictTypeRepository::select_dict_id_by_type(dict_type).await?.is_none() {
+            return Err(ZzylError::InvalidParameter(format!("字典类型'{}'不存在", dict_type)));
+        }
+        let found = SysDictDataRepository::select_dict_code_by_value(dict_type, request.dict_value.trim()).await?;
+        if found.is_some_and(|found| Some(&found) != dict_code) {
+            return Err(ZzylError::AlreadyExists(format!("字典键值'{}'已存在", request.dict_value.trim())));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,269 @@
+use std::collections::HashMap;
+use chrono::Utc;
+use tracing::warn;
+use uuid::Uuid;
+use zzyl_common::constants::constants::{DICT_NORMAL, SYS_DICT_KEY};
+use zzyl_common::error::{Result, ZzylError};
+use zzyl_common::{BaseEntity, DictData, LoginUser, PageDomain, PageResult};
+use zzyl_framework::database::require_database_pool;
+use zzyl_framework::redis::get_redis_manager;
+use crate::domain::{DictTypeRequest, DictTypeSearchCondition, SysDictType};
+use crate::repository::{SysDictDataRepository, SysDictTypeRepository};
+
+/// 字典标签与键值的默认分隔符
+pub const DICT_SEPARATOR: &str = ",";
+
+/// 字典类型服务，同时负责字典缓存与标签/键值转换
+pub struct SysDictTypeService;
+
+impl SysDictTypeService {
+    /// 根据条件分页查询字典类型
+    pub async fn select_dict_type_list(
+        condition: &DictTypeSearchCondition,
+        page: &PageDomain,
+    ) -> Result<PageResult<SysDictType>> {
+        let (list, total) = SysDictTypeRepository::select_dict_type_list(condition, page).await?;
+        Ok(PageResult::new(page.page_num(), page.page_size(), total, list))
+    }
+
+    /// 查询全部字典类型
+    pub async fn select_dict_type_all() -> Result<Vec<SysDictType>> {
+        SysDictTypeRepository::select_dict_type_all().await
+    }
+
+    /// 查询字典类型详情
+    pub async fn select_dict_type_by_id(dict_id: &Uuid) -> Result<SysDictType> {
+        SysDictTypeRepository::select_dict_type_by_id(dict_id)
+            .await?
+            .ok_or_else(|| ZzylError::NotFound("字典类型不存在".to_string()))
+    }
+
+    /// 新增字典类型
+    pub async fn insert_dict_type(login_user: &LoginUser, request: &DictTypeRequest) -> Result<Uuid> {
+        let dict_type = request.dict_type.trim();
+        Self::check_dict_type_unique(dict_type, None).await?;
+
+        let dict_id = Uuid::new_v4();
+        SysDictTypeRepository::insert_dict_type(&SysDictType {
+            dict_id: Some(dict_id),
+            dict_name: request.dict_name.trim().to_string(),
+            dict_type: dict_type.to_string(),
+            status: request.status.clone().unwrap_or_else(|| DICT_NORMAL.to_string()),
+            base: BaseEntity {
+                create_by: Some(login_user.username.clone()),
+                create_time: Some(Utc::now()),
+                update_by: None,
+                update_time: None,
+                remark: request.remark.clone(),
+            },
+        }).await?;
+        Ok(dict_id)
+    }
+
+    /// 修改字典类型，类型标识变化时同步修改其字典数据
+    pub async fn update_dict_type(login_user: &LoginUser, request: &DictTypeRequest) -> Result<()> {
+        let dict_id = request.dict_id
+            .ok_or_else(|| ZzylError::InvalidParameter("字典主键不能为空".to_string()))?;
+        let existing = Self::select_dict_type_by_id(&dict_id).await?;
+        let dict_type = request.dict_type.trim();
+        Self::check_dict_type_unique(dict_type, Some(&dict_id)).await?;
+
+        let old_dict_type = existing.dict_type.clone();
+        let updated = SysDictType {
+            dict_name: request.dict_name.trim().to_string(),
+            dict_type: dict_type.to_string(),
+            status: request.status.clone().unwrap_or_else(|| existing.status.clone()),
+            base: BaseEntity {
+                update_by: Some(login_user.username.clone()),
+                update_time: Some(Utc::now()),
+                remark: request.remark.clone(),
+                ..existing.base
+            },
+            ..existing
+        };
+
+        let mut tx = require_database_pool()?.begin().await?;
+        SysDictDataRepository::update_dict_type(&mut tx, &old_dict_type, dict_type).await?;
+        SysDictTypeRepository::update_dict_type(&mut tx, &updated).await?;
+        tx.commit().await?;
+
+        Self::remove_dict_cache(&old_dict_type).await;
+        Self::refresh_dict_cache(dict_type).await
+    }
+
+    /// 批量删除字典类型，已分配字典数据的类型不允许删除
+    pub async fn delete_dict_type_by_ids(dict_ids: &[Uuid]) -> Result<()> {
+        if dict_ids.is_empty() {
+            return Err(ZzylError::InvalidParameter("请选择要删除的字典类型".to_string()));
+        }
+        let mut dict_types = Vec::with_capacity(dict_ids.len());
+        for dict_id in dict_ids {
+            let dict_type = Self::select_dict_type_by_id(dict_id).await?;
+            if SysDictDataRepository::count_by_dict_type(&dict_type.dict_type).await? > 0 {
+                return Err(ZzylError::Business(format!("{}已分配,不能删除", dict_type.dict_name)));
+            }
+            dict_types.push(dict_type.dict_type);
+        }
+        SysDictTypeRepository::delete_dict_type_by_ids(dict_ids).await?;
+        for dict_type in &dict_types {
+            Self::remove_dict_cache(dict_type).await;
+        }
+        Ok(())
+    }
+
+    /// 按类型查询正常的字典数据，优先读取缓存
+    pub async fn select_dict_data_by_type(dict_type: &str) -> Result<Vec<DictData>> {
+        if let Some(redis) = get_redis_manager() {
+            match redis.get_json::<Vec<DictData>>(&Self::cache_key(dict_type)).await {
+                Ok(Some(dict_datas)) => return Ok(dict_datas),
+                Ok(None) => {}
+                Err(e) => warn!("读取字典缓存失败: {}", e),
+            }
+        }
+        let dict_datas: Vec<DictData> = SysDictDataRepository::select_dict_data_by_type(dict_type)
+            .await?
+            .iter()
+            .map(DictData::from)
+            .collect();
+        if !dict_datas.is_empty() {
+            Self::set_dict_cache(dict_type, &dict_datas).await;
+        }
+        Ok(dict_datas)
+    }
+
+    /// 根据字典键值获取标签，多个键值以分隔符连接
+    pub async fn get_dict_label(dict_type: &str, dict_value: &str) -> Result<String> {
+        let dict_datas = Self::select_dict_data_by_type(dict_type).await?;
+        Ok(Self::translate_label(&dict_datas, dict_value, DICT_SEPARATOR))
+    }
+
+    /// 根据字典标签获取键值，多个标签以分隔符连接
+    pub async fn get_dict_value(dict_type: &str, dict_label: &str) -> Result<String> {
+        let dict_datas = Self::select_dict_data_by_type(dict_type).await?;
+        Ok(Self::translate_value(&dict_datas, dict_label, DICT_SEPARATOR))
+    }
+
+    /// 构建键值到标签的映射，便于批量导出时转换
+    pub async fn get_dict_label_map(dict_type: &str) -> Result<HashMap<String, String>> {
+        Ok(Self::select_dict_data_by_type(dict_type)
+            .await?
+            .into_iter()
+            .map(|data| (data.dict_value, data.dict_label))
+            .collect())
+    }
+
+    /// 构建标签到键值的映射，便于批量导入时转换
+    pub async fn get_dict_value_map(dict_type: &str) -> Result<HashMap<String, String>> {
+        Ok(Self::select_dict_data_by_type(dict_type)
+            .await?
+            .into_iter()
+            .map(|data| (data.dict_label, data.dict_value))
+            .collect())
+    }
+
+    /// 将键值转换为标签，未匹配的键值原样保留
+    pub fn translate_label(dict_datas: &[DictData], dict_value: &str, separator: &str) -> String {
+        Self::translate(dict_value, separator, |value| {
+            dict_datas.iter().find(|data| data.dict_value == value).map(|data| data.dict_label.as_str())
+        })
+    }
+
+    /// 将标签转换为键值，未匹配的标签原样保留
+    pub fn translate_value(dict_datas: &[DictData], dict_label: &str, separator: &str) -> String {
+        Self::translate(dict_label, separator, |label| {
+            dict_datas.iter().find(|data| data.dict_label == label).map(|data| data.dict_value.as_str())
+        })
+    }
+
+    /// 加载全部字典到缓存
+    pub async fn load_dict_cache() -> Result<()> {
+        let mut grouped: HashMap<String, Vec<DictData>> = HashMap::new();
+        for data in SysDictDataRepository::select_dict_data_all().await? {
+            grouped.entry(data.dict_type.clone()).or_default().push(DictData::from(&data));
+        }
+        for (dict_type, dict_datas) in &grouped {
+            Self::set_dict_cache(dict_type, dict_datas).await;
+        }
+        Ok(())
+    }
+
+    /// 清空全部字典缓存
+    pub async fn clear_dict_cache() -> Result<()> {
+        if let Some(redis) = get_redis_manager() {
+            let keys = redis.keys(&format!("{}*", SYS_DICT_KEY)).await?;
+            if !keys.is_empty() {
+                redis.del_batch(&keys).await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// 重置字典缓存
+    pub async fn reset_dict_cache() -> Result<()> {
+        Self::clear_dict_cache().await?;
+        Self::load_dict_cache().await
+    }
+
+    /// 重新加载指定类型的字典缓存
+    pub async fn refresh_dict_cache(dict_type: &str) -> Result<()> {
+        let dict_datas: Vec<DictData> = SysDictDataRepository::select_dict_data_by_type(dict_type)
+            .await?
+            .iter()
+            .map(DictData::from)
+            .collect();
+        if dict_datas.is_empty() {
+            Self::remove_dict_cache(dict_type).await;
+        } else {
+            Self::set_dict_cache(dict_type, &dict_datas).await;
+        }
+        Ok(())
+    }
+
+    /// 校验字典类型唯一
+    async fn check_dict_type_unique(dict_type: &str, dict_id: Option<&Uuid>) -> Result<()> {
+        if dict_type.is_empty() {
+            return Err(ZzylError::InvalidParameter("字典类型不能为空".to_string()));
+        }
+        let found = SysDictTypeRepository::select_dict_id_by_type(dict_type).await?;
+        if found.is_some_and(|found| Some(&found) != dict_id) {
+            return Err(ZzylError::AlreadyExists(format!("字典类型'{}'已存在", dict_type)));
+        }
+        Ok(())
+    }
+
+    /// 按分隔符逐项转换
+    fn translate<'a, F>(source: &str, separator: &str, lookup: F) -> String
+    where
+        F: Fn(&str) -> Option<&'a str>,
+    {
+        source.split(separator)
+            .map(str::trim)
+            .filter(|item| !item.is_empty())
+            .map(|item| lookup(item).unwrap_or(item))
+            .collect::<Vec<_>>()
+            .join(separator)
+    }
+
+    /// 写入字典缓存，失败时仅记录日志
+    async fn set_dict_cache(dict_type: &str, dict_datas: &[DictData]) {
+        if let Some(redis) = get_redis_manager() {
+            if let Err(e) = redis.set_json(&Self::cache_key(dict_type), &dict_datas).await {
+                warn!("写入字典缓存失败: {}", e);
+            }
+        }
+    }
+
+    /// 删除字典缓存，失败时仅记录日志
+    async fn remove_dict_cache(dict_type: &str) {
+        if let Some(redis) = get_redis_manager() {
+            if let Err(e) = redis.del(&Self::cache_key(dict_type)).await {
+                warn!("删除字典缓存失败: {}", e);
+            }
+        }
+    }
+
+    /// 字典缓存键
+    fn cache_key(dict_type: &str) -> String {
+        format!("{}{}", SYS_DICT_KEY, dict_type)
+    }
+}
@@ -0,0 +1,284 @@
+use chrono::Utc;
+use tracing::{info, warn};
+use uuid::Uuid;
+use zzyl_common::constants::constants::{
+    LOGIN_SUCCESS, LOGOUT, PASSWORD_MAX_LENGTH, PASSWORD_MIN_LENGTH, PWD_ERR_LOCK_TIME,
+    PWD_ERR_MAX_CNT, USERNAME_MAX_LENGTH, USERNAME_MIN_LENGTH,
+};
+use zzyl_common::enums::{LoginStatus, UserStatus};
+use zzyl_common::error::{Result, ZzylError};
+use zzyl_common::utils::{SecurityUtils, UserAgentUtils};
+use zzyl_common::{Dept, LoginUser, Role};
+use zzyl_framework::captcha::CaptchaService;
+use zzyl_framework::config::get_config;
+use zzyl_framework::redis::{get_redis_manager, RedisManager, RedisUtils};
+use zzyl_framework::security::{TokenService, ALL_PERMISSION, SUPER_ADMIN, TOKEN_EXPIRE_SECONDS};
+use zzyl_framework::web::ClientInfo;
+use crate::domain::{LoginRequest, LoginResponse, SysLoginInfo, SysUser, UserInfoResponse};
+use crate::repository::{
+    SysDeptRepository, SysLoginInfoRepository, SysMenuRepository, SysRoleRepository, SysUserRepository,
+};
+
+/// 令牌类型
+pub const TOKEN_TYPE: &str = "Bearer";
+
+/// 登录校验服务
+pub struct SysLoginService;
+
+impl SysLoginService {
+    /// 登录验证
+    pub async fn login(request: &LoginRequest, client: &ClientInfo) -> Result<LoginResponse> {
+        let username = request.username.trim();
+
+        if CaptchaService::is_enabled().await {
+            if let Err(e) = CaptchaService::verify_captcha(&request.uuid, &request.captcha).await {
+                Self::record_login_info(username, LoginStatus::Fail, &e.to_string(), client).await;
+                return Err(e);
+            }
+        }
+
+        if let Err(e) = Self::login_pre_check(username, &request.password) {
+            Self::record_login_info(username, LoginStatus::Fail, &e.to_string(), client).await;
+            return Err(e);
+        }
+
+        let user = match Self::authenticate(username, &request.password).await {
+            Ok(user) => user,
+            Err(e) => {
+                Self::record_login_info(username, LoginStatus::Fail, &e.to_string(), client).await;
+                return Err(e);
+            }
+        };
+
+        Self::record_login_info(username, LoginStatus::Success, LOGIN_SUCCESS, client).await;
+
+        let now = Utc::now();
+        if let Some(user_id) = &user.user_id {
+            SysUserRepository::update_login_info(user_id, &client.ip, now).await?;
+        }
+
+        let mut user = user;
+        user.login_ip = Some(client.ip.clone());
+        user.login_date = Some(now);
+
+        let login_user = Self::build_login_user(&user).await?;
+        let access_token = TokenService::create_token(&login_user, client).await?;
+
+        Ok(LoginResponse {
+            access_token,
+            token_type: TOKEN_TYPE.to_string(),
+            expires_in: TOKEN_EXPIRE_SECONDS as i64,
+            user_info: user,
+        })
+    }
+
+    /// 退出登录
+    pub async fn logout(token: Option<&str>, client: &ClientInfo) -> Result<()> {
+        let token = match token {
+            Some(token) => token,
+            None => return Ok(()),
+        };
+
+        if let Ok(login_user) = TokenService::get_login_user(token).await {
+            TokenService::delete_session(&TokenService::token_id(token)).await?;
+            Self::record_login_info(&login_user.username, LoginStatus::Success, LOGOUT, client).await;
+            info!("用户 {} 退出登录", login_user.username);
+        }
+
+        Ok(())
+    }
+
+    /// 刷新令牌，同时重新加载用户的角色和权限，原令牌随即失效
+    pub async fn refresh(login_user: &LoginUser, token: Option<&str>, client: &ClientInfo) -> Result<LoginResponse> {
+        let user = SysUserRepository::select_user_by_id(&login_user.user_id)
+            .await?
+            .ok_or_else(|| ZzylError::Authentication("用户不存在或已被删除".to_string()))?;
+        if user.status == UserStatus::Disabled {
+            TokenService::delete_login_user(&login_user.user_id).await?;
+            return Err(ZzylError::User(format!("对不起，您的账号：{} 已停用", user.username)));
+        }
+
+        let refreshed = Self::build_login_user(&user).await?;
+        let access_token = TokenService::create_token(&refreshed, client).await?;
+        if let Some(token) = token {
+            TokenService::delete_session(&TokenService::token_id(token)).await?;
+        }
+
+        Ok(LoginResponse {
+            access_token,
+            token_type: TOKEN_TYPE.to_string(),
+            expires_in: TOKEN_EXPIRE_SECONDS as i64,
+            user_info: user,
+        })
+    }
+
+    /// 获取当前用户信息
+    pub async fn get_info(login_user: &LoginUser) -> Result<UserInfoResponse> {
+        let user = SysUserRepository::select_user_by_id(&login_user.user_id)
+            .await?
+            .ok_or_else(|| ZzylError::NotFound("用户不存在".to_string()))?;
+        let roles = SysRoleRepository::select_roles_by_user_id(&login_user.user_id).await?;
+        let menus = if login_user.role_keys.iter().any(|key| key == SUPER_ADMIN) {
+            SysMenuRepository::select_all_menus().await?
+        } else {
+            SysMenuRepository::select_menus_by_user_id(&login_user.user_id).await?
+        };
+
+        Ok(UserInfoResponse {
+            user,
+            roles,
+            permissions: login_user.permissions.clone(),
+            menus,
+        })
+    }
+
+    /// 根据用户实体构建登录用户（含部门、角色、权限）
+    pub async fn build_login_user(user: &SysUser) -> Result<LoginUser> {
+        let user_id = user.user_id
+            .ok_or_else(|| ZzylError::User("用户ID不能为空".to_string()))?;
+
+        let roles = SysRoleRepository::select_roles_by_user_id(&user_id).await?;
+        let role_keys: Vec<String> = roles.iter().map(|role| role.role_key.clone()).collect();
+        let permissions = if role_keys.iter().any(|key| key == SUPER_ADMIN) {
+            vec![ALL_PERMISSION.to_string()]
+        } else {
+            SysMenuRepository::select_perms_by_user_id(&user_id).await?
+        };
+
+        let dept = match &user.dept_id {
+            Some(dept_id) => SysDeptRepository::select_dept_by_id(dept_id).await?.as_ref().map(Dept::from),
+            None => None,
+        };
+
+        Ok(LoginUser {
+            user_id,
+            username: user.username.clone(),
+            nick_name: user.nick_name.clone().unwrap_or_else(|| user.username.clone()),
+            email: user.email.clone(),
+            phone: user.phone.clone(),
+            sex: user.sex.clone(),
+            avatar: user.avatar.clone(),
+            password: user.password.clone(),
+            salt: user.salt.clone().unwrap_or_default(),
+            status: i32::from(user.status).to_string(),
+            del_flag: i32::from(user.del_flag).to_string(),
+            login_ip: user.login_ip.clone(),
+            login_date: user.login_date,
+            dept,
+            role_ids: roles.iter().filter_map(|role| role.role_id).collect(),
+            role_keys,
+            roles: roles.iter().map(Role::from).collect(),
+            permissions,
+            role_id: None,
+        })
+    }
+
+    /// 重新加载已登录用户缓存的角色与权限，用户未登录时不做处理
+    pub async fn refresh_cached_login_user(user_id: &Uuid) -> Result<()> {
+        if TokenService::get_cached_login_user(user_id).await?.is_none() {
+            return Ok(());
+        }
+        match SysUserRepository::select_user_by_id(user_id).await? {
+            Some(user) if user.status == UserStatus::Normal => {
+                let login_user = Self::build_login_user(&user).await?;
+                TokenService::refresh_login_user(&login_user).await
+            }
+            _ => TokenService::delete_login_user(user_id).await,
+        }
+    }
+
+    /// 登录前置校验
+    fn login_pre_check(username: &str, password: &str) -> Result<()> {
+        if username.is_empty() || password.is_empty() {
+            return Err(ZzylError::InvalidParameter("用户名或密码不能为空".to_string()));
+        }
+
+        let username_len = username.chars().count();
+        let password_len = password.chars().count();
+        if !(USERNAME_MIN_LENGTH..=USERNAME_MAX_LENGTH).contains(&username_len)
+            || !(PASSWORD_MIN_LENGTH..=PASSWORD_MAX_LENGTH).contains(&password_len)
+        {
+            return Err(ZzylError::User("用户不存在/密码错误".to_string()));
+        }
+
+        Ok(())
+    }
+
+    /// 校验用户名密码，连续输错达到上限后锁定账户
+    async fn authenticate(username: &str, password: &str) -> Result<SysUser> {
+        let (max_retry_count, lock_time) = Self::password_policy();
+        let redis = Self::redis()?;
+        let key = RedisUtils::build_login_fail_cache_key(username);
+
+        let retry_count = redis.get(&key).await?
+            .and_then(|count| count.parse::<i32>().ok())
+            .unwrap_or(0);
+        if retry_count >= max_retry_count {
+            return Err(ZzylError::User(format!(
+                "密码输入错误{}次，帐户锁定{}分钟", max_retry_count, lock_time
+            )));
+        }
+
+        let user = SysUserRepository::select_user_by_username(username)
+            .await?
+            .ok_or_else(|| ZzylError::User("用户不存在/密码错误".to_string()))?;
+
+        if user.status == UserStatus::Disabled {
+            return Err(ZzylError::User(format!("对不起，您的账号：{} 已停用", username)));
+        }
+
+        let matched = SecurityUtils::verify_password(password, &user.password)
+            .map_err(|e| ZzylError::System(format!("密码校验失败: {}", e)))?;
+        if !matched {
+            let retry_count = redis.incr(&key).await?;
+            redis.expire(&key, (lock_time as u64) * 60).await?;
+            if retry_count >= max_retry_count as i64 {
+                return Err(ZzylError::User(format!(
+                    "密码输入错误{}次，帐户锁定{}分钟", max_retry_count, lock_time
+                )));
+            }
+            return Err(ZzylError::User("用户不存在/密码错误".to_string()));
+        }
+
+        redis.del(&key).await?;
+        Ok(user)
+    }
+
+    /// 清除账户的密码错误次数，解除登录锁定
+    pub async fn clear_login_fail_count(username: &str) -> Result<()> {
+        Self::redis()?.del(&RedisUtils::build_login_fail_cache_key(username)).await?;
+        Ok(())
+    }
+
+    /// 获取密码错误次数上限与锁定时间（分钟）
+    fn password_policy() -> (i32, i64) {
+        match get_config() {
+            Some(config) => (config.user.password.max_retry_count, config.user.password.lock_time as i64),
+            None => (PWD_ERR_MAX_CNT, PWD_ERR_LOCK_TIME),
+        }
+    }
+
+    /// 记录登录日志，写入失败不影响登录流程
+    async fn record_login_info(username: &str, status: LoginStatus, msg: &str, client: &ClientInfo) {
+        let login_info = SysLoginInfo {
+            info_id: None,
+            username: Some(username.to_string()),
+            ipaddr: Some(client.ip.clone()),
+            login_location: None,
+            browser: Some(UserAgentUtils::get_browser(&client.user_agent)),
+            os: Some(UserAgentUtils::get_os(&client.user_agent)),
+            status: Some(i32::from(status).to_string()),
+            msg: Some(msg.to_string()),
+            login_time: Some(Utc::now()),
+        };
+
+        if let Err(e) = SysLoginInfoRepository::insert_login_info(&login_info).await {
+            warn!("记录登录日志失败: {}", e);
+        }
+    }
+
+    /// 获取Redis管理器
+    fn redis() -> Result<&'static RedisManager> {
+        get_redis_manager().ok_or_else(|| ZzylError::ServiceUnavailable("Redis未初始化".to_string()))
+    }
+}
@@ -0,0 +1,82 @@
+use uuid::Uuid;
+use zzyl_common::error::{Result, ZzylError};
+use zzyl_common::utils::DateUtils;
+use zzyl_common::{PageDomain, PageResult};
+use crate::domain::{LoginInfoSearchCondition, SysLoginInfo};
+use crate::repository::SysLoginInfoRepository;
+use crate::service::{SysDictTypeService, SysLoginService};
+
+/// 登录状态字典类型
+const LOGIN_STATUS_DICT: &str = "sys_common_status";
+
+/// 登录日志服务
+pub struct SysLoginInfoService;
+
+impl SysLoginInfoService {
+    /// 根据条件分页查询登录日志
+    pub async fn select_login_info_list(
+        condition: &LoginInfoSearchCondition,
+        page: &PageDomain,
+    ) -> Result<PageResult<SysLoginInfo>> {
+        let (list, total) = SysLoginInfoRepository::select_login_info_list(condition, page).await?;
+        Ok(PageResult::new(page.page_num(), page.page_size(), total, list))
+    }
+
+    /// 查询登录日志详情
+    pub async fn select_login_info_by_id(info_id: &Uuid) -> Result<SysLoginInfo> {
+        SysLoginInfoRepository::select_login_info_by_id(info_id)
+            .await?
+            .ok_or_else(|| ZzylError::NotFound("登录日志不存在".to_string()))
+    }
+
+    /// 批量删除登录日志
+    pub async fn delete_login_info_by_ids(info_ids: &[Uuid]) -> Result<()> {
+        if info_ids.is_empty() {
+            return Err(ZzylError::InvalidParameter("请选择要删除的日志".to_string()));
+        }
+        SysLoginInfoRepository::delete_login_info_by_ids(info_ids).await?;
+        Ok(())
+    }
+
+    /// 清空登录日志
+    pub async fn clean_login_info() -> Result<()> {
+        SysLoginInfoRepository::clean_login_info().await
+    }
+
+    /// 解锁账户，清除密码错误次数
+    pub async fn unlock(username: &str) -> Result<()> {
+        let username = username.trim();
+        if username.is_empty() {
+            return Err(ZzylError::InvalidParameter("用户账号不能为空".to_string()));
+        }
+        SysLoginService::clear_login_fail_count(username).await
+    }
+
+    /// 导出登录日志，返回含表头的表格行
+    pub async fn export_login_info(condition: &LoginInfoSearchCondition) -> Result<Vec<Vec<String>>> {
+        let login_infos = SysLoginInfoRepository::select_login_info_export(condition).await?;
+        let status_labels = SysDictTypeService::get_dict_label_map(LOGIN_STATUS_DICT).await?;
+
+        let mut rows = Vec::with_capacity(login_infos.len() + 1);
+        rows.push(
+            ["用户账号", "登录状态", "登录地址", "登录地点", "浏览器", "操作系统", "提示消息", "访问时间"]
+                .iter()
+                .map(|header| header.to_string())
+                .collect(),
+        );
+        for login_info in login_infos {
+            let status = login_info.status.unwrap_or_default();
+            rows.push(vec![
+                login_info.username.unwrap_or_default(),
+                status_labels.get(&status).cloned().unwrap_or(status),
+                login_info.ipaddr.unwrap_or_default(),
+                login_info.login_location.unwrap_or_default(),
+                login_info.browser.unwrap_or_default(),
+                login_info.os.unwrap_or_default(),
+                login_info.msg.unwrap_or_default(),
+                login_info.login_time.as_ref().map(DateUtils::format_datetime_default).unwrap_or_default(),
+            ]);
+        }
+        Ok(rows)
+    }
+}
@@ -0,0 +1,234 @@
+use std::collections::HashMap;
+use uuid::Uuid;
+use zzyl_common::constants::constants::{INNER_LINK, LAYOUT, NO_FRAME, PARENT_VIEW, TYPE_DIR, TYPE_MENU};
+use zzyl_common::error::{Result, ZzylError};
+use zzyl_common::utils::StringUtils;
+use zzyl_common::LoginUser;
+use zzyl_framework::security::PermissionService;
+use crate::domain::{MetaVo, RoleMenuTreeResponse, RouterVo, SysMenu, TreeSelect};
+use crate::repository::{SysMenuRepository, SysRoleRepository};
+
+/// 目录重定向标识，面包屑中不可点击
+const NO_REDIRECT: &str = "noRedirect";
+
+/// 菜单管理服务
+pub struct SysMenuService;
+
+impl SysMenuService {
+    /// 构建当前用户的前端路由
+    pub async fn select_routers(login_user: &LoginUser) -> Result<Vec<RouterVo>> {
+        let menus = if PermissionService::is_admin(login_user) {
+            SysMenuRepository::select_all_menus().await?
+        } else {
+            SysMenuRepository::select_menus_by_user_id(&login_user.user_id).await?
+        };
+        Ok(Self::build_routers(&menus))
+    }
+
+    /// 构建当前用户可分配的菜单下拉树
+    pub async fn select_menu_tree_select(login_user: &LoginUser) -> Result<Vec<TreeSelect>> {
+        let menus = Self::select_menu_list(login_user).await?;
+        Ok(Self::build_tree_select(&menus))
+    }
+
+    /// 构建角色菜单树及已选中的菜单
+    pub async fn select_role_menu_tree(login_user: &LoginUser, role_id: &Uuid) -> Result<RoleMenuTreeResponse> {
+        let role = SysRoleRepository::select_role_by_id(role_id)
+            .await?
+            .ok_or_else(|| ZzylError::NotFound("角色不存在".to_string()))?;
+        let menus = Self::select_menu_list(login_user).await?;
+        Ok(RoleMenuTreeResponse {
+            checked_keys: SysMenuRepository::select_checked_menu_ids(role_id, role.menu_check_strictly).await?,
+            menus: Self::build_tree_select(&menus),
+        })
+    }
+
+    /// 构建前端路由树
+    pub fn build_routers(menus: &[SysMenu]) -> Vec<RouterVo> {
+        let children = Self::group_children(menus);
+        Self::root_menus(menus)
+            .into_iter()
+            .map(|menu| Self::build_router(menu, &children))
+            .collect()
+    }
+
+    /// 构建下拉树
+    pub fn build_tree_select(menus: &[SysMenu]) -> Vec<TreeSelect> {
+        let children = Self::group_children(menus);
+        Self::root_menus(menus)
+            .into_iter()
+            .filter_map(|menu| Self::build_tree_node(menu, &children))
+            .collect()
+    }
+
+    /// 查询用户可见的全部菜单（含按钮）
+    async fn select_menu_list(login_user: &LoginUser) -> Result<Vec<SysMenu>> {
+        if PermissionService::is_admin(login_user) {
+            SysMenuRepository::select_menu_list().await
+        } else {
+            SysMenuRepository::select_menu_list_by_user_id(&login_user.user_id).await
+        }
+    }
+
+    /// 构建单个路由及其子路由
+    fn build_router(menu: &SysMenu, children: &HashMap<Uuid, Vec<&SysMenu>>) -> RouterVo {
+        let path = menu.path.clone().unwrap_or_default();
+        let sub_menus = menu.menu_id
+            .and_then(|menu_id| children.get(&menu_id))
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+
+        let mut router = RouterVo {
+            name: Self::route_name(menu),
+            path: Self::router_path(menu),
+            hidden: menu.visible == "1",
+            redirect: None,
+            component: Self::component(menu),
+            query: menu.query.clone(),
+            always_show: None,
+            meta: Some(Self::meta(menu, None)),
+            children: Vec::new(),
+        };
+
+        if !sub_menus.is_empty() && menu.menu_type == TYPE_DIR {
+            router.always_show = Some(true);
+            router.redirect = Some(NO_REDIRECT.to_string());
+            router.children = sub_menus.iter().map(|sub| Self::build_router(sub, children)).collect();
+        } else if Self::is_menu_frame(menu) {
+            router.meta = None;
+            router.children.push(RouterVo {
+                name: StringUtils::capitalize(&path),
+                path: path.clone(),
+                hidden: false,
+                redirect: None,
+                component: menu.component.clone().unwrap_or_default(),
+                query: menu.query.clone(),
+                always_show: None,
+                meta: Some(Self::meta(menu, None)),
+                children: Vec::new(),
+            });
+        } else if Self::is_root(menu) && Self::is_inner_link(menu) {
+            router.path = "/".to_string();
+            router.meta = Some(MetaVo { title: menu.menu_name.clone(), icon: menu.icon.clone(), no_cache: false, link: None });
+            let inner_path = Self::inner_link_replace_each(&path);
+            router.children.push(RouterVo {
+                name: StringUtils::capitalize(&inner_path),
+                path: inner_path,
+                hidden: false,
+                redirect: None,
+                component: INNER_LINK.to_string(),
+                query: None,
+                always_show: None,
+                meta: Some(Self::meta(menu, Some(path))),
+                children: Vec::new(),
+            });
+        }
+        router
+    }
+
+    /// 构建单个下拉树节点，菜单ID为空时忽略
+    fn build_tree_node(menu: &SysMenu, children: &HashMap<Uuid, Vec<&SysMenu>>) -> Option<TreeSelect> {
+        let menu_id = menu.menu_id?;
+        Some(TreeSelect {
+            id: menu_id,
+            label: menu.menu_name.clone(),
+            children: children.get(&menu_id)
+                .map(|subs| subs.iter().filter_map(|sub| Self::build_tree_node(sub, children)).collect())
+                .unwrap_or_default(),
+        })
+    }
+
+    /// 构建路由元信息
+    fn meta(menu: &SysMenu, link: Option<String>) -> MetaVo {
+        let link = link.or_else(|| menu.path.clone().filter(|path| Self::is_http(path)));
+        MetaVo {
+            title: menu.menu_name.clone(),
+            icon: menu.icon.clone(),
+            no_cache: menu.is_cache == 1,
+            link,
+        }
+    }
+
+    /// 按父菜单分组子菜单，保持原有排序
+    fn group_children(menus: &[SysMenu]) -> HashMap<Uuid, Vec<&SysMenu>> {
+        let mut children: HashMap<Uuid, Vec<&SysMenu>> = HashMap::new();
+        for menu in menus {
+            if let Some(parent_id) = menu.parent_id.filter(|id| !id.is_nil()) {
+                children.entry(parent_id).or_default().push(menu);
+            }
+        }
+        children
+    }
+
+    /// 查找顶层菜单：父菜单为空或不在列表中
+    fn root_menus(menus: &[SysMenu]) -> Vec<&SysMenu> {
+        let mut roots: Vec<&SysMenu> = menus.iter()
+            .filter(|menu| match menu.parent_id.filter(|id| !id.is_nil()) {
+                Some(parent_id) => !menus.iter().any(|m| m.menu_id == Some(parent_id)),
+                None => true,
+            })
+            .collect();
+        roots.sort_by_key(|menu| menu.order_num);
+        roots
+    }
+
+    /// 获取路由名称，一级菜单外链不设置名称
+    fn route_name(menu: &SysMenu) -> String {
+        if Self::is_menu_frame(menu) {
+            return String::new();
+        }
+        StringUtils::capitalize(menu.path.as_deref().unwrap_or_default())
+    }
+
+    /// 获取路由地址
+    fn router_path(menu: &SysMenu) -> String {
+        let path = menu.path.clone().unwrap_or_default();
+        if !Self::is_root(menu) && Self::is_inner_link(menu) {
+            return Self::inner_link_replace_each(&path);
+        }
+        if Self::is_root(menu) && menu.menu_type == TYPE_DIR && menu.is_frame == NO_FRAME {
+            return format!("/{}", path);
+        }
+        if Self::is_menu_frame(menu) {
+            return "/".to_string();
+        }
+        path
+    }
+
+    /// 获取组件信息
+    fn component(menu: &SysMenu) -> String {
+        let component = menu.component.as_deref().filter(|component| !component.trim().is_empty());
+        match component {
+            Some(component) if !Self::is_menu_frame(menu) => component.to_string(),
+            None if !Self::is_root(menu) && Self::is_inner_link(menu) => INNER_LINK.to_string(),
+            None if !Self::is_root(menu) && menu.menu_type == TYPE_DIR => PARENT_VIEW.to_string(),
+            _ => LAYOUT.to_string(),
+        }
+    }
+
+    /// 是否为顶层菜单
+    fn is_root(menu: &SysMenu) -> bool {
+        menu.parent_id.is_none_or(|id| id.is_nil())
+    }
+
+    /// 是否为一级菜单且非外链（内部跳转）
+    fn is_menu_frame(menu: &SysMenu) -> bool {
+        Self::is_root(menu) && menu.menu_type == TYPE_MENU && menu.is_frame == NO_FRAME
+    }
+
+    /// 是否为内链组件
+    fn is_inner_link(menu: &SysMenu) -> bool {
+        menu.is_frame == NO_FRAME && menu.path.as_deref().is_some_and(Self::is_http)
+    }
+
+    /// 是否为http(s)地址
+    fn is_http(path: &str) -> bool {
+        path.starts_with("http://") || path.starts_with("https://")
+    }
+
+    /// 内链地址转换为路由地址
+    fn inner_link_replace_each(path: &str) -> String {
+        let path = path.trim_start_matches("http://").trim_start_matches("https://");
+        path.trim_start_matches("www.").replace(['.', ':'], "/")
+    }
+}
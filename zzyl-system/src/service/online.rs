@@ -0,0 +1,43 @@
+use zzyl_common::error::{Result, ZzylError};
+use zzyl_common::{PageDomain, PageResult};
+use zzyl_framework::security::TokenService;
+use crate::domain::{OnlineSearchCondition, SysUserOnline};
+
+/// 在线用户服务
+pub struct SysUserOnlineService;
+
+impl SysUserOnlineService {
+    /// 分页查询在线用户，按登录时间倒序
+    pub async fn select_online_list(
+        condition: &OnlineSearchCondition,
+        page: &PageDomain,
+    ) -> Result<PageResult<SysUserOnline>> {
+        let ipaddr = condition.ipaddr.as_deref().map(str::trim).filter(|ipaddr| !ipaddr.is_empty());
+        let user_name = condition.user_name.as_deref().map(str::trim).filter(|name| !name.is_empty());
+
+        let mut sessions: Vec<SysUserOnline> = TokenService::list_sessions()
+            .await?
+            .into_iter()
+            .filter(|session| ipaddr.is_none_or(|ipaddr| session.ipaddr == ipaddr))
+            .filter(|session| user_name.is_none_or(|name| session.user_name.contains(name)))
+            .map(SysUserOnline::from)
+            .collect();
+        sessions.sort_by(|a, b| b.login_time.cmp(&a.login_time));
+
+        let total = sessions.len() as i64;
+        let list = sessions.into_iter()
+            .skip(page.offset() as usize)
+            .take(page.page_size() as usize)
+            .collect();
+        Ok(PageResult::new(page.page_num(), page.page_size(), total, list))
+    }
+
+    /// 强退用户
+    pub async fn force_logout(token_id: &str) -> Result<()> {
+        if TokenService::delete_session(token_id).await? {
+            Ok(())
+        } else {
+            Err(ZzylError::NotFound("会话不存在或已失效".to_string()))
+        }
+    }
+}
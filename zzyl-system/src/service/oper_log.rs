@@ -0,0 +1,91 @@
+use uuid::Uuid;
+use zzyl_common::error::{Result, ZzylError};
+use zzyl_common::utils::DateUtils;
+use zzyl_common::{PageDomain, PageResult};
+use crate::domain::{OperLogSearchCondition, SysOperLog};
+use crate::repository::SysOperLogRepository;
+use crate::service::SysDictTypeService;
+
+/// 业务类型字典类型
+const OPER_TYPE_DICT: &str = "sys_oper_type";
+
+/// 操作状态字典类型
+const OPER_STATUS_DICT: &str = "sys_common_status";
+
+/// 操作日志服务
+pub struct SysOperLogService;
+
+impl SysOperLogService {
+    /// 新增操作日志
+    pub async fn insert_oper_log(oper_log: SysOperLog) -> Result<()> {
+        SysOperLogRepository::insert_oper_log(&oper_log).await?;
+        Ok(())
+    }
+
+    /// 根据条件分页查询操作日志
+    pub async fn select_oper_log_list(
+        condition: &OperLogSearchCondition,
+        page: &PageDomain,
+    ) -> Result<PageResult<SysOperLog>> {
+        let (list, total) = SysOperLogRepository::select_oper_log_list(condition, page).await?;
+        Ok(PageResult::new(page.page_num(), page.page_size(), total, list))
+    }
+
+    /// 查询操作日志详情
+    pub async fn select_oper_log_by_id(oper_id: &Uuid) -> Result<SysOperLog> {
+        SysOperLogRepository::select_oper_log_by_id(oper_id)
+            .await?
+            .ok_or_else(|| ZzylError::NotFound("操作日志不存在".to_string()))
+    }
+
+    /// 批量删除操作日志
+    pub async fn delete_oper_log_by_ids(oper_ids: &[Uuid]) -> Result<()> {
+        if oper_ids.is_empty() {
+            return Err(ZzylError::InvalidParameter("请选择要删除的日志".to_string()));
+        }
+        SysOperLogRepository::delete_oper_log_by_ids(oper_ids).await?;
+        Ok(())
+    }
+
+    /// 清空操作日志
+    pub async fn clean_oper_log() -> Result<()> {
+        SysOperLogRepository::clean_oper_log().await
+    }
+
+    /// 导出操作日志，返回含表头的表格行
+    pub async fn export_oper_log(condition: &OperLogSearchCondition) -> Result<Vec<Vec<String>>> {
+        let oper_logs = SysOperLogRepository::select_oper_log_export(condition).await?;
+        let type_labels = SysDictTypeService::get_dict_label_map(OPER_TYPE_DICT).await?;
+        let status_labels = SysDictTypeService::get_dict_label_map(OPER_STATUS_DICT).await?;
+
+        let mut rows = Vec::with_capacity(oper_logs.len() + 1);
+        rows.push(
+            [
+                "系统模块", "业务类型", "请求方式", "操作人员", "部门名称", "请求地址", "操作地址", "操作地点",
+                "操作状态", "错误消息", "操作时间", "消耗时间（毫秒）",
+            ]
+                .iter()
+                .map(|header| header.to_string())
+                .collect(),
+        );
+        for oper_log in oper_logs {
+            let business_type = oper_log.business_type.to_string();
+            let status = oper_log.status.to_string();
+            rows.push(vec![
+                oper_log.title.unwrap_or_default(),
+                type_labels.get(&business_type).cloned().unwrap_or(business_type),
+                oper_log.request_method.unwrap_or_default(),
+                oper_log.oper_name.unwrap_or_default(),
+                oper_log.dept_name.unwrap_or_default(),
+                oper_log.oper_url.unwrap_or_default(),
+                oper_log.oper_ip.unwrap_or_default(),
+                oper_log.oper_location.unwrap_or_default(),
+                status_labels.get(&status).cloned().unwrap_or(status),
+                oper_log.error_msg.unwrap_or_default(),
+                oper_log.oper_time.as_ref().map(DateUtils::format_datetime_default).unwrap_or_default(),
+                oper_log.cost_time.map(|cost| cost.to_string()).unwrap_or_default(),
+            ]);
+        }
+        Ok(rows)
+    }
+}
@@ -0,0 +1,132 @@
+use chrono::Utc;
+use uuid::Uuid;
+use zzyl_common::error::{Result, ZzylError};
+use zzyl_common::{BaseEntity, LoginUser, PageDomain, PageResult};
+use crate::domain::{PostRequest, PostSearchCondition, SysPost};
+use crate::repository::SysPostRepository;
+use crate::service::SysDictTypeService;
+
+/// 岗位状态字典类型
+const POST_STATUS_DICT: &str = "sys_normal_disable";
+
+/// 岗位管理服务
+pub struct SysPostService;
+
+impl SysPostService {
+    /// 根据条件分页查询岗位
+    pub async fn select_post_list(condition: &PostSearchCondition, page: &PageDomain) -> Result<PageResult<SysPost>> {
+        let (list, total) = SysPostRepository::select_post_list(condition, page).await?;
+        Ok(PageResult::new(page.page_num(), page.page_size(), total, list))
+    }
+
+    /// 查询全部岗位（用于下拉选择）
+    pub async fn select_post_all() -> Result<Vec<SysPost>> {
+        SysPostRepository::select_post_all().await
+    }
+
+    /// 查询岗位详情
+    pub async fn select_post_by_id(post_id: &Uuid) -> Result<SysPost> {
+        SysPostRepository::select_post_by_id(post_id)
+            .await?
+            .ok_or_else(|| ZzylError::NotFound("岗位不存在".to_string()))
+    }
+
+    /// 新增岗位
+    pub async fn insert_post(login_user: &LoginUser, request: &PostRequest) -> Result<Uuid> {
+        let post_code = request.post_code.trim();
+        let post_name = request.post_name.trim();
+        Self::check_post_unique(post_code, post_name, None).await?;
+
+        let post_id = Uuid::new_v4();
+        let post = SysPost {
+            post_id: Some(post_id),
+            post_code: post_code.to_string(),
+            post_name: post_name.to_string(),
+            post_sort: request.post_sort,
+            status: request.status.clone().unwrap_or_else(|| "0".to_string()),
+            base: BaseEntity {
+                create_by: Some(login_user.username.clone()),
+                create_time: Some(Utc::now()),
+                update_by: None,
+                update_time: None,
+                remark: request.remark.clone(),
+            },
+        };
+        SysPostRepository::insert_post(&post).await?;
+        Ok(post_id)
+    }
+
+    /// 修改岗位
+    pub async fn update_post(login_user: &LoginUser, request: &PostRequest) -> Result<()> {
+        let post_id = request.post_id
+            .ok_or_else(|| ZzylError::InvalidParameter("岗位ID不能为空".to_string()))?;
+        let existing = Self::select_post_by_id(&post_id).await?;
+        let post_code = request.post_code.trim();
+        let post_name = request.post_name.trim();
+        Self::check_post_unique(post_code, post_name, Some(&post_id)).await?;
+
+        let post = SysPost {
+            post_code: post_code.to_string(),
+            post_name: post_name.to_string(),
+            post_sort: request.post_sort,
+            status: request.status.clone().unwrap_or_else(|| existing.status.clone()),
+            base: BaseEntity {
+                update_by: Some(login_user.username.clone()),
+                update_time: Some(Utc::now()),
+                remark: request.remark.clone(),
+                ..existing.base
+            },
+            ..existing
+        };
+        SysPostRepository::update_post(&post).await?;
+        Ok(())
+    }
+
+    /// 批量删除岗位，已分配给用户的岗位不允许删除
+    pub async fn delete_post_by_ids(post_ids: &[Uuid]) -> Result<()> {
+        if post_ids.is_empty() {
+            return Err(ZzylError::InvalidParameter("请选择要删除的岗位".to_string()));
+        }
+        for post_id in post_ids {
+            let post = Self::select_post_by_id(post_id).await?;
+            if SysPostRepository::count_user_by_post_id(post_id).await? > 0 {
+                return Err(ZzylError::Business(format!("{}已分配,不能删除", post.post_name)));
+            }
+        }
+        SysPostRepository::delete_post_by_ids(post_ids).await?;
+        Ok(())
+    }
+
+    /// 导出岗位数据，返回含表头的表格行
+    pub async fn export_posts(condition: &PostSearchCondition) -> Result<Vec<Vec<String>>> {
+        let posts = SysPostRepository::select_post_export(condition).await?;
+        let status_labels = SysDictTypeService::get_dict_label_map(POST_STATUS_DICT).await?;
+
+        let mut rows = Vec::with_capacity(posts.len() + 1);
+        rows.push(["岗位编码", "岗位名称", "岗位排序", "状态"].iter().map(|header| header.to_string()).collect());
+        for post in posts {
+            let status = status_labels.get(&post.status).cloned().unwrap_or(post.status);
+            rows.push(vec![post.post_code, post.post_name, post.post_sort.to_string(), status]);
+        }
+        Ok(rows)
+    }
+
+    /// 校验岗位编码与名称唯一
+    async fn check_post_unique(post_code: &str, post_name: &str, post_id: Option<&Uuid>) -> Result<()> {
+        if post_code.is_empty() {
+            return Err(ZzylError::InvalidParameter("岗位编码不能为空".to_string()));
+        }
+        if post_name.is_empty() {
+            return Err(ZzylError::InvalidParameter("岗位名称不能为空".to_string()));
+        }
+        let found = SysPostRepository::select_post_id_by_code(post_code).await?;
+        if found.is_some_and(|found| Some(&found) != post_id) {
+            return Err(ZzylError::AlreadyExists(format!("岗位编码'{}'已存在", post_code)));
+        }
+        let found = SysPostRepository::select_post_id_by_name(post_name).await?;
+        if found.is_some_and(|found| Some(&found) != post_id) {
+            return Err(ZzylError::AlreadyExists(format!("岗位名称'{}'已存在", post_name)));
+        }
+        Ok(())
+    }
+}
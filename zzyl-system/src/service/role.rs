@@ -0,0 +1,279 @@
+use chrono::Utc;
+use uuid::Uuid;
+use zzyl_common::annotation::DataScope;
+use zzyl_common::data_scope;
+use zzyl_common::error::{Result, ZzylError};
+use zzyl_common::{BaseEntity, LoginUser, PageDomain, PageResult};
+use zzyl_framework::database::require_database_pool;
+use zzyl_framework::security::{DataScopeFilter, PermissionService, SUPER_ADMIN};
+use crate::domain::{
+    ChangeRoleStatusRequest, RoleDataScopeRequest, RoleDetailResponse, RoleRequest, RoleSearchCondition,
+    RoleUsersRequest, SysRole, SysUser, UserSearchCondition,
+};
+use crate::repository::{
+    SysRoleDeptRepository, SysRoleMenuRepository, SysRoleRepository, SysUserRepository, SysUserRoleRepository,
+};
+use crate::service::{SysLoginService, SysUserService};
+
+/// 自定义数据权限
+const DATA_SCOPE_CUSTOM: &str = "2";
+
+/// 合法的数据范围取值
+const DATA_SCOPES: [&str; 5] = ["1", "2", "3", "4", "5"];
+
+/// 角色管理服务
+pub struct SysRoleService;
+
+impl SysRoleService {
+    /// 根据条件分页查询角色列表
+    pub async fn select_role_list(condition: &RoleSearchCondition, page: &PageDomain) -> Result<PageResult<SysRole>> {
+        let (list, total) = SysRoleRepository::select_role_list(condition, page).await?;
+        Ok(PageResult::new(page.page_num(), page.page_size(), total, list))
+    }
+
+    /// 查询全部角色
+    pub async fn select_role_all() -> Result<Vec<SysRole>> {
+        SysRoleRepository::select_role_all().await
+    }
+
+    /// 查询角色详情及已分配的菜单、部门
+    pub async fn select_role_detail(role_id: &Uuid) -> Result<RoleDetailResponse> {
+        let role = Self::require_role(role_id).await?;
+        Ok(RoleDetailResponse {
+            role,
+            menu_ids: SysRoleMenuRepository::select_menu_ids_by_role_id(role_id).await?,
+            dept_ids: SysRoleDeptRepository::select_dept_ids_by_role_id(role_id).await?,
+        })
+    }
+
+    /// 新增角色
+    pub async fn insert_role(login_user: &LoginUser, request: &RoleRequest) -> Result<Uuid> {
+        Self::check_role_unique(request, None).await?;
+
+        let role_id = Uuid::new_v4();
+        let role = SysRole {
+            role_id: Some(role_id),
+            role_name: request.role_name.trim().to_string(),
+            role_key: request.role_key.trim().to_string(),
+            role_sort: request.role_sort,
+            data_scope: "1".to_string(),
+            menu_check_strictly: request.menu_check_strictly,
+            dept_check_strictly: request.dept_check_strictly,
+            status: request.status.clone().unwrap_or_else(|| "0".to_string()),
+            del_flag: "0".to_string(),
+            remark: request.remark.clone(),
+            base: BaseEntity {
+                create_by: Some(login_user.username.clone()),
+                create_time: Some(Utc::now()),
+                update_by: None,
+                update_time: None,
+                remark: request.remark.clone(),
+            },
+        };
+
+        let mut tx = require_database_pool()?.begin().await?;
+        SysRoleRepository::insert_role(&mut tx, &role).await?;
+        SysRoleMenuRepository::insert_role_menus(&mut tx, &role_id, &request.menu_ids).await?;
+        tx.commit().await?;
+
+        Ok(role_id)
+    }
+
+    /// 修改角色及其菜单权限
+    pub async fn update_role(login_user: &LoginUser, request: &RoleRequest) -> Result<()> {
+        let role_id = request.role_id
+            .ok_or_else(|| ZzylError::InvalidParameter("角色ID不能为空".to_string()))?;
+        let existing = Self::require_role(&role_id).await?;
+        Self::check_role_allowed(&existing)?;
+        Self::check_role_unique(request, Some(&role_id)).await?;
+
+        let role = SysRole {
+            role_name: request.role_name.trim().to_string(),
+            role_key: request.role_key.trim().to_string(),
+            role_sort: request.role_sort,
+            menu_check_strictly: request.menu_check_strictly,
+            dept_check_strictly: request.dept_check_strictly,
+            status: request.status.clone().unwrap_or_else(|| existing.status.clone()),
+            remark: request.remark.clone(),
+            base: BaseEntity {
+                update_by: Some(login_user.username.clone()),
+                update_time: Some(Utc::now()),
+                remark: request.remark.clone(),
+                ..existing.base.clone()
+            },
+            ..existing
+        };
+
+        let mut tx = require_database_pool()?.begin().await?;
+        SysRoleRepository::update_role(&mut tx, &role).await?;
+        SysRoleMenuRepository::delete_by_role_ids(&mut tx, &[role_id]).await?;
+        SysRoleMenuRepository::insert_role_menus(&mut tx, &role_id, &request.menu_ids).await?;
+        tx.commit().await?;
+
+        Self::refresh_role_users(&[role_id]).await
+    }
+
+    /// 修改角色数据权限
+    pub async fn auth_data_scope(login_user: &LoginUser, request: &RoleDataScopeRequest) -> Result<()> {
+        if !DATA_SCOPES.contains(&request.data_scope.as_str()) {
+            return Err(ZzylError::InvalidParameter(format!("数据范围'{}'不合法", request.data_scope)));
+        }
+        let role = Self::require_role(&request.role_id).await?;
+        Self::check_role_allowed(&role)?;
+
+        let dept_ids: &[Uuid] = if request.data_scope == DATA_SCOPE_CUSTOM { &request.dept_ids } else { &[] };
+        let mut tx = require_database_pool()?.begin().await?;
+        SysRoleRepository::update_data_scope(&mut tx, &request.role_id, &request.data_scope, &login_user.username)
+            .await?;
+        SysRoleDeptRepository::delete_by_role_ids(&mut tx, &[request.role_id]).await?;
+        SysRoleDeptRepository::insert_role_depts(&mut tx, &request.role_id, dept_ids).await?;
+        tx.commit().await?;
+
+        Self::refresh_role_users(&[request.role_id]).await
+    }
+
+    /// 修改角色状态
+    pub async fn change_status(login_user: &LoginUser, request: &ChangeRoleStatusRequest) -> Result<()> {
+        if request.status != "0" && request.status != "1" {
+            return Err(ZzylError::InvalidParameter(format!("角色状态'{}'不合法", request.status)));
+        }
+        let role = Self::require_role(&request.role_id).await?;
+        Self::check_role_allowed(&role)?;
+        SysRoleRepository::update_status(&request.role_id, &request.status, &login_user.username).await?;
+        Self::refresh_role_users(&[request.role_id]).await
+    }
+
+    /// 批量删除角色（逻辑删除），已分配用户的角色不允许删除
+    pub async fn delete_role_by_ids(login_user: &LoginUser, role_ids: &[Uuid]) -> Result<()> {
+        if role_ids.is_empty() {
+            return Err(ZzylError::InvalidParameter("请选择要删除的角色".to_string()));
+        }
+        for role_id in role_ids {
+            let role = Self::require_role(role_id).await?;
+            Self::check_role_allowed(&role)?;
+            if !SysUserRoleRepository::select_user_ids_by_role_ids(&[*role_id]).await?.is_empty() {
+                return Err(ZzylError::Business(format!("{}已分配,不能删除", role.role_name)));
+            }
+        }
+
+        let mut tx = require_database_pool()?.begin().await?;
+        SysRoleMenuRepository::delete_by_role_ids(&mut tx, role_ids).await?;
+        SysRoleDeptRepository::delete_by_role_ids(&mut tx, role_ids).await?;
+        SysRoleRepository::delete_role_by_ids(&mut tx, role_ids, &login_user.username).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// 分页查询已分配该角色的用户
+    pub async fn select_allocated_list(
+        login_user: &LoginUser,
+        role_id: &Uuid,
+        condition: &UserSearchCondition,
+        page: &PageDomain,
+    ) -> Result<PageResult<SysUser>> {
+        let scope = DataScopeFilter::build(login_user, &data_scope!("u", "d"));
+        let (list, total) = SysUserRepository::select_allocated_list(role_id, condition, &scope, page).await?;
+        Ok(PageResult::new(page.page_num(), page.page_size(), total, list))
+    }
+
+    /// 分页查询未分配该角色的用户
+    pub async fn select_unallocated_list(
+        login_user: &LoginUser,
+        role_id: &Uuid,
+        condition: &UserSearchCondition,
+        page: &PageDomain,
+    ) -> Result<PageResult<SysUser>> {
+        let scope = DataScopeFilter::build(login_user, &data_scope!("u", "d"));
+        let (list, total) = SysUserRepository::select_unallocated_list(role_id, condition, &scope, page).await?;
+        Ok(PageResult::new(page.page_num(), page.page_size(), total, list))
+    }
+
+    /// 批量授权用户
+    pub async fn grant_users(login_user: &LoginUser, request: &RoleUsersRequest) -> Result<()> {
+        Self::check_role_users(login_user, request).await?;
+        SysUserRoleRepository::insert_role_users(&request.role_id, &request.user_ids).await?;
+        Self::refresh_users(&request.user_ids).await
+    }
+
+    /// 批量取消用户授权
+    pub async fn revoke_users(login_user: &LoginUser, request: &RoleUsersRequest) -> Result<()> {
+        Self::check_role_users(login_user, request).await?;
+        SysUserRoleRepository::delete_role_users(&request.role_id, &request.user_ids).await?;
+        Self::refresh_users(&request.user_ids).await
+    }
+
+    /// 校验角色是否允许操作（超级管理员角色不允许被修改）
+    pub fn check_role_allowed(role: &SysRole) -> Result<()> {
+        if role.role_key == SUPER_ADMIN {
+            return Err(ZzylError::Forbidden("不允许操作超级管理员角色".to_string()));
+        }
+        Ok(())
+    }
+
+    /// 校验当前用户是否有权访问目标角色的数据
+    pub async fn check_role_data_scope(login_user: &LoginUser, role_id: &Uuid) -> Result<()> {
+        if PermissionService::is_admin(login_user) {
+            return Ok(());
+        }
+        let scope = DataScopeFilter::build(login_user, &data_scope!("u", "d"));
+        if SysRoleRepository::count_role_in_scope(role_id, &scope).await? == 0 {
+            return Err(ZzylError::Permission("没有权限访问角色数据！".to_string()));
+        }
+        Ok(())
+    }
+
+    /// 查询角色，不存在时返回错误
+    async fn require_role(role_id: &Uuid) -> Result<SysRole> {
+        SysRoleRepository::select_role_by_id(role_id)
+            .await?
+            .ok_or_else(|| ZzylError::NotFound("角色不存在".to_string()))
+    }
+
+    /// 校验角色名称与权限字符唯一
+    async fn check_role_unique(request: &RoleRequest, role_id: Option<&Uuid>) -> Result<()> {
+        let is_other = |found: Option<Uuid>| found.is_some_and(|found| Some(&found) != role_id);
+        let role_name = request.role_name.trim();
+        let role_key = request.role_key.trim();
+        if role_name.is_empty() || role_key.is_empty() {
+            return Err(ZzylError::InvalidParameter("角色名称和权限字符不能为空".to_string()));
+        }
+        if role_key == SUPER_ADMIN {
+            return Err(ZzylError::Forbidden("不允许使用超级管理员权限字符".to_string()));
+        }
+
+        if is_other(SysRoleRepository::select_role_id_by_name(role_name).await?) {
+            return Err(ZzylError::AlreadyExists(format!("角色'{}'保存失败，角色名称已存在", role_name)));
+        }
+        if is_other(SysRoleRepository::select_role_id_by_key(role_key).await?) {
+            return Err(ZzylError::AlreadyExists(format!("角色'{}'保存失败，角色权限已存在", role_name)));
+        }
+        Ok(())
+    }
+
+    /// 校验批量授权请求
+    async fn check_role_users(login_user: &LoginUser, request: &RoleUsersRequest) -> Result<()> {
+        if request.user_ids.is_empty() {
+            return Err(ZzylError::InvalidParameter("请选择要授权的用户".to_string()));
+        }
+        let role = Self::require_role(&request.role_id).await?;
+        Self::check_role_allowed(&role)?;
+        for user_id in &request.user_ids {
+            SysUserService::check_user_data_scope(login_user, user_id).await?;
+        }
+        Ok(())
+    }
+
+    /// 刷新拥有指定角色的用户的登录缓存
+    async fn refresh_role_users(role_ids: &[Uuid]) -> Result<()> {
+        let user_ids = SysUserRoleRepository::select_user_ids_by_role_ids(role_ids).await?;
+        Self::refresh_users(&user_ids).await
+    }
+
+    /// 刷新指定用户的登录缓存
+    async fn refresh_users(user_ids: &[Uuid]) -> Result<()> {
+        for user_id in user_ids {
+            SysLoginService::refresh_cached_login_user(user_id).await?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,567 @@
+use std::collections::HashMap;
+use chrono::Utc;
+use tracing::warn;
+use uuid::Uuid;
+use zzyl_common::annotation::DataScope;
+use zzyl_common::constants::constants::{
+    CONFIG_KEY_INIT_PASSWORD, PASSWORD_MAX_LENGTH, PASSWORD_MIN_LENGTH, USERNAME_MAX_LENGTH, USERNAME_MIN_LENGTH,
+};
+use zzyl_common::data_scope;
+use zzyl_common::enums::{DelFlag, UserStatus};
+use zzyl_common::error::{Result, ZzylError};
+use zzyl_common::utils::{ExcelUtils, SecurityUtils, ValidationUtils};
+use zzyl_common::{BaseEntity, LoginUser, PageDomain, PageResult};
+use zzyl_framework::cache::ConfigCache;
+use zzyl_framework::database::require_database_pool;
+use zzyl_framework::security::{DataScopeFilter, PermissionService, TokenService, SUPER_ADMIN};
+use zzyl_oss::OssService;
+use crate::domain::{
+    AuthRoleRequest, ChangePasswordRequest, ChangeUserStatusRequest, ProfileRequest, ProfileResponse,
+    ResetPasswordRequest, SysUser, UserDetailResponse, UserImportResult, UserImportRow, UserImportRowResult,
+    UserRequest, UserSearchCondition,
+};
+use crate::repository::{
+    SysDeptRepository, SysPostRepository, SysRoleRepository, SysUserPostRepository, SysUserRepository,
+    SysUserRoleRepository,
+};
+use crate::service::{SysDeptService, SysDictTypeService, SysLoginService, SysRoleService};
+
+/// 头像存储目录
+const AVATAR_DIR: &str = "avatar";
+
+/// 头像允许的文件扩展名
+const AVATAR_EXTENSIONS: &[&str] = &["bmp", "gif", "jpg", "jpeg", "png"];
+
+/// 头像大小上限（字节）
+pub const AVATAR_MAX_SIZE: usize = 5 * 1024 * 1024;
+
+/// 用户导入文件大小上限（字节）
+pub const IMPORT_MAX_SIZE: usize = 10 * 1024 * 1024;
+
+/// 用户导入模板表头
+const USER_IMPORT_HEADERS: [&str; 7] = ["登录账号", "用户昵称", "部门名称", "手机号码", "用户邮箱", "用户性别", "帐号状态"];
+
+/// 用户性别字典类型
+const USER_SEX_DICT: &str = "sys_user_sex";
+
+/// 帐号状态字典类型
+const USER_STATUS_DICT: &str = "sys_normal_disable";
+
+/// 用户管理服务
+pub struct SysUserService;
+
+impl SysUserService {
+    /// 根据条件分页查询用户列表
+    pub async fn select_user_list(
+        login_user: &LoginUser,
+        condition: &UserSearchCondition,
+        page: &PageDomain,
+    ) -> Result<PageResult<SysUser>> {
+        let scope = DataScopeFilter::build(login_user, &data_scope!("u", "d"));
+        let (list, total) = SysUserRepository::select_user_list(condition, &scope, page).await?;
+        Ok(PageResult::new(page.page_num(), page.page_size(), total, list))
+    }
+
+    /// 查询用户详情及已分配的角色、岗位
+    pub async fn select_user_detail(login_user: &LoginUser, user_id: &Uuid) -> Result<UserDetailResponse> {
+        Self::check_user_data_scope(login_user, user_id).await?;
+        let user = Self::require_user(user_id).await?;
+        Ok(UserDetailResponse {
+            user,
+            role_ids: SysUserRoleRepository::select_role_ids_by_user_id(user_id).await?,
+            post_ids: SysUserPostRepository::select_post_ids_by_user_id(user_id).await?,
+        })
+    }
+
+    /// 新增用户
+    pub async fn insert_user(login_user: &LoginUser, request: &UserRequest) -> Result<Uuid> {
+        let username = request.username.trim();
+        let username_len = username.chars().count();
+        if !(USERNAME_MIN_LENGTH..=USERNAME_MAX_LENGTH).contains(&username_len) {
+            return Err(ZzylError::InvalidParameter(format!(
+                "用户账号长度必须在{}到{}个字符之间", USERNAME_MIN_LENGTH, USERNAME_MAX_LENGTH
+            )));
+        }
+        let password = match request.password.as_deref().filter(|password| !password.is_empty()) {
+            Some(password) => password.to_string(),
+            None => ConfigCache::get_string(CONFIG_KEY_INIT_PASSWORD, "").await,
+        };
+        if password.is_empty() {
+            return Err(ZzylError::InvalidParameter("用户密码不能为空".to_string()));
+        }
+        let password = Self::encrypt_password(&password)?;
+
+        if SysUserRepository::select_user_id_by_username(username).await?.is_some() {
+            return Err(ZzylError::AlreadyExists(format!("新增用户'{}'失败，登录账号已存在", username)));
+        }
+        Self::check_contact_unique(&request.username, request.phone.as_deref(), request.email.as_deref(), None).await?;
+        Self::check_assignment_scope(login_user, request.dept_id.as_ref(), &request.role_ids).await?;
+
+        let user_id = Uuid::new_v4();
+        let user = SysUser {
+            user_id: Some(user_id),
+            username: username.to_string(),
+            nick_name: request.nick_name.clone(),
+            email: request.email.clone(),
+            phone: request.phone.clone(),
+            sex: request.sex.clone(),
+            avatar: None,
+            password,
+            salt: None,
+            status: request.status.unwrap_or(UserStatus::Normal),
+            del_flag: DelFlag::Exists,
+            login_ip: None,
+            login_date: None,
+            dept_id: request.dept_id,
+            base: BaseEntity {
+                create_by: Some(login_user.username.clone()),
+                create_time: Some(Utc::now()),
+                update_by: None,
+                update_time: None,
+                remark: request.remark.clone(),
+            },
+        };
+
+        let mut tx = require_database_pool()?.begin().await?;
+        SysUserRepository::insert_user(&mut tx, &user).await?;
+        SysUserRoleRepository::insert_user_roles(&mut tx, &user_id, &request.role_ids).await?;
+        SysUserPostRepository::insert_user_posts(&mut tx, &user_id, &request.post_ids).await?;
+        tx.commit().await?;
+
+        Ok(user_id)
+    }
+
+    /// 修改用户
+    pub async fn update_user(login_user: &LoginUser, request: &UserRequest) -> Result<()> {
+        let user_id = request.user_id
+            .ok_or_else(|| ZzylError::InvalidParameter("用户ID不能为空".to_string()))?;
+        Self::check_user_allowed(&user_id).await?;
+        Self::check_user_data_scope(login_user, &user_id).await?;
+        let existing = Self::require_user(&user_id).await?;
+        Self::check_contact_unique(&request.username, request.phone.as_deref(), request.email.as_deref(), Some(&user_id))
+            .await?;
+        Self::check_assignment_scope(login_user, request.dept_id.as_ref(), &request.role_ids).await?;
+
+        let user = SysUser {
+            nick_name: request.nick_name.clone(),
+            email: request.email.clone(),
+            phone: request.phone.clone(),
+            sex: request.sex.clone(),
+            status: request.status.unwrap_or(existing.status),
+            dept_id: request.dept_id,
+            base: BaseEntity {
+                update_by: Some(login_user.username.clone()),
+                update_time: Some(Utc::now()),
+                remark: request.remark.clone(),
+                ..existing.base.clone()
+            },
+            ..existing
+        };
+
+        let mut tx = require_database_pool()?.begin().await?;
+        SysUserRepository::update_user(&mut tx, &user).await?;
+        SysUserRoleRepository::delete_by_user_ids(&mut tx, &[user_id]).await?;
+        SysUserRoleRepository::insert_user_roles(&mut tx, &user_id, &request.role_ids).await?;
+        SysUserPostRepository::delete_by_user_ids(&mut tx, &[user_id]).await?;
+        SysUserPostRepository::insert_user_posts(&mut tx, &user_id, &request.post_ids).await?;
+        tx.commit().await?;
+
+        SysLoginService::refresh_cached_login_user(&user_id).await
+    }
+
+    /// 批量删除用户（逻辑删除）
+    pub async fn delete_user_by_ids(login_user: &LoginUser, user_ids: &[Uuid]) -> Result<()> {
+        if user_ids.is_empty() {
+            return Err(ZzylError::InvalidParameter("请选择要删除的用户".to_string()));
+        }
+        if user_ids.contains(&login_user.user_id) {
+            return Err(ZzylError::Business("当前用户不能删除".to_string()));
+        }
+        for user_id in user_ids {
+            Self::check_user_allowed(user_id).await?;
+            Self::check_user_data_scope(login_user, user_id).await?;
+        }
+
+        let mut tx = require_database_pool()?.begin().await?;
+        SysUserRoleRepository::delete_by_user_ids(&mut tx, user_ids).await?;
+        SysUserPostRepository::delete_by_user_ids(&mut tx, user_ids).await?;
+        SysUserRepository::delete_user_by_ids(&mut tx, user_ids, &login_user.username).await?;
+        tx.commit().await?;
+
+        for user_id in user_ids {
+            TokenService::delete_login_user(user_id).await?;
+        }
+        Ok(())
+    }
+
+    /// 重置用户密码，用户需重新登录
+    pub async fn reset_password(login_user: &LoginUser, request: &ResetPasswordRequest) -> Result<()> {
+        Self::check_user_allowed(&request.user_id).await?;
+        Self::check_user_data_scope(login_user, &request.user_id).await?;
+        let password = Self::encrypt_password(&request.password)?;
+        if SysUserRepository::reset_password(&request.user_id, &password, &login_user.username).await? == 0 {
+            return Err(ZzylError::NotFound("用户不存在".to_string()));
+        }
+        TokenService::delete_login_user(&request.user_id).await
+    }
+
+    /// 修改用户状态，停用后用户立即下线
+    pub async fn change_status(login_user: &LoginUser, request: &ChangeUserStatusRequest) -> Result<()> {
+        Self::check_user_allowed(&request.user_id).await?;
+        Self::check_user_data_scope(login_user, &request.user_id).await?;
+        if SysUserRepository::update_status(&request.user_id, request.status, &login_user.username).await? == 0 {
+            return Err(ZzylError::NotFound("用户不存在".to_string()));
+        }
+        if request.status == UserStatus::Disabled {
+            TokenService::delete_login_user(&request.user_id).await?;
+        }
+        Ok(())
+    }
+
+    /// 用户授权角色
+    pub async fn auth_role(login_user: &LoginUser, request: &AuthRoleRequest) -> Result<()> {
+        Self::check_user_allowed(&request.user_id).await?;
+        Self::check_user_data_scope(login_user, &request.user_id).await?;
+        Self::require_user(&request.user_id).await?;
+        Self::check_assignment_scope(login_user, None, &request.role_ids).await?;
+
+        let mut tx = require_database_pool()?.begin().await?;
+        SysUserRoleRepository::delete_by_user_ids(&mut tx, &[request.user_id]).await?;
+        SysUserRoleRepository::insert_user_roles(&mut tx, &request.user_id, &request.role_ids).await?;
+        tx.commit().await?;
+
+        SysLoginService::refresh_cached_login_user(&request.user_id).await
+    }
+
+    /// 查询个人信息及所属角色组、岗位组
+    pub async fn select_profile(login_user: &LoginUser) -> Result<ProfileResponse> {
+        let user = Self::require_user(&login_user.user_id).await?;
+        let role_group = SysRoleRepository::select_roles_by_user_id(&login_user.user_id)
+            .await?
+            .into_iter()
+            .map(|role| role.role_name)
+            .collect::<Vec<_>>()
+            .join(",");
+        let post_group = SysPostRepository::select_posts_by_user_id(&login_user.user_id)
+            .await?
+            .into_iter()
+            .map(|post| post.post_name)
+            .collect::<Vec<_>>()
+            .join(",");
+        Ok(ProfileResponse { user, role_group, post_group })
+    }
+
+    /// 修改个人信息
+    pub async fn update_profile(login_user: &LoginUser, request: &ProfileRequest) -> Result<()> {
+        let existing = Self::require_user(&login_user.user_id).await?;
+        Self::check_contact_unique(
+            &existing.username,
+            request.phone.as_deref(),
+            request.email.as_deref(),
+            Some(&login_user.user_id),
+        )
+            .await?;
+
+        let user = SysUser {
+            nick_name: request.nick_name.clone(),
+            phone: request.phone.clone(),
+            email: request.email.clone(),
+            sex: request.sex.clone(),
+            base: BaseEntity {
+                update_by: Some(login_user.username.clone()),
+                update_time: Some(Utc::now()),
+                ..existing.base.clone()
+            },
+            ..existing
+        };
+        SysUserRepository::update_profile(&user).await?;
+        SysLoginService::refresh_cached_login_user(&login_user.user_id).await
+    }
+
+    /// 修改个人密码，需校验旧密码
+    pub async fn update_password(login_user: &LoginUser, request: &ChangePasswordRequest) -> Result<()> {
+        let user = Self::require_user(&login_user.user_id).await?;
+        let matched = SecurityUtils::verify_password(&request.old_password, &user.password)
+            .map_err(|e| ZzylError::System(format!("密码校验失败: {}", e)))?;
+        if !matched {
+            return Err(ZzylError::Business("修改密码失败，旧密码错误".to_string()));
+        }
+        if request.old_password == request.new_password {
+            return Err(ZzylError::Business("新密码不能与旧密码相同".to_string()));
+        }
+        let password = Self::encrypt_password(&request.new_password)?;
+        SysUserRepository::reset_password(&login_user.user_id, &password, &login_user.username).await?;
+        SysLoginService::refresh_cached_login_user(&login_user.user_id).await
+    }
+
+    /// 上传个人头像，返回头像地址
+    pub async fn update_avatar(
+        login_user: &LoginUser,
+        file_name: &str,
+        content_type: Option<&str>,
+        bytes: Vec<u8>,
+    ) -> Result<String> {
+        if bytes.is_empty() {
+            return Err(ZzylError::InvalidParameter("上传图片不能为空".to_string()));
+        }
+        if bytes.len() > AVATAR_MAX_SIZE {
+            return Err(ZzylError::InvalidParameter(format!(
+                "头像大小不能超过{}MB", AVATAR_MAX_SIZE / 1024 / 1024
+            )));
+        }
+        if !OssService::extension(file_name).is_some_and(|ext| AVATAR_EXTENSIONS.contains(&ext.as_str())) {
+            return Err(ZzylError::InvalidParameter(format!(
+                "文件格式不正确，请上传{}格式", AVATAR_EXTENSIONS.join("/")
+            )));
+        }
+
+        let user = Self::require_user(&login_user.user_id).await?;
+        let file = OssService::upload(AVATAR_DIR, file_name, content_type, bytes).await?;
+        SysUserRepository::update_avatar(&login_user.user_id, &file.url, &login_user.username).await?;
+
+        if let Some(old_avatar) = user.avatar.as_deref().filter(|avatar| !avatar.is_empty()) {
+            if let Err(e) = OssService::delete_by_url(old_avatar).await {
+                warn!("删除旧头像失败: {}", e);
+            }
+        }
+        SysLoginService::refresh_cached_login_user(&login_user.user_id).await?;
+        Ok(file.url)
+    }
+
+    /// 用户导入模板
+    pub fn import_template() -> Vec<Vec<String>> {
+        vec![USER_IMPORT_HEADERS.iter().map(|header| header.to_string()).collect()]
+    }
+
+    /// 从Excel批量导入用户，逐行返回导入结果
+    pub async fn import_users(login_user: &LoginUser, bytes: &[u8], update_support: bool) -> Result<UserImportResult> {
+        if bytes.len() > IMPORT_MAX_SIZE {
+            return Err(ZzylError::InvalidParameter(format!(
+                "导入文件大小不能超过{}MB", IMPORT_MAX_SIZE / 1024 / 1024
+            )));
+        }
+        let headers = USER_IMPORT_HEADERS.iter().map(|header| header.to_string()).collect::<Vec<_>>();
+        let rows = ExcelUtils::read_excel_bytes_to_struct::<UserImportRow>(bytes, &headers)
+            .map_err(|e| ZzylError::InvalidParameter(format!("导入文件解析失败: {}", e)))?;
+        if rows.is_empty() {
+            return Err(ZzylError::InvalidParameter("导入用户数据不能为空".to_string()));
+        }
+
+        let sex_values = SysDictTypeService::get_dict_value_map(USER_SEX_DICT).await?;
+        let status_values = SysDictTypeService::get_dict_value_map(USER_STATUS_DICT).await?;
+        let mut dept_ids = HashMap::new();
+        let mut results = Vec::with_capacity(rows.len());
+        for (index, row) in rows.iter().enumerate() {
+            let outcome = Self::import_user_row(login_user, row, update_support, &sex_values, &status_values, &mut dept_ids)
+                .await;
+            results.push(UserImportRowResult {
+                // 表头占第1行
+                row_num: index + 2,
+                username: row.username.trim().to_string(),
+                success: outcome.is_ok(),
+                message: outcome.unwrap_or_else(|e| e.response_message()),
+            });
+        }
+
+        let success_count = results.iter().filter(|result| result.success).count();
+        Ok(UserImportResult {
+            success_count,
+            failure_count: results.len() - success_count,
+            rows: results,
+        })
+    }
+
+    /// 校验用户是否允许操作（超级管理员不允许被修改）
+    pub async fn check_user_allowed(user_id: &Uuid) -> Result<()> {
+        let roles = SysRoleRepository::select_roles_by_user_id(user_id).await?;
+        if roles.iter().any(|role| role.role_key == SUPER_ADMIN) {
+            return Err(ZzylError::Forbidden("不允许操作超级管理员用户".to_string()));
+        }
+        Ok(())
+    }
+
+    /// 校验当前用户是否有权访问目标用户的数据
+    pub async fn check_user_data_scope(login_user: &LoginUser, user_id: &Uuid) -> Result<()> {
+        if PermissionService::is_admin(login_user) {
+            return Ok(());
+        }
+        let scope = DataScopeFilter::build(login_user, &data_scope!("u", "d"));
+        if SysUserRepository::count_user_in_scope(user_id, &scope).await? == 0 {
+            return Err(ZzylError::Permission("没有权限访问用户数据！".to_string()));
+        }
+        Ok(())
+    }
+
+    /// 校验待分配的部门与角色均在当前用户的数据权限范围内，非超级管理员不能分配超级管理员角色
+    async fn check_assignment_scope(login_user: &LoginUser, dept_id: Option<&Uuid>, role_ids: &[Uuid]) -> Result<()> {
+        if let Some(dept_id) = dept_id {
+            SysDeptService::check_dept_data_scope(login_user, dept_id).await?;
+        }
+        let is_admin = PermissionService::is_admin(login_user);
+        for role_id in role_ids {
+            let role = SysRoleRepository::select_role_by_id(role_id)
+                .await?
+                .ok_or_else(|| ZzylError::NotFound("角色不存在".to_string()))?;
+            if role.role_key == SUPER_ADMIN && !is_admin {
+                return Err(ZzylError::Forbidden("不允许分配超级管理员角色".to_string()));
+            }
+            SysRoleService::check_role_data_scope(login_user, role_id).await?;
+        }
+        Ok(())
+    }
+
+    /// 查询用户，不存在时返回错误
+    async fn require_user(user_id: &Uuid) -> Result<SysUser> {
+        SysUserRepository::select_user_by_id(user_id)
+            .await?
+            .ok_or_else(|| ZzylError::NotFound("用户不存在".to_string()))
+    }
+
+    /// 校验手机号码与邮箱唯一
+    async fn check_contact_unique(
+        username: &str,
+        phone: Option<&str>,
+        email: Option<&str>,
+        user_id: Option<&Uuid>,
+    ) -> Result<()> {
+        let is_other = |found: Option<Uuid>| found.is_some_and(|found| Some(&found) != user_id);
+
+        if let Some(phone) = phone.filter(|phone| !phone.trim().is_empty()) {
+            if is_other(SysUserRepository::select_user_id_by_phone(phone.trim()).await?) {
+                return Err(ZzylError::AlreadyExists(format!("用户'{}'保存失败，手机号码已存在", username)));
+            }
+        }
+        if let Some(email) = email.filter(|email| !email.trim().is_empty()) {
+            if is_other(SysUserRepository::select_user_id_by_email(email.trim()).await?) {
+                return Err(ZzylError::AlreadyExists(format!("用户'{}'保存失败，邮箱账号已存在", username)));
+            }
+        }
+        Ok(())
+    }
+
+    /// 导入单行用户，新增或按需更新已存在的用户
+    async fn import_user_row(
+        login_user: &LoginUser,
+        row: &UserImportRow,
+        update_support: bool,
+        sex_values: &HashMap<String, String>,
+        status_values: &HashMap<String, String>,
+        dept_ids: &mut HashMap<String, Uuid>,
+    ) -> Result<String> {
+        let non_empty = |value: &str| Some(value.trim().to_string()).filter(|value| !value.is_empty());
+
+        let username = row.username.trim();
+        ValidationUtils::validate_username(username)
+            .map_err(|_| ZzylError::InvalidParameter("登录账号格式不正确".to_string()))?;
+        let phone = non_empty(&row.phone);
+        if let Some(phone) = &phone {
+            ValidationUtils::validate_phone(phone)
+                .map_err(|_| ZzylError::InvalidParameter(format!("手机号码'{}'格式不正确", phone)))?;
+        }
+        let email = non_empty(&row.email);
+        if let Some(email) = &email {
+            ValidationUtils::validate_email(email)
+                .map_err(|_| ZzylError::InvalidParameter(format!("用户邮箱'{}'格式不正确", email)))?;
+        }
+        let sex = match non_empty(&row.sex) {
+            Some(label) => Some(sex_values.get(&label).cloned()
+                .ok_or_else(|| ZzylError::InvalidParameter(format!("用户性别'{}'无效", label)))?),
+            None => None,
+        };
+        let status = match non_empty(&row.status) {
+            Some(label) => status_values.get(&label)
+                .and_then(|value| value.parse::<i32>().ok())
+                .map(UserStatus::from)
+                .ok_or_else(|| ZzylError::InvalidParameter(format!("帐号状态'{}'无效", label)))?,
+            None => UserStatus::Normal,
+        };
+        let dept_id = match non_empty(&row.dept_name) {
+            Some(dept_name) => Some(Self::resolve_import_dept(login_user, &dept_name, dept_ids).await?),
+            None => None,
+        };
+
+        let request = UserRequest {
+            user_id: None,
+            dept_id,
+            username: username.to_string(),
+            nick_name: non_empty(&row.nick_name).or_else(|| Some(username.to_string())),
+            email,
+            phone,
+            sex,
+            password: None,
+            status: Some(status),
+            remark: None,
+            role_ids: Vec::new(),
+            post_ids: Vec::new(),
+        };
+        match SysUserRepository::select_user_id_by_username(username).await? {
+            None => {
+                Self::insert_user(login_user, &request).await?;
+                Ok("新增成功".to_string())
+            }
+            Some(_) if !update_support => Err(ZzylError::AlreadyExists(format!("登录账号'{}'已存在", username))),
+            Some(user_id) => {
+                Self::update_imported_user(login_user, &user_id, &request).await?;
+                Ok("更新成功".to_string())
+            }
+        }
+    }
+
+    /// 按导入行更新已存在的用户，保留原有角色、岗位与头像
+    async fn update_imported_user(login_user: &LoginUser, user_id: &Uuid, request: &UserRequest) -> Result<()> {
+        Self::check_user_allowed(user_id).await?;
+        Self::check_user_data_scope(login_user, user_id).await?;
+        let existing = Self::require_user(user_id).await?;
+        Self::check_contact_unique(&request.username, request.phone.as_deref(), request.email.as_deref(), Some(user_id))
+            .await?;
+
+        let user = SysUser {
+            nick_name: request.nick_name.clone(),
+            email: request.email.clone(),
+            phone: request.phone.clone(),
+            sex: request.sex.clone().or_else(|| existing.sex.clone()),
+            status: request.status.unwrap_or(existing.status),
+            dept_id: request.dept_id.or(existing.dept_id),
+            base: BaseEntity {
+                update_by: Some(login_user.username.clone()),
+                update_time: Some(Utc::now()),
+                ..existing.base.clone()
+            },
+            ..existing
+        };
+        let mut conn = require_database_pool()?.acquire().await?;
+        SysUserRepository::update_user(&mut conn, &user).await?;
+        SysLoginService::refresh_cached_login_user(user_id).await
+    }
+
+    /// 按名称解析导入行的部门，名称须唯一且在数据权限范围内
+    async fn resolve_import_dept(
+        login_user: &LoginUser,
+        dept_name: &str,
+        dept_ids: &mut HashMap<String, Uuid>,
+    ) -> Result<Uuid> {
+        if let Some(dept_id) = dept_ids.get(dept_name) {
+            return Ok(*dept_id);
+        }
+        let dept_id = match SysDeptRepository::select_dept_ids_by_name(dept_name).await?.as_slice() {
+            [] => return Err(ZzylError::NotFound(format!("部门'{}'不存在", dept_name))),
+            [dept_id] => *dept_id,
+            _ => return Err(ZzylError::Business(format!("部门名称'{}'不唯一，请先调整部门名称", dept_name))),
+        };
+        SysDeptService::check_dept_data_scope(login_user, &dept_id).await?;
+        dept_ids.insert(dept_name.to_string(), dept_id);
+        Ok(dept_id)
+    }
+
+    /// 校验密码长度并加密
+    fn encrypt_password(password: &str) -> Result<String> {
+        let password_len = password.chars().count();
+        if !(PASSWORD_MIN_LENGTH..=PASSWORD_MAX_LENGTH).contains(&password_len) {
+            return Err(ZzylError::InvalidParameter(format!(
+                "用户密码长度必须在{}到{}个字符之间", PASSWORD_MIN_LENGTH, PASSWORD_MAX_LENGTH
+            )));
+        }
+        SecurityUtils::encrypt_password(password)
+            .map_err(|e| ZzylError::System(format!("密码加密失败: {}", e)))
+    }
+}